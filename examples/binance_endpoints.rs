@@ -78,7 +78,7 @@ async fn account() -> Result<()> {
         Err(e) => println!("Error getting open orders: {:?}", e),
     }
 
-    match account.limit_buy("WTCETH", 10, 0.014000).await {
+    match account.limit_buy("WTCETH", 10, "0.014".parse()?).await {
         Ok(answer) => println!("{:?}", answer),
         Err(e) => println!("Error limit buy: {:?}", e),
     }
@@ -93,7 +93,7 @@ async fn account() -> Result<()> {
         Err(e) => println!("Error market buy (quote): {:?}", e),
     }
 
-    match account.limit_sell("WTCETH", 10, 0.035000).await {
+    match account.limit_sell("WTCETH", 10, "0.035".parse()?).await {
         Ok(answer) => println!("{:?}", answer),
         Err(e) => println!("Error limit sell: {:?}", e),
     }
@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 
 use binance_rs_plus::api::*;
+use binance_rs_plus::streams::{Stream, UpdateSpeed};
 use binance_rs_plus::userstream::*;
 use binance_rs_plus::websockets::*;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -291,8 +292,11 @@ async fn last_price_for_one_symbol() -> Result<()> {
         Box::pin(async move {
             if let WebsocketEvent::DayTicker(ticker_event) = event {
                 // btcusdt_price = ticker_event.average_price.parse().unwrap_or_default();
-                let current_close_price: f32 =
-                    ticker_event.current_close.parse().unwrap_or_default();
+                let current_close_price: f32 = ticker_event
+                    .current_close
+                    .to_string()
+                    .parse()
+                    .unwrap_or_default();
                 println!(
                     "BTCUSDT Ticker - Avg Price: {}, Current Close: {}",
                     ticker_event.average_price, current_close_price
@@ -333,9 +337,10 @@ async fn last_price_for_one_symbol() -> Result<()> {
 }
 
 async fn multiple_streams() -> Result<()> {
-    let endpoints = ["ethbtc@depth@100ms", "bnbeth@depth@100ms"]
-        .map(String::from)
-        .to_vec(); // Ensure Vec<String>
+    let endpoints = [
+        Stream::depth("ETHBTC", UpdateSpeed::Ms100),
+        Stream::depth("BNBETH", UpdateSpeed::Ms100),
+    ];
 
     let keep_running = Arc::new(AtomicBool::new(true));
     let keep_running_for_event_loop = Arc::clone(&keep_running);
@@ -1,10 +1,14 @@
 use crate::util::{build_signed_request, is_start_time_valid};
 use crate::model::{
-    AccountInformation, Balance, Empty, Order, OrderCanceled, TradeHistory, Transaction,
+    AccountInformation, Balance, CancelReplace, Empty, Number, Order, OrderCanceled, OrderList,
+    TradeHistory, Transaction,
 };
 use crate::client::Client;
 use crate::errors::{Result, Error};
-use std::collections::BTreeMap;
+use crate::pagination::{self, DAY, PageLimits};
+use futures_util::Stream;
+use crate::symbol_rules::SymbolRules;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Display;
 use crate::api::API;
 use crate::api::Spot;
@@ -13,27 +17,8 @@ use crate::api::Spot;
 pub struct Account {
     pub client: Client,
     pub recv_window: u64,
-}
-
-struct OrderRequest {
-    pub symbol: String,
-    pub qty: f64,
-    pub price: f64,
-    pub stop_price: Option<f64>,
-    pub order_side: OrderSide,
-    pub order_type: OrderType,
-    pub time_in_force: TimeInForce,
-    pub new_client_order_id: Option<String>,
-}
-
-struct OrderQuoteQuantityRequest {
-    pub symbol: String,
-    pub quote_order_qty: f64,
-    pub price: f64,
-    pub order_side: OrderSide,
-    pub order_type: OrderType,
-    pub time_in_force: TimeInForce,
-    pub new_client_order_id: Option<String>,
+    /// Filters checked before orders for these symbols are sent, keyed by symbol.
+    pub symbol_rules: HashMap<String, SymbolRules>,
 }
 
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
@@ -41,6 +26,10 @@ pub enum OrderType {
     Limit,
     Market,
     StopLossLimit,
+    StopLoss,
+    TakeProfit,
+    TakeProfitLimit,
+    LimitMaker,
 }
 
 impl OrderType {
@@ -49,9 +38,37 @@ impl OrderType {
             1 => Some(OrderType::Limit),
             2 => Some(OrderType::Market),
             3 => Some(OrderType::StopLossLimit),
+            4 => Some(OrderType::StopLoss),
+            5 => Some(OrderType::TakeProfit),
+            6 => Some(OrderType::TakeProfitLimit),
+            7 => Some(OrderType::LimitMaker),
             _ => None,
         }
     }
+
+    /// Whether orders of this type rest on the book at a limit price.
+    pub fn requires_price(&self) -> bool {
+        matches!(
+            self,
+            Self::Limit | Self::StopLossLimit | Self::TakeProfitLimit | Self::LimitMaker
+        )
+    }
+
+    pub fn requires_time_in_force(&self) -> bool {
+        matches!(
+            self,
+            Self::Limit | Self::StopLossLimit | Self::TakeProfitLimit
+        )
+    }
+
+    /// Whether orders of this type are triggered by `stopPrice` or
+    /// `trailingDelta`.
+    pub fn is_conditional(&self) -> bool {
+        matches!(
+            self,
+            Self::StopLoss | Self::StopLossLimit | Self::TakeProfit | Self::TakeProfitLimit
+        )
+    }
 }
 
 impl Display for OrderType {
@@ -60,6 +77,10 @@ impl Display for OrderType {
             Self::Limit => write!(f, "LIMIT"),
             Self::Market => write!(f, "MARKET"),
             Self::StopLossLimit => write!(f, "STOP_LOSS_LIMIT"),
+            Self::StopLoss => write!(f, "STOP_LOSS"),
+            Self::TakeProfit => write!(f, "TAKE_PROFIT"),
+            Self::TakeProfitLimit => write!(f, "TAKE_PROFIT_LIMIT"),
+            Self::LimitMaker => write!(f, "LIMIT_MAKER"),
         }
     }
 }
@@ -118,7 +139,1014 @@ impl Display for TimeInForce {
     }
 }
 
+/// Level of detail in the response to a new order.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum NewOrderRespType {
+    Ack,
+    Result,
+    Full,
+}
+
+impl Display for NewOrderRespType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ack => write!(f, "ACK"),
+            Self::Result => write!(f, "RESULT"),
+            Self::Full => write!(f, "FULL"),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum SelfTradePreventionMode {
+    None,
+    ExpireTaker,
+    ExpireMaker,
+    ExpireBoth,
+    Decrement,
+}
+
+impl Display for SelfTradePreventionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "NONE"),
+            Self::ExpireTaker => write!(f, "EXPIRE_TAKER"),
+            Self::ExpireMaker => write!(f, "EXPIRE_MAKER"),
+            Self::ExpireBoth => write!(f, "EXPIRE_BOTH"),
+            Self::Decrement => write!(f, "DECREMENT"),
+        }
+    }
+}
+
+/// A request to `/api/v3/order`, covering every order type and parameter.
+///
+/// Field combinations are checked by [`SpotOrderRequest::validate`] before the
+/// request is sent by [`Account::place`] or [`Account::test`].
+///
+///```no_run
+/// use binance_rs_plus::api::Binance;
+/// use binance_rs_plus::account::*;
+///
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let account: Account = Binance::new(Some("api_key".into()), Some("secret_key".into()));
+///
+///     let order = SpotOrderRequest::new("BNBBTC", OrderSide::Sell, OrderType::TakeProfitLimit)
+///         .set_quantity(1)
+///         .set_price("0.0012".parse()?)
+///         .set_stop_price("0.0011".parse()?)
+///         .set_time_in_force(TimeInForce::GTC)
+///         .set_new_order_resp_type(NewOrderRespType::Full);
+///     let transaction = account.place(order).await?;
+///     println!("{:#?}", transaction);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpotOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub time_in_force: Option<TimeInForce>,
+    pub quantity: Option<Number>,
+    pub quote_order_qty: Option<Number>,
+    pub price: Option<Number>,
+    pub new_client_order_id: Option<String>,
+    pub strategy_id: Option<u64>,
+    pub strategy_type: Option<u64>,
+    pub stop_price: Option<Number>,
+    pub trailing_delta: Option<u64>,
+    pub iceberg_qty: Option<Number>,
+    pub new_order_resp_type: Option<NewOrderRespType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+impl SpotOrderRequest {
+    pub fn new<S: Into<String>>(symbol: S, side: OrderSide, order_type: OrderType) -> Self {
+        Self {
+            symbol: symbol.into(),
+            side,
+            order_type,
+            time_in_force: None,
+            quantity: None,
+            quote_order_qty: None,
+            price: None,
+            new_client_order_id: None,
+            strategy_id: None,
+            strategy_type: None,
+            stop_price: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        }
+    }
+
+    pub fn set_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn set_quantity<F: Into<Number>>(mut self, quantity: F) -> Self {
+        self.quantity = Some(quantity.into());
+        self
+    }
+
+    /// Spend (BUY) or receive (SELL) this amount of the quote asset. MARKET only.
+    pub fn set_quote_order_qty<F: Into<Number>>(mut self, quote_order_qty: F) -> Self {
+        self.quote_order_qty = Some(quote_order_qty.into());
+        self
+    }
+
+    pub fn set_price(mut self, price: Number) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn set_new_client_order_id<S: Into<String>>(mut self, new_client_order_id: S) -> Self {
+        self.new_client_order_id = Some(new_client_order_id.into());
+        self
+    }
+
+    pub fn set_strategy_id(mut self, strategy_id: u64) -> Self {
+        self.strategy_id = Some(strategy_id);
+        self
+    }
+
+    /// Must be 1000000 or greater.
+    pub fn set_strategy_type(mut self, strategy_type: u64) -> Self {
+        self.strategy_type = Some(strategy_type);
+        self
+    }
+
+    pub fn set_stop_price(mut self, stop_price: Number) -> Self {
+        self.stop_price = Some(stop_price);
+        self
+    }
+
+    /// Trailing distance in basis points (BIPS).
+    pub fn set_trailing_delta(mut self, trailing_delta: u64) -> Self {
+        self.trailing_delta = Some(trailing_delta);
+        self
+    }
+
+    pub fn set_iceberg_qty<F: Into<Number>>(mut self, iceberg_qty: F) -> Self {
+        self.iceberg_qty = Some(iceberg_qty.into());
+        self
+    }
+
+    pub fn set_new_order_resp_type(mut self, new_order_resp_type: NewOrderRespType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn set_self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    /// Check that the fields set are the ones Binance requires and accepts for
+    /// this order type.
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |msg: String| Err(Error::InvalidOrder(msg));
+        let order_type = self.order_type;
+
+        if self.symbol.is_empty() {
+            return invalid("symbol is required".into());
+        }
+        for (name, value) in [
+            ("quantity", self.quantity),
+            ("quoteOrderQty", self.quote_order_qty),
+            ("price", self.price),
+            ("stopPrice", self.stop_price),
+            ("icebergQty", self.iceberg_qty),
+        ] {
+            if let Some(v) = value {
+                if !is_positive(v) {
+                    return invalid(format!("{} must be positive, got {}", name, v));
+                }
+            }
+        }
+
+        match (order_type, self.quantity, self.quote_order_qty) {
+            (OrderType::Market, None, None) => {
+                return invalid("MARKET orders need quantity or quoteOrderQty".into());
+            }
+            (OrderType::Market, Some(_), Some(_)) => {
+                return invalid("MARKET orders take quantity or quoteOrderQty, not both".into());
+            }
+            (OrderType::Market, _, _) => {}
+            (_, None, _) => return invalid(format!("{} orders need quantity", order_type)),
+            (_, _, Some(_)) => {
+                return invalid(format!(
+                    "quoteOrderQty is not allowed on {} orders",
+                    order_type
+                ));
+            }
+            _ => {}
+        }
+
+        match (order_type.requires_price(), self.price) {
+            (true, None) => return invalid(format!("{} orders need price", order_type)),
+            (false, Some(_)) => {
+                return invalid(format!("price is not allowed on {} orders", order_type));
+            }
+            _ => {}
+        }
+
+        match (order_type.requires_time_in_force(), self.time_in_force) {
+            (true, None) => return invalid(format!("{} orders need timeInForce", order_type)),
+            (false, Some(_)) => {
+                return invalid(format!(
+                    "timeInForce is not allowed on {} orders",
+                    order_type
+                ));
+            }
+            _ => {}
+        }
+
+        if order_type.is_conditional() {
+            if self.stop_price.is_none() && self.trailing_delta.is_none() {
+                return invalid(format!(
+                    "{} orders need stopPrice or trailingDelta",
+                    order_type
+                ));
+            }
+        } else if self.stop_price.is_some() || self.trailing_delta.is_some() {
+            return invalid(format!(
+                "stopPrice and trailingDelta are not allowed on {} orders",
+                order_type
+            ));
+        }
+
+        if self.iceberg_qty.is_some() {
+            if !(order_type.requires_price()) {
+                return invalid(format!(
+                    "icebergQty is not allowed on {} orders",
+                    order_type
+                ));
+            }
+            if self
+                .time_in_force
+                .is_some_and(|tif| tif != TimeInForce::GTC)
+            {
+                return invalid("icebergQty requires timeInForce GTC".into());
+            }
+        }
+
+        if self.strategy_type.is_some_and(|t| t < 1_000_000) {
+            return invalid("strategyType must be 1000000 or greater".into());
+        }
+
+        Ok(())
+    }
+
+    /// Request parameters, without `recvWindow`, `timestamp` and `signature`.
+    pub fn to_params(&self) -> BTreeMap<String, String> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), self.symbol.clone());
+        parameters.insert("side".into(), self.side.to_string());
+        parameters.insert("type".into(), self.order_type.to_string());
+
+        if let Some(tif) = self.time_in_force {
+            parameters.insert("timeInForce".into(), tif.to_string());
+        }
+        if let Some(qty) = self.quantity {
+            parameters.insert("quantity".into(), qty.to_string());
+        }
+        if let Some(quote_qty) = self.quote_order_qty {
+            parameters.insert("quoteOrderQty".into(), quote_qty.to_string());
+        }
+        if let Some(price) = self.price {
+            parameters.insert("price".into(), price.to_string());
+        }
+        if let Some(ref client_id) = self.new_client_order_id {
+            parameters.insert("newClientOrderId".into(), client_id.clone());
+        }
+        if let Some(id) = self.strategy_id {
+            parameters.insert("strategyId".into(), id.to_string());
+        }
+        if let Some(strategy_type) = self.strategy_type {
+            parameters.insert("strategyType".into(), strategy_type.to_string());
+        }
+        if let Some(stop_price) = self.stop_price {
+            parameters.insert("stopPrice".into(), stop_price.to_string());
+        }
+        if let Some(delta) = self.trailing_delta {
+            parameters.insert("trailingDelta".into(), delta.to_string());
+        }
+        if let Some(iceberg_qty) = self.iceberg_qty {
+            parameters.insert("icebergQty".into(), iceberg_qty.to_string());
+        }
+        if let Some(resp_type) = self.new_order_resp_type {
+            parameters.insert("newOrderRespType".into(), resp_type.to_string());
+        }
+        if let Some(mode) = self.self_trade_prevention_mode {
+            parameters.insert("selfTradePreventionMode".into(), mode.to_string());
+        }
+        parameters
+    }
+}
+
+#[cfg(not(feature = "decimal"))]
+fn is_positive(value: Number) -> bool {
+    value.is_finite() && value > 0.0
+}
+
+#[cfg(feature = "decimal")]
+fn is_positive(value: Number) -> bool {
+    value > Number::ZERO
+}
+
+/// What `/api/v3/order/cancelReplace` does when the cancel fails.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum CancelReplaceMode {
+    /// Don't place the new order.
+    StopOnFailure,
+    /// Place the new order anyway.
+    AllowFailure,
+}
+
+impl Display for CancelReplaceMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::StopOnFailure => write!(f, "STOP_ON_FAILURE"),
+            Self::AllowFailure => write!(f, "ALLOW_FAILURE"),
+        }
+    }
+}
+
+/// Only cancel the order while it has this status.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum CancelRestrictions {
+    OnlyNew,
+    OnlyPartiallyFilled,
+}
+
+impl Display for CancelRestrictions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OnlyNew => write!(f, "ONLY_NEW"),
+            Self::OnlyPartiallyFilled => write!(f, "ONLY_PARTIALLY_FILLED"),
+        }
+    }
+}
+
+/// A request to `/api/v3/order/cancelReplace`: cancels an open order and
+/// places `order` in one step. The order to cancel is set with
+/// [`set_cancel_order_id`](Self::set_cancel_order_id) or
+/// [`set_cancel_orig_client_order_id`](Self::set_cancel_orig_client_order_id).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CancelReplaceRequest {
+    pub order: SpotOrderRequest,
+    pub mode: CancelReplaceMode,
+    pub cancel_order_id: Option<u64>,
+    pub cancel_orig_client_order_id: Option<String>,
+    /// New id for the cancel itself.
+    pub cancel_new_client_order_id: Option<String>,
+    pub cancel_restrictions: Option<CancelRestrictions>,
+}
+
+impl CancelReplaceRequest {
+    pub fn new(order: SpotOrderRequest, mode: CancelReplaceMode) -> Self {
+        Self {
+            order,
+            mode,
+            cancel_order_id: None,
+            cancel_orig_client_order_id: None,
+            cancel_new_client_order_id: None,
+            cancel_restrictions: None,
+        }
+    }
+
+    pub fn set_cancel_order_id(mut self, order_id: u64) -> Self {
+        self.cancel_order_id = Some(order_id);
+        self
+    }
+
+    pub fn set_cancel_orig_client_order_id<S: Into<String>>(mut self, client_order_id: S) -> Self {
+        self.cancel_orig_client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    pub fn set_cancel_new_client_order_id<S: Into<String>>(mut self, client_order_id: S) -> Self {
+        self.cancel_new_client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    pub fn set_cancel_restrictions(mut self, restrictions: CancelRestrictions) -> Self {
+        self.cancel_restrictions = Some(restrictions);
+        self
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.cancel_order_id.is_none() && self.cancel_orig_client_order_id.is_none() {
+            return Err(Error::InvalidOrder(
+                "cancelOrderId or cancelOrigClientOrderId is required".into(),
+            ));
+        }
+        self.order.validate()
+    }
+
+    /// Request parameters, without `recvWindow`, `timestamp` and `signature`.
+    pub fn to_params(&self) -> BTreeMap<String, String> {
+        let mut parameters = self.order.to_params();
+        parameters.insert("cancelReplaceMode".into(), self.mode.to_string());
+        if let Some(order_id) = self.cancel_order_id {
+            parameters.insert("cancelOrderId".into(), order_id.to_string());
+        }
+        if let Some(ref client_id) = self.cancel_orig_client_order_id {
+            parameters.insert("cancelOrigClientOrderId".into(), client_id.clone());
+        }
+        if let Some(ref client_id) = self.cancel_new_client_order_id {
+            parameters.insert("cancelNewClientOrderId".into(), client_id.clone());
+        }
+        if let Some(restrictions) = self.cancel_restrictions {
+            parameters.insert("cancelRestrictions".into(), restrictions.to_string());
+        }
+        parameters
+    }
+}
+
+/// A request to the deprecated `/api/v3/order/oco`: a LIMIT_MAKER order at
+/// `price` and a STOP_LOSS (or STOP_LOSS_LIMIT with a stop limit price)
+/// order at `stop_price`. When one fills, the other is cancelled. New code
+/// should prefer [`OrderListOcoRequest`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcoOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub quantity: Number,
+    pub price: Number,
+    pub stop_price: Number,
+    pub stop_limit_price: Option<Number>,
+    pub stop_limit_time_in_force: Option<TimeInForce>,
+    pub list_client_order_id: Option<String>,
+    pub limit_client_order_id: Option<String>,
+    pub stop_client_order_id: Option<String>,
+    pub limit_iceberg_qty: Option<Number>,
+    pub stop_iceberg_qty: Option<Number>,
+    pub new_order_resp_type: Option<NewOrderRespType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+impl OcoOrderRequest {
+    pub fn new<S: Into<String>, F: Into<Number>>(
+        symbol: S, side: OrderSide, quantity: F, price: Number, stop_price: Number,
+    ) -> Self {
+        Self {
+            symbol: symbol.into(),
+            side,
+            quantity: quantity.into(),
+            price,
+            stop_price,
+            stop_limit_price: None,
+            stop_limit_time_in_force: None,
+            list_client_order_id: None,
+            limit_client_order_id: None,
+            stop_client_order_id: None,
+            limit_iceberg_qty: None,
+            stop_iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        }
+    }
+
+    /// Makes the stop leg a STOP_LOSS_LIMIT order.
+    pub fn set_stop_limit(mut self, stop_limit_price: Number, time_in_force: TimeInForce) -> Self {
+        self.stop_limit_price = Some(stop_limit_price);
+        self.stop_limit_time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn set_list_client_order_id<S: Into<String>>(mut self, list_client_order_id: S) -> Self {
+        self.list_client_order_id = Some(list_client_order_id.into());
+        self
+    }
+
+    pub fn set_limit_client_order_id<S: Into<String>>(mut self, limit_client_order_id: S) -> Self {
+        self.limit_client_order_id = Some(limit_client_order_id.into());
+        self
+    }
+
+    pub fn set_stop_client_order_id<S: Into<String>>(mut self, stop_client_order_id: S) -> Self {
+        self.stop_client_order_id = Some(stop_client_order_id.into());
+        self
+    }
+
+    pub fn set_limit_iceberg_qty<F: Into<Number>>(mut self, limit_iceberg_qty: F) -> Self {
+        self.limit_iceberg_qty = Some(limit_iceberg_qty.into());
+        self
+    }
+
+    pub fn set_stop_iceberg_qty<F: Into<Number>>(mut self, stop_iceberg_qty: F) -> Self {
+        self.stop_iceberg_qty = Some(stop_iceberg_qty.into());
+        self
+    }
+
+    pub fn set_new_order_resp_type(mut self, new_order_resp_type: NewOrderRespType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn set_self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.symbol.is_empty() {
+            return Err(Error::InvalidOrder("symbol is required".into()));
+        }
+        for (name, value) in [
+            ("quantity", Some(self.quantity)),
+            ("price", Some(self.price)),
+            ("stopPrice", Some(self.stop_price)),
+            ("stopLimitPrice", self.stop_limit_price),
+            ("limitIcebergQty", self.limit_iceberg_qty),
+            ("stopIcebergQty", self.stop_iceberg_qty),
+        ] {
+            if let Some(v) = value {
+                if !is_positive(v) {
+                    return Err(Error::InvalidOrder(format!(
+                        "{} must be positive, got {}",
+                        name, v
+                    )));
+                }
+            }
+        }
+        if self.stop_limit_price.is_some() != self.stop_limit_time_in_force.is_some() {
+            return Err(Error::InvalidOrder(
+                "stopLimitPrice and stopLimitTimeInForce go together".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Request parameters, without `recvWindow`, `timestamp` and `signature`.
+    pub fn to_params(&self) -> BTreeMap<String, String> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), self.symbol.clone());
+        parameters.insert("side".into(), self.side.to_string());
+        parameters.insert("quantity".into(), self.quantity.to_string());
+        parameters.insert("price".into(), self.price.to_string());
+        parameters.insert("stopPrice".into(), self.stop_price.to_string());
+
+        if let Some(stop_limit_price) = self.stop_limit_price {
+            parameters.insert("stopLimitPrice".into(), stop_limit_price.to_string());
+        }
+        if let Some(tif) = self.stop_limit_time_in_force {
+            parameters.insert("stopLimitTimeInForce".into(), tif.to_string());
+        }
+        if let Some(ref id) = self.list_client_order_id {
+            parameters.insert("listClientOrderId".into(), id.clone());
+        }
+        if let Some(ref id) = self.limit_client_order_id {
+            parameters.insert("limitClientOrderId".into(), id.clone());
+        }
+        if let Some(ref id) = self.stop_client_order_id {
+            parameters.insert("stopClientOrderId".into(), id.clone());
+        }
+        if let Some(qty) = self.limit_iceberg_qty {
+            parameters.insert("limitIcebergQty".into(), qty.to_string());
+        }
+        if let Some(qty) = self.stop_iceberg_qty {
+            parameters.insert("stopIcebergQty".into(), qty.to_string());
+        }
+        if let Some(resp_type) = self.new_order_resp_type {
+            parameters.insert("newOrderRespType".into(), resp_type.to_string());
+        }
+        if let Some(mode) = self.self_trade_prevention_mode {
+            parameters.insert("selfTradePreventionMode".into(), mode.to_string());
+        }
+        parameters
+    }
+}
+
+/// One order of an order list placed with [`OrderListOcoRequest`],
+/// [`OrderListOtoRequest`] or [`OrderListOtocoRequest`].
+///
+/// The fields follow [`SpotOrderRequest`], and a leg is validated as the
+/// spot order it becomes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderListLeg {
+    pub order_type: OrderType,
+    pub side: OrderSide,
+    pub quantity: Number,
+    pub price: Option<Number>,
+    pub stop_price: Option<Number>,
+    pub trailing_delta: Option<u64>,
+    pub time_in_force: Option<TimeInForce>,
+    pub iceberg_qty: Option<Number>,
+    pub client_order_id: Option<String>,
+    pub strategy_id: Option<u64>,
+    pub strategy_type: Option<u64>,
+}
+
+impl OrderListLeg {
+    pub fn new<F: Into<Number>>(order_type: OrderType, side: OrderSide, quantity: F) -> Self {
+        Self {
+            order_type,
+            side,
+            quantity: quantity.into(),
+            price: None,
+            stop_price: None,
+            trailing_delta: None,
+            time_in_force: None,
+            iceberg_qty: None,
+            client_order_id: None,
+            strategy_id: None,
+            strategy_type: None,
+        }
+    }
+
+    pub fn set_price(mut self, price: Number) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn set_stop_price(mut self, stop_price: Number) -> Self {
+        self.stop_price = Some(stop_price);
+        self
+    }
+
+    /// Trailing distance in basis points (BIPS).
+    pub fn set_trailing_delta(mut self, trailing_delta: u64) -> Self {
+        self.trailing_delta = Some(trailing_delta);
+        self
+    }
+
+    pub fn set_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn set_iceberg_qty<F: Into<Number>>(mut self, iceberg_qty: F) -> Self {
+        self.iceberg_qty = Some(iceberg_qty.into());
+        self
+    }
+
+    pub fn set_client_order_id<S: Into<String>>(mut self, client_order_id: S) -> Self {
+        self.client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    pub fn set_strategy_id(mut self, strategy_id: u64) -> Self {
+        self.strategy_id = Some(strategy_id);
+        self
+    }
+
+    /// Must be 1000000 or greater.
+    pub fn set_strategy_type(mut self, strategy_type: u64) -> Self {
+        self.strategy_type = Some(strategy_type);
+        self
+    }
+
+    /// The spot order this leg stands for.
+    pub fn to_spot_order<S: Into<String>>(&self, symbol: S) -> SpotOrderRequest {
+        SpotOrderRequest {
+            symbol: symbol.into(),
+            side: self.side,
+            order_type: self.order_type,
+            time_in_force: self.time_in_force,
+            quantity: Some(self.quantity),
+            quote_order_qty: None,
+            price: self.price,
+            new_client_order_id: self.client_order_id.clone(),
+            strategy_id: self.strategy_id,
+            strategy_type: self.strategy_type,
+            stop_price: self.stop_price,
+            trailing_delta: self.trailing_delta,
+            iceberg_qty: self.iceberg_qty,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        }
+    }
+
+    fn validate(&self, symbol: &str, name: &str) -> Result<()> {
+        self.to_spot_order(symbol).validate().map_err(|e| match e {
+            Error::InvalidOrder(msg) => Error::InvalidOrder(format!("{} order: {}", name, msg)),
+            e => e,
+        })
+    }
+
+    // Inserts the leg as `{prefix}Type`, `{prefix}Price`, ...; side and
+    // quantity only with `with_side_and_quantity`.
+    fn insert_params(
+        &self, prefix: &str, with_side_and_quantity: bool,
+        parameters: &mut BTreeMap<String, String>,
+    ) {
+        let mut insert = |name: &str, value: String| {
+            parameters.insert(format!("{}{}", prefix, name), value);
+        };
+        insert("Type", self.order_type.to_string());
+        if with_side_and_quantity {
+            insert("Side", self.side.to_string());
+            insert("Quantity", self.quantity.to_string());
+        }
+        if let Some(price) = self.price {
+            insert("Price", price.to_string());
+        }
+        if let Some(stop_price) = self.stop_price {
+            insert("StopPrice", stop_price.to_string());
+        }
+        if let Some(delta) = self.trailing_delta {
+            insert("TrailingDelta", delta.to_string());
+        }
+        if let Some(tif) = self.time_in_force {
+            insert("TimeInForce", tif.to_string());
+        }
+        if let Some(iceberg_qty) = self.iceberg_qty {
+            insert("IcebergQty", iceberg_qty.to_string());
+        }
+        if let Some(ref client_id) = self.client_order_id {
+            insert("ClientOrderId", client_id.clone());
+        }
+        if let Some(id) = self.strategy_id {
+            insert("StrategyId", id.to_string());
+        }
+        if let Some(strategy_type) = self.strategy_type {
+            insert("StrategyType", strategy_type.to_string());
+        }
+    }
+}
+
+// The above and below orders of an OCO share one side and quantity.
+fn validate_oco_legs(symbol: &str, above: &OrderListLeg, below: &OrderListLeg) -> Result<()> {
+    above.validate(symbol, "above")?;
+    below.validate(symbol, "below")?;
+    if above.side != below.side || above.quantity != below.quantity {
+        return Err(Error::InvalidOrder(
+            "above and below orders need the same side and quantity".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// A request to `/api/v3/orderList/oco`: two orders, one above and one below
+/// the market price, where the fill of one cancels the other. A
+/// take-profit and stop-loss bracket for a long position is a SELL
+/// LIMIT_MAKER above and a SELL STOP_LOSS below.
+///
+///```no_run
+/// use binance_rs_plus::api::Binance;
+/// use binance_rs_plus::account::*;
+/// use binance_rs_plus::model::Number;
+///
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let account: Account = Binance::new(Some("api_key".into()), Some("secret_key".into()));
+///
+///     let qty: Number = "0.01".parse()?;
+///     let take_profit =
+///         OrderListLeg::new(OrderType::LimitMaker, OrderSide::Sell, qty).set_price(70000.into());
+///     let stop_loss =
+///         OrderListLeg::new(OrderType::StopLoss, OrderSide::Sell, qty).set_stop_price(55000.into());
+///     let order_list = account
+///         .place_order_list_oco(OrderListOcoRequest::new("BTCUSDT", take_profit, stop_loss))
+///         .await?;
+///     println!("{}", order_list.order_list_id);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderListOcoRequest {
+    pub symbol: String,
+    pub above: OrderListLeg,
+    pub below: OrderListLeg,
+    pub list_client_order_id: Option<String>,
+    pub new_order_resp_type: Option<NewOrderRespType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+impl OrderListOcoRequest {
+    pub fn new<S: Into<String>>(symbol: S, above: OrderListLeg, below: OrderListLeg) -> Self {
+        Self {
+            symbol: symbol.into(),
+            above,
+            below,
+            list_client_order_id: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        }
+    }
+
+    pub fn set_list_client_order_id<S: Into<String>>(mut self, list_client_order_id: S) -> Self {
+        self.list_client_order_id = Some(list_client_order_id.into());
+        self
+    }
+
+    pub fn set_new_order_resp_type(mut self, new_order_resp_type: NewOrderRespType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn set_self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        validate_oco_legs(&self.symbol, &self.above, &self.below)
+    }
+
+    /// Request parameters, without `recvWindow`, `timestamp` and `signature`.
+    pub fn to_params(&self) -> BTreeMap<String, String> {
+        let mut parameters = list_params(
+            &self.symbol,
+            &self.list_client_order_id,
+            self.new_order_resp_type,
+            self.self_trade_prevention_mode,
+        );
+        parameters.insert("side".into(), self.above.side.to_string());
+        parameters.insert("quantity".into(), self.above.quantity.to_string());
+        self.above.insert_params("above", false, &mut parameters);
+        self.below.insert_params("below", false, &mut parameters);
+        parameters
+    }
+}
+
+/// A request to `/api/v3/orderList/oto`: a working order, and a pending
+/// order that is placed once the working order is fully filled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderListOtoRequest {
+    pub symbol: String,
+    pub working: OrderListLeg,
+    pub pending: OrderListLeg,
+    pub list_client_order_id: Option<String>,
+    pub new_order_resp_type: Option<NewOrderRespType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+impl OrderListOtoRequest {
+    pub fn new<S: Into<String>>(symbol: S, working: OrderListLeg, pending: OrderListLeg) -> Self {
+        Self {
+            symbol: symbol.into(),
+            working,
+            pending,
+            list_client_order_id: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        }
+    }
+
+    pub fn set_list_client_order_id<S: Into<String>>(mut self, list_client_order_id: S) -> Self {
+        self.list_client_order_id = Some(list_client_order_id.into());
+        self
+    }
+
+    pub fn set_new_order_resp_type(mut self, new_order_resp_type: NewOrderRespType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn set_self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        self.working.validate(&self.symbol, "working")?;
+        self.pending.validate(&self.symbol, "pending")
+    }
+
+    /// Request parameters, without `recvWindow`, `timestamp` and `signature`.
+    pub fn to_params(&self) -> BTreeMap<String, String> {
+        let mut parameters = list_params(
+            &self.symbol,
+            &self.list_client_order_id,
+            self.new_order_resp_type,
+            self.self_trade_prevention_mode,
+        );
+        self.working.insert_params("working", true, &mut parameters);
+        self.pending.insert_params("pending", true, &mut parameters);
+        parameters
+    }
+}
+
+/// A request to `/api/v3/orderList/otoco`: a working order that, once fully
+/// filled, places an OCO of the pending above and below orders. This is an
+/// entry with its take-profit and stop-loss bracket in one request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderListOtocoRequest {
+    pub symbol: String,
+    pub working: OrderListLeg,
+    pub pending_above: OrderListLeg,
+    pub pending_below: OrderListLeg,
+    pub list_client_order_id: Option<String>,
+    pub new_order_resp_type: Option<NewOrderRespType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+impl OrderListOtocoRequest {
+    pub fn new<S: Into<String>>(
+        symbol: S, working: OrderListLeg, pending_above: OrderListLeg, pending_below: OrderListLeg,
+    ) -> Self {
+        Self {
+            symbol: symbol.into(),
+            working,
+            pending_above,
+            pending_below,
+            list_client_order_id: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        }
+    }
+
+    pub fn set_list_client_order_id<S: Into<String>>(mut self, list_client_order_id: S) -> Self {
+        self.list_client_order_id = Some(list_client_order_id.into());
+        self
+    }
+
+    pub fn set_new_order_resp_type(mut self, new_order_resp_type: NewOrderRespType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn set_self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        self.working.validate(&self.symbol, "working")?;
+        validate_oco_legs(&self.symbol, &self.pending_above, &self.pending_below)
+    }
+
+    /// Request parameters, without `recvWindow`, `timestamp` and `signature`.
+    pub fn to_params(&self) -> BTreeMap<String, String> {
+        let mut parameters = list_params(
+            &self.symbol,
+            &self.list_client_order_id,
+            self.new_order_resp_type,
+            self.self_trade_prevention_mode,
+        );
+        self.working.insert_params("working", true, &mut parameters);
+        parameters.insert("pendingSide".into(), self.pending_above.side.to_string());
+        parameters.insert(
+            "pendingQuantity".into(),
+            self.pending_above.quantity.to_string(),
+        );
+        self.pending_above
+            .insert_params("pendingAbove", false, &mut parameters);
+        self.pending_below
+            .insert_params("pendingBelow", false, &mut parameters);
+        parameters
+    }
+}
+
+fn list_params(
+    symbol: &str, list_client_order_id: &Option<String>,
+    new_order_resp_type: Option<NewOrderRespType>,
+    self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("symbol".into(), symbol.into());
+    if let Some(id) = list_client_order_id {
+        parameters.insert("listClientOrderId".into(), id.clone());
+    }
+    if let Some(resp_type) = new_order_resp_type {
+        parameters.insert("newOrderRespType".into(), resp_type.to_string());
+    }
+    if let Some(mode) = self_trade_prevention_mode {
+        parameters.insert("selfTradePreventionMode".into(), mode.to_string());
+    }
+    parameters
+}
+
+/// Filters for [`Account::get_all_order_lists`]. `from_id` can't be
+/// combined with a time range, and a range can span at most 24 hours.
+#[derive(Debug, Clone, Default)]
+pub struct OrderListHistoryRequest {
+    pub from_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default 500, max 1000.
+    pub limit: Option<u16>,
+}
+
 impl Account {
+    /// Check orders for `rules.symbol` against its exchange filters before
+    /// they are sent. Violations fail with `Error::Filter`.
+    pub fn set_symbol_rules(&mut self, rules: SymbolRules) {
+        self.symbol_rules.insert(rules.symbol.clone(), rules);
+    }
+
+    fn check_symbol_rules(&self, order: &SpotOrderRequest) -> Result<()> {
+        let Some(rules) = self.symbol_rules.get(&order.symbol) else {
+            return Ok(());
+        };
+        let is_market = order.order_type == OrderType::Market;
+        rules.validate(order.price, order.quantity, is_market)?;
+        if let Some(stop_price) = order.stop_price {
+            rules.check_price(stop_price)?;
+        }
+        if let Some(quote_qty) = order.quote_order_qty {
+            rules.check_quote_qty(quote_qty)?;
+        }
+        Ok(())
+    }
+
     // Account Information
     pub async fn get_account(&self) -> Result<AccountInformation> {
         let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
@@ -198,6 +1226,58 @@ impl Account {
             .await
     }
 
+    pub async fn order_status_with_client_id<S, C>(
+        &self, symbol: S, orig_client_order_id: C,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+        C: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("origClientOrderId".into(), orig_client_order_id.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::Order), Some(request))
+            .await
+    }
+
+    /// All orders of a symbol: open, cancelled or filled.
+    ///
+    /// With `order_id`, orders from that id on are returned; otherwise the
+    /// most recent ones. A time range can span at most 24 hours.
+    pub async fn get_all_orders<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, order_id: S2, start_time: S3, end_time: S4, limit: S5,
+    ) -> Result<Vec<Order>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(id) = order_id.into() {
+            parameters.insert("orderId".into(), id.to_string());
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::AllOrders), Some(request))
+            .await
+    }
+
     /// Place a test status order
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
@@ -216,125 +1296,99 @@ impl Account {
         Ok(())
     }
 
-    // Place a LIMIT order - BUY
-    pub async fn limit_buy<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<Transaction>
-    where
-        S: Into<String>,
-        F: Into<f64>,
-    {
-        let buy = OrderRequest {
-            symbol: symbol.into(),
-            qty: qty.into(),
-            price,
-            stop_price: None,
-            order_side: OrderSide::Buy,
-            order_type: OrderType::Limit,
-            time_in_force: TimeInForce::GTC,
-            new_client_order_id: None,
-        };
-        let order = self.build_order(buy);
-        let request = build_signed_request(order, self.recv_window)?;
+    /// Place an order of any type.
+    ///
+    /// The request is checked with [`SpotOrderRequest::validate`] first, so
+    /// missing or conflicting parameters fail with `Error::InvalidOrder`
+    /// without a round trip to Binance. If rules were registered for the
+    /// symbol with [`Account::set_symbol_rules`], they are checked as well.
+    pub async fn place(&self, order: SpotOrderRequest) -> Result<Transaction> {
+        order.validate()?;
+        self.check_symbol_rules(&order)?;
+        let request = build_signed_request(order.to_params(), self.recv_window)?;
         self.client
             .post_signed(API::Spot(Spot::Order), request)
             .await
     }
 
+    /// Place a test order of any type
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test(&self, order: SpotOrderRequest) -> Result<()> {
+        order.validate()?;
+        self.check_symbol_rules(&order)?;
+        let request = build_signed_request(order.to_params(), self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await?;
+        Ok(())
+    }
+
+    // Place a LIMIT order - BUY
+    pub async fn limit_buy<S, F>(&self, symbol: S, qty: F, price: Number) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let order = SpotOrderRequest::new(symbol, OrderSide::Buy, OrderType::Limit)
+            .set_quantity(qty)
+            .set_price(price)
+            .set_time_in_force(TimeInForce::GTC);
+        self.place(order).await
+    }
+
     /// Place a test limit order - BUY
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
-    pub async fn test_limit_buy<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<()>
+    pub async fn test_limit_buy<S, F>(&self, symbol: S, qty: F, price: Number) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
-        let buy = OrderRequest {
-            symbol: symbol.into(),
-            qty: qty.into(),
-            price,
-            stop_price: None,
-            order_side: OrderSide::Buy,
-            order_type: OrderType::Limit,
-            time_in_force: TimeInForce::GTC,
-            new_client_order_id: None,
-        };
-        let order = self.build_order(buy);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
-            .await?;
-        Ok(())
+        let order = SpotOrderRequest::new(symbol, OrderSide::Buy, OrderType::Limit)
+            .set_quantity(qty)
+            .set_price(price)
+            .set_time_in_force(TimeInForce::GTC);
+        self.test(order).await
     }
 
     // Place a LIMIT order - SELL
-    pub async fn limit_sell<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<Transaction>
+    pub async fn limit_sell<S, F>(&self, symbol: S, qty: F, price: Number) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
-        let sell = OrderRequest {
-            symbol: symbol.into(),
-            qty: qty.into(),
-            price,
-            stop_price: None,
-            order_side: OrderSide::Sell,
-            order_type: OrderType::Limit,
-            time_in_force: TimeInForce::GTC,
-            new_client_order_id: None,
-        };
-        let order = self.build_order(sell);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Spot(Spot::Order), request)
-            .await
+        let order = SpotOrderRequest::new(symbol, OrderSide::Sell, OrderType::Limit)
+            .set_quantity(qty)
+            .set_price(price)
+            .set_time_in_force(TimeInForce::GTC);
+        self.place(order).await
     }
 
     /// Place a test LIMIT order - SELL
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
-    pub async fn test_limit_sell<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<()>
+    pub async fn test_limit_sell<S, F>(&self, symbol: S, qty: F, price: Number) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
-        let sell = OrderRequest {
-            symbol: symbol.into(),
-            qty: qty.into(),
-            price,
-            stop_price: None,
-            order_side: OrderSide::Sell,
-            order_type: OrderType::Limit,
-            time_in_force: TimeInForce::GTC,
-            new_client_order_id: None,
-        };
-        let order = self.build_order(sell);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
-            .await?;
-        Ok(())
+        let order = SpotOrderRequest::new(symbol, OrderSide::Sell, OrderType::Limit)
+            .set_quantity(qty)
+            .set_price(price)
+            .set_time_in_force(TimeInForce::GTC);
+        self.test(order).await
     }
 
     // Place a MARKET order - BUY
     pub async fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
-        let buy = OrderRequest {
-            symbol: symbol.into(),
-            qty: qty.into(),
-            price: 0.0,
-            stop_price: None,
-            order_side: OrderSide::Buy,
-            order_type: OrderType::Market,
-            time_in_force: TimeInForce::GTC,
-            new_client_order_id: None,
-        };
-        let order = self.build_order(buy);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Spot(Spot::Order), request)
-            .await
+        let order =
+            SpotOrderRequest::new(symbol, OrderSide::Buy, OrderType::Market).set_quantity(qty);
+        self.place(order).await
     }
 
     /// Place a test MARKET order - BUY
@@ -343,24 +1397,11 @@ impl Account {
     pub async fn test_market_buy<S, F>(&self, symbol: S, qty: F) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
-        let buy = OrderRequest {
-            symbol: symbol.into(),
-            qty: qty.into(),
-            price: 0.0,
-            stop_price: None,
-            order_side: OrderSide::Buy,
-            order_type: OrderType::Market,
-            time_in_force: TimeInForce::GTC,
-            new_client_order_id: None,
-        };
-        let order = self.build_order(buy);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
-            .await?;
-        Ok(())
+        let order =
+            SpotOrderRequest::new(symbol, OrderSide::Buy, OrderType::Market).set_quantity(qty);
+        self.test(order).await
     }
 
     // Place a MARKET order with quote quantity - BUY
@@ -369,22 +1410,11 @@ impl Account {
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
-        let buy = OrderQuoteQuantityRequest {
-            symbol: symbol.into(),
-            quote_order_qty: quote_order_qty.into(),
-            price: 0.0,
-            order_side: OrderSide::Buy,
-            order_type: OrderType::Market,
-            time_in_force: TimeInForce::GTC,
-            new_client_order_id: None,
-        };
-        let order = self.build_quote_quantity_order(buy);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Spot(Spot::Order), request)
-            .await
+        let order = SpotOrderRequest::new(symbol, OrderSide::Buy, OrderType::Market)
+            .set_quote_order_qty(quote_order_qty);
+        self.place(order).await
     }
 
     /// Place a test MARKET order with quote quantity - BUY
@@ -395,46 +1425,22 @@ impl Account {
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
-        let buy = OrderQuoteQuantityRequest {
-            symbol: symbol.into(),
-            quote_order_qty: quote_order_qty.into(),
-            price: 0.0,
-            order_side: OrderSide::Buy,
-            order_type: OrderType::Market,
-            time_in_force: TimeInForce::GTC,
-            new_client_order_id: None,
-        };
-        let order = self.build_quote_quantity_order(buy);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
-            .await?;
-        Ok(())
+        let order = SpotOrderRequest::new(symbol, OrderSide::Buy, OrderType::Market)
+            .set_quote_order_qty(quote_order_qty);
+        self.test(order).await
     }
 
     // Place a MARKET order - SELL
     pub async fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
-        let sell = OrderRequest {
-            symbol: symbol.into(),
-            qty: qty.into(),
-            price: 0.0,
-            stop_price: None,
-            order_side: OrderSide::Sell,
-            order_type: OrderType::Market,
-            time_in_force: TimeInForce::GTC,
-            new_client_order_id: None,
-        };
-        let order = self.build_order(sell);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Spot(Spot::Order), request)
-            .await
+        let order =
+            SpotOrderRequest::new(symbol, OrderSide::Sell, OrderType::Market).set_quantity(qty);
+        self.place(order).await
     }
 
     /// Place a test MARKET order - SELL
@@ -443,24 +1449,11 @@ impl Account {
     pub async fn test_market_sell<S, F>(&self, symbol: S, qty: F) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
-        let sell = OrderRequest {
-            symbol: symbol.into(),
-            qty: qty.into(),
-            price: 0.0,
-            stop_price: None,
-            order_side: OrderSide::Sell,
-            order_type: OrderType::Market,
-            time_in_force: TimeInForce::GTC,
-            new_client_order_id: None,
-        };
-        let order = self.build_order(sell);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
-            .await?;
-        Ok(())
+        let order =
+            SpotOrderRequest::new(symbol, OrderSide::Sell, OrderType::Market).set_quantity(qty);
+        self.test(order).await
     }
 
     // Place a MARKET order with quote quantity - SELL
@@ -469,22 +1462,11 @@ impl Account {
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
-        let sell = OrderQuoteQuantityRequest {
-            symbol: symbol.into(),
-            quote_order_qty: quote_order_qty.into(),
-            price: 0.0,
-            order_side: OrderSide::Sell,
-            order_type: OrderType::Market,
-            time_in_force: TimeInForce::GTC,
-            new_client_order_id: None,
-        };
-        let order = self.build_quote_quantity_order(sell);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Spot(Spot::Order), request)
-            .await
+        let order = SpotOrderRequest::new(symbol, OrderSide::Sell, OrderType::Market)
+            .set_quote_order_qty(quote_order_qty);
+        self.place(order).await
     }
 
     /// Place a test MARKET order with quote quantity - SELL
@@ -495,23 +1477,11 @@ impl Account {
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
-        let sell = OrderQuoteQuantityRequest {
-            symbol: symbol.into(),
-            quote_order_qty: quote_order_qty.into(),
-            price: 0.0,
-            order_side: OrderSide::Sell,
-            order_type: OrderType::Market,
-            time_in_force: TimeInForce::GTC,
-            new_client_order_id: None,
-        };
-        let order = self.build_quote_quantity_order(sell);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
-            .await?;
-        Ok(())
+        let order = SpotOrderRequest::new(symbol, OrderSide::Sell, OrderType::Market)
+            .set_quote_order_qty(quote_order_qty);
+        self.test(order).await
     }
 
     /// Create a stop limit buy order for the given symbol, price and stop price.
@@ -527,7 +1497,7 @@ impl Account {
     ///     let secret_key = Some("secret_key".into());
     ///     let account: Account = Binance::new(api_key, secret_key);
     ///
-    ///     match account.stop_limit_buy_order("BNBBTC", 1.0, 0.001, 0.0009).await {
+    ///     match account.stop_limit_buy_order("BNBBTC", 1, "0.001".parse()?, "0.0009".parse()?).await {
     ///         Ok(answer) => println!("{:#?}", answer),
     ///         Err(e) => println!("Error: {:#?}", e),
     ///     }
@@ -535,27 +1505,18 @@ impl Account {
     /// }
     /// ```
     pub async fn stop_limit_buy_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: f64,
+        &self, symbol: S, qty: F, price: Number, stop_price: Number,
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
-        let buy = OrderRequest {
-            symbol: symbol.into(),
-            qty: qty.into(),
-            price,
-            stop_price: Some(stop_price),
-            order_side: OrderSide::Buy,
-            order_type: OrderType::StopLossLimit,
-            time_in_force: TimeInForce::GTC,
-            new_client_order_id: None,
-        };
-        let order = self.build_order(buy);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Spot(Spot::Order), request)
-            .await
+        let order = SpotOrderRequest::new(symbol, OrderSide::Buy, OrderType::StopLossLimit)
+            .set_quantity(qty)
+            .set_price(price)
+            .set_stop_price(stop_price)
+            .set_time_in_force(TimeInForce::GTC);
+        self.place(order).await
     }
 
     /// Place a test Stop Limit Buy order
@@ -572,7 +1533,7 @@ impl Account {
     ///     let secret_key = Some("secret_key".into());
     ///     let account: Account = Binance::new(api_key, secret_key);
     ///
-    ///     match account.test_stop_limit_buy_order("BNBBTC", 1.0, 0.001, 0.0009).await {
+    ///     match account.test_stop_limit_buy_order("BNBBTC", 1, "0.001".parse()?, "0.0009".parse()?).await {
     ///         Ok(_answer) => println!("Test stop limit buy order placed successfully."),
     ///         Err(e) => println!("Error: {:#?}", e),
     ///     }
@@ -580,28 +1541,18 @@ impl Account {
     /// }
     /// ```
     pub async fn test_stop_limit_buy_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: f64,
+        &self, symbol: S, qty: F, price: Number, stop_price: Number,
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
-        let buy = OrderRequest {
-            symbol: symbol.into(),
-            qty: qty.into(),
-            price,
-            stop_price: Some(stop_price),
-            order_side: OrderSide::Buy,
-            order_type: OrderType::StopLossLimit,
-            time_in_force: TimeInForce::GTC,
-            new_client_order_id: None,
-        };
-        let order = self.build_order(buy);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
-            .await?;
-        Ok(())
+        let order = SpotOrderRequest::new(symbol, OrderSide::Buy, OrderType::StopLossLimit)
+            .set_quantity(qty)
+            .set_price(price)
+            .set_stop_price(stop_price)
+            .set_time_in_force(TimeInForce::GTC);
+        self.test(order).await
     }
 
     /// Create a stop limit sell order for the given symbol, price and stop price.
@@ -617,7 +1568,7 @@ impl Account {
     ///     let secret_key = Some("secret_key".into());
     ///     let account: Account = Binance::new(api_key, secret_key);
     ///
-    ///     match account.stop_limit_sell_order("BNBBTC", 1.0, 0.001, 0.0009).await {
+    ///     match account.stop_limit_sell_order("BNBBTC", 1, "0.001".parse()?, "0.0009".parse()?).await {
     ///         Ok(answer) => println!("{:#?}", answer),
     ///         Err(e) => println!("Error: {:#?}", e),
     ///     }
@@ -625,27 +1576,18 @@ impl Account {
     /// }
     /// ```
     pub async fn stop_limit_sell_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: f64,
+        &self, symbol: S, qty: F, price: Number, stop_price: Number,
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
-        let sell = OrderRequest {
-            symbol: symbol.into(),
-            qty: qty.into(),
-            price,
-            stop_price: Some(stop_price),
-            order_side: OrderSide::Sell,
-            order_type: OrderType::StopLossLimit,
-            time_in_force: TimeInForce::GTC,
-            new_client_order_id: None,
-        };
-        let order = self.build_order(sell);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Spot(Spot::Order), request)
-            .await
+        let order = SpotOrderRequest::new(symbol, OrderSide::Sell, OrderType::StopLossLimit)
+            .set_quantity(qty)
+            .set_price(price)
+            .set_stop_price(stop_price)
+            .set_time_in_force(TimeInForce::GTC);
+        self.place(order).await
     }
 
     /// Place a test Stop Limit Sell order
@@ -662,7 +1604,7 @@ impl Account {
     ///     let secret_key = Some("secret_key".into());
     ///     let account: Account = Binance::new(api_key, secret_key);
     ///
-    ///     match account.test_stop_limit_sell_order("BNBBTC", 1.0, 0.001, 0.0009).await {
+    ///     match account.test_stop_limit_sell_order("BNBBTC", 1, "0.001".parse()?, "0.0009".parse()?).await {
     ///         Ok(_answer) => println!("Test stop limit sell order placed successfully."),
     ///         Err(e) => println!("Error: {:#?}", e),
     ///     }
@@ -670,83 +1612,60 @@ impl Account {
     /// }
     /// ```
     pub async fn test_stop_limit_sell_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: f64,
+        &self, symbol: S, qty: F, price: Number, stop_price: Number,
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
-        let sell = OrderRequest {
-            symbol: symbol.into(),
-            qty: qty.into(),
-            price,
-            stop_price: Some(stop_price),
-            order_side: OrderSide::Sell,
-            order_type: OrderType::StopLossLimit,
-            time_in_force: TimeInForce::GTC,
-            new_client_order_id: None,
-        };
-        let order = self.build_order(sell);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
-            .await?;
-        Ok(())
+        let order = SpotOrderRequest::new(symbol, OrderSide::Sell, OrderType::StopLossLimit)
+            .set_quantity(qty)
+            .set_price(price)
+            .set_stop_price(stop_price)
+            .set_time_in_force(TimeInForce::GTC);
+        self.test(order).await
     }
 
     /// Create a custom order
     pub async fn custom_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, order_side: OrderSide, order_type: OrderType,
+        &self, symbol: S, qty: F, price: Number, order_side: OrderSide, order_type: OrderType,
         time_in_force: TimeInForce, new_client_order_id: Option<String>,
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
-        let custom_order = OrderRequest {
-            symbol: symbol.into(),
-            qty: qty.into(),
-            price,
-            stop_price: None,
-            order_side,
-            order_type,
-            time_in_force,
-            new_client_order_id,
-        };
-        let order = self.build_order(custom_order);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Spot(Spot::Order), request)
-            .await
+        let mut order = SpotOrderRequest::new(symbol, order_side, order_type).set_quantity(qty);
+        if order_type.requires_price() {
+            order = order.set_price(price);
+        }
+        if order_type.requires_time_in_force() {
+            order = order.set_time_in_force(time_in_force);
+        }
+        order.new_client_order_id = new_client_order_id;
+        self.place(order).await
     }
 
     /// Place a test custom order
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
     pub async fn test_custom_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, order_side: OrderSide, order_type: OrderType,
+        &self, symbol: S, qty: F, price: Number, order_side: OrderSide, order_type: OrderType,
         time_in_force: TimeInForce, new_client_order_id: Option<String>,
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
-        let custom_order = OrderRequest {
-            symbol: symbol.into(),
-            qty: qty.into(),
-            price,
-            stop_price: None,
-            order_side,
-            order_type,
-            time_in_force,
-            new_client_order_id,
-        };
-        let order = self.build_order(custom_order);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
-            .await?;
-        Ok(())
+        let mut order = SpotOrderRequest::new(symbol, order_side, order_type).set_quantity(qty);
+        if order_type.requires_price() {
+            order = order.set_price(price);
+        }
+        if order_type.requires_time_in_force() {
+            order = order.set_time_in_force(time_in_force);
+        }
+        order.new_client_order_id = new_client_order_id;
+        self.test(order).await
     }
 
     // Cancel an order
@@ -797,6 +1716,178 @@ impl Account {
         Ok(())
     }
 
+    /// Cancel an open order and place a new one atomically.
+    ///
+    /// When only one of the two steps succeeds, Binance answers with HTTP
+    /// 409 and the result of each step; it is returned as `Error::Http` with
+    /// that body and code -2021
+    /// ([`CancelReplaceOrTriggerRejected`](crate::errors::BinanceErrorCode::CancelReplaceOrTriggerRejected)).
+    /// When both fail the code is -2022
+    /// ([`CancelReplaceOrReduceOnlyRejected`](crate::errors::BinanceErrorCode::CancelReplaceOrReduceOnlyRejected)).
+    pub async fn cancel_replace(&self, request: CancelReplaceRequest) -> Result<CancelReplace> {
+        request.validate()?;
+        self.check_symbol_rules(&request.order)?;
+        let request = build_signed_request(request.to_params(), self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::CancelReplace), request)
+            .await
+    }
+
+    /// Place an OCO order on the deprecated `/api/v3/order/oco`, checked
+    /// against the symbol rules like the order lists below.
+    pub async fn place_oco(&self, order: OcoOrderRequest) -> Result<OrderList> {
+        order.validate()?;
+        self.check_oco_symbol_rules(&order)?;
+        let request = build_signed_request(order.to_params(), self.recv_window)?;
+        self.client.post_signed(API::Spot(Spot::Oco), request).await
+    }
+
+    /// Place an OCO order list. Each leg is checked like a
+    /// [`SpotOrderRequest`], including the symbol rules.
+    pub async fn place_order_list_oco(&self, order: OrderListOcoRequest) -> Result<OrderList> {
+        order.validate()?;
+        self.check_leg_symbol_rules(&order.symbol, &[&order.above, &order.below])?;
+        let request = build_signed_request(order.to_params(), self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::OrderListOco), request)
+            .await
+    }
+
+    /// Place an OTO (one-triggers-the-other) order list.
+    pub async fn place_order_list_oto(&self, order: OrderListOtoRequest) -> Result<OrderList> {
+        order.validate()?;
+        self.check_leg_symbol_rules(&order.symbol, &[&order.working, &order.pending])?;
+        let request = build_signed_request(order.to_params(), self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::OrderListOto), request)
+            .await
+    }
+
+    /// Place an OTOCO (one-triggers-a-one-cancels-the-other) order list.
+    pub async fn place_order_list_otoco(&self, order: OrderListOtocoRequest) -> Result<OrderList> {
+        order.validate()?;
+        self.check_leg_symbol_rules(
+            &order.symbol,
+            &[&order.working, &order.pending_above, &order.pending_below],
+        )?;
+        let request = build_signed_request(order.to_params(), self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::OrderListOtoco), request)
+            .await
+    }
+
+    fn check_leg_symbol_rules(&self, symbol: &str, legs: &[&OrderListLeg]) -> Result<()> {
+        for leg in legs {
+            self.check_symbol_rules(&leg.to_spot_order(symbol))?;
+        }
+        Ok(())
+    }
+
+    fn check_oco_symbol_rules(&self, order: &OcoOrderRequest) -> Result<()> {
+        let Some(rules) = self.symbol_rules.get(&order.symbol) else {
+            return Ok(());
+        };
+        rules.validate(Some(order.price), Some(order.quantity), false)?;
+        rules.check_price(order.stop_price)?;
+        if let Some(stop_limit_price) = order.stop_limit_price {
+            rules.validate(Some(stop_limit_price), Some(order.quantity), false)?;
+        }
+        Ok(())
+    }
+
+    // Cancel an order list and all its orders
+    pub async fn cancel_order_list<S>(&self, symbol: S, order_list_id: u64) -> Result<OrderList>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Spot(Spot::OrderList), Some(request))
+            .await
+    }
+
+    pub async fn cancel_order_list_with_client_id<S>(
+        &self, symbol: S, list_client_order_id: String,
+    ) -> Result<OrderList>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("listClientOrderId".into(), list_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Spot(Spot::OrderList), Some(request))
+            .await
+    }
+
+    // Check an order list's status
+    pub async fn order_list_status(&self, order_list_id: u64) -> Result<OrderList> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OrderList), Some(request))
+            .await
+    }
+
+    pub async fn order_list_status_with_client_id(
+        &self, list_client_order_id: String,
+    ) -> Result<OrderList> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("origClientOrderId".into(), list_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OrderList), Some(request))
+            .await
+    }
+
+    /// Order lists of all symbols, most recent last. Without filters the
+    /// most recent ones are returned.
+    pub async fn get_all_order_lists(
+        &self, request: OrderListHistoryRequest,
+    ) -> Result<Vec<OrderList>> {
+        if request.from_id.is_some() && (request.start_time.is_some() || request.end_time.is_some())
+        {
+            return Err(Error::InvalidOrder(
+                "fromId can't be combined with startTime or endTime".into(),
+            ));
+        }
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(from_id) = request.from_id {
+            parameters.insert("fromId".into(), from_id.to_string());
+        }
+        if let Some(start_time) = request.start_time {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = request.end_time {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = request.limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::AllOrderList), Some(request))
+            .await
+    }
+
+    // All open order lists
+    pub async fn get_open_order_lists(&self) -> Result<Vec<OrderList>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OpenOrderList), Some(request))
+            .await
+    }
+
     // Trade History
     pub async fn trade_history<S>(&self, symbol: S) -> Result<Vec<TradeHistory>>
     where
@@ -843,6 +1934,70 @@ impl Account {
             ))
         }
     }
+    // Trade History, one page of at most `limit` (max 1000) trades
+    pub async fn get_user_trades<S, F, N>(
+        &self, symbol: S, from_id: F, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<TradeHistory>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(fid) = from_id.into() {
+            parameters.insert("fromId".into(), fid.to_string());
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(lim) = limit.into() {
+            parameters.insert("limit".into(), lim.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::MyTrades), Some(request))
+            .await
+    }
+
+    /// Stream every trade from `start_time` to `end_time` (default now),
+    /// one day window and 1000 trades per request.
+    pub fn iter_trade_history<S, E>(
+        &self, symbol: S, start_time: u64, end_time: E,
+    ) -> impl Stream<Item = Result<TradeHistory>> + use<S, E>
+    where
+        S: Into<String>,
+        E: Into<Option<u64>>,
+    {
+        let account = self.clone();
+        let symbol = symbol.into();
+        let end_time = pagination::end_or_now(end_time.into());
+        pagination::by_id(
+            start_time,
+            end_time,
+            PageLimits::new(DAY, 1000),
+            move |page| {
+                let account = account.clone();
+                let symbol = symbol.clone();
+                async move {
+                    account
+                        .get_user_trades(
+                            symbol,
+                            page.from_id,
+                            page.start_time,
+                            page.end_time,
+                            page.limit,
+                        )
+                        .await
+                }
+            },
+        )
+    }
+
     async fn get_trades<S>(
         &self, symbol: S, start_time: u64, end_time: u64,
     ) -> Result<Vec<TradeHistory>>
@@ -861,47 +2016,4 @@ impl Account {
         trades.retain(|trade| trade.time <= end_time);
         Ok(trades)
     }
-
-    fn build_order(&self, order: OrderRequest) -> BTreeMap<String, String> {
-        let mut open_order: BTreeMap<String, String> = BTreeMap::new();
-
-        open_order.insert("symbol".into(), order.symbol);
-        open_order.insert("quantity".into(), format!("{}", order.qty));
-        open_order.insert("side".into(), order.order_side.to_string());
-        open_order.insert("type".into(), order.order_type.to_string());
-        if order.order_type == OrderType::Limit || order.order_type == OrderType::StopLossLimit {
-            open_order.insert("timeInForce".into(), order.time_in_force.to_string());
-            open_order.insert("price".into(), format!("{}", order.price));
-        }
-        if order.order_type == OrderType::StopLossLimit {
-            if let Some(stop_price) = order.stop_price {
-                open_order.insert("stopPrice".into(), format!("{}", stop_price));
-            }
-        }
-        if let Some(ref new_client_order_id) = order.new_client_order_id {
-            open_order.insert("newClientOrderId".into(), new_client_order_id.clone());
-        }
-
-        open_order
-    }
-
-    fn build_quote_quantity_order(
-        &self, order: OrderQuoteQuantityRequest,
-    ) -> BTreeMap<String, String> {
-        let mut open_order: BTreeMap<String, String> = BTreeMap::new();
-
-        open_order.insert("symbol".into(), order.symbol);
-        open_order.insert("quoteOrderQty".into(), format!("{}", order.quote_order_qty));
-        open_order.insert("side".into(), order.order_side.to_string());
-        open_order.insert("type".into(), order.order_type.to_string());
-        if order.order_type == OrderType::Limit {
-            open_order.insert("timeInForce".into(), order.time_in_force.to_string());
-            open_order.insert("price".into(), format!("{}", order.price));
-        }
-        if let Some(ref new_client_order_id) = order.new_client_order_id {
-            open_order.insert("newClientOrderId".into(), new_client_order_id.clone());
-        }
-
-        open_order
-    }
 }
@@ -1,20 +1,32 @@
 use crate::account::Account;
 use crate::client::Client;
+use crate::coin_futures::account::CoinFuturesAccount;
+use crate::coin_futures::general::CoinFuturesGeneral;
+use crate::coin_futures::market::CoinFuturesMarket;
+use crate::coin_futures::userstream::CoinFuturesUserStream;
 use crate::config::Config;
 use crate::futures::account::FuturesAccount;
 use crate::futures::general::FuturesGeneral;
 use crate::futures::market::FuturesMarket;
 use crate::futures::userstream::FuturesUserStream;
 use crate::general::General;
+use crate::margin::Margin;
 use crate::market::Market;
+use crate::options::account::OptionsAccount;
+use crate::options::general::OptionsGeneral;
+use crate::options::market::OptionsMarket;
+use crate::options::userstream::OptionsUserStream;
 use crate::userstream::UserStream;
 use crate::savings::Savings;
+use std::collections::HashMap;
 
 #[allow(clippy::all)]
 pub enum API {
     Spot(Spot),
     Savings(Sapi),
     Futures(Futures),
+    CoinFutures(CoinFutures),
+    Options(Options),
 }
 
 /// Endpoint for production and test orders.
@@ -35,12 +47,16 @@ pub enum Spot {
     BookTicker,
     Order,
     OrderTest,
+    CancelReplace,
     OpenOrders,
     AllOrders,
     Oco,
     OrderList,
     AllOrderList,
     OpenOrderList,
+    OrderListOco,
+    OrderListOto,
+    OrderListOtoco,
     Account,
     MyTrades,
     UserDataStream,
@@ -51,6 +67,18 @@ pub enum Sapi {
     AssetDetail,
     DepositAddress,
     SpotFuturesTransfer,
+    MarginBorrowRepay,
+    MarginOrder,
+    MarginOco,
+    MarginOrderList,
+    MarginOpenOrders,
+    MarginAccount,
+    IsolatedMarginAccount,
+    MarginMaxBorrowable,
+    MarginMaxTransferable,
+    MarginInterestHistory,
+    MarginUserDataStream,
+    IsolatedMarginUserDataStream,
 }
 
 pub enum Futures {
@@ -75,6 +103,7 @@ pub enum Futures {
     AllOrders,
     UserTrades,
     Order,
+    BatchOrders,
     PositionRisk,
     Balance,
     PositionSide,
@@ -84,6 +113,7 @@ pub enum Futures {
     TopLongShortPositionRatio,
     GlobalLongShortAccountRatio,
     TakerlongshortRatio,
+    Basis,
     LvtKlines,
     IndexInfo,
     ChangeInitialLeverage,
@@ -95,6 +125,57 @@ pub enum Futures {
     Income,
 }
 
+/// COIN-M futures (`/dapi`) routes.
+pub enum CoinFutures {
+    Ping,
+    Time,
+    ExchangeInfo,
+    Depth,
+    Trades,
+    AggTrades,
+    Klines,
+    PremiumIndex,
+    Ticker24hr,
+    TickerPrice,
+    BookTicker,
+    OpenInterest,
+    Order,
+    OpenOrders,
+    AllOpenOrders,
+    AllOrders,
+    UserTrades,
+    PositionRisk,
+    PositionSide,
+    ChangeInitialLeverage,
+    MarginType,
+    PositionMargin,
+    Account,
+    Balance,
+    Income,
+    UserDataStream,
+}
+
+/// European options (`/eapi`) routes.
+pub enum Options {
+    Ping,
+    Time,
+    ExchangeInfo,
+    Depth,
+    Trades,
+    Klines,
+    Mark,
+    Ticker,
+    Index,
+    OpenInterest,
+    Order,
+    OpenOrders,
+    AllOpenOrders,
+    Position,
+    Account,
+    UserTrades,
+    UserDataStream,
+}
+
 impl From<API> for String {
     fn from(item: API) -> Self {
         String::from(match item {
@@ -113,12 +194,16 @@ impl From<API> for String {
                 Spot::BookTicker => "/api/v3/ticker/bookTicker",
                 Spot::Order => "/api/v3/order",
                 Spot::OrderTest => "/api/v3/order/test",
+                Spot::CancelReplace => "/api/v3/order/cancelReplace",
                 Spot::OpenOrders => "/api/v3/openOrders",
                 Spot::AllOrders => "/api/v3/allOrders",
                 Spot::Oco => "/api/v3/order/oco",
                 Spot::OrderList => "/api/v3/orderList",
                 Spot::AllOrderList => "/api/v3/allOrderList",
                 Spot::OpenOrderList => "/api/v3/openOrderList",
+                Spot::OrderListOco => "/api/v3/orderList/oco",
+                Spot::OrderListOto => "/api/v3/orderList/oto",
+                Spot::OrderListOtoco => "/api/v3/orderList/otoco",
                 Spot::Account => "/api/v3/account",
                 Spot::MyTrades => "/api/v3/myTrades",
                 Spot::UserDataStream => "/api/v3/userDataStream",
@@ -128,6 +213,18 @@ impl From<API> for String {
                 Sapi::AssetDetail => "/sapi/v1/asset/assetDetail",
                 Sapi::DepositAddress => "/sapi/v1/capital/deposit/address",
                 Sapi::SpotFuturesTransfer => "/sapi/v1/futures/transfer",
+                Sapi::MarginBorrowRepay => "/sapi/v1/margin/borrow-repay",
+                Sapi::MarginOrder => "/sapi/v1/margin/order",
+                Sapi::MarginOco => "/sapi/v1/margin/order/oco",
+                Sapi::MarginOrderList => "/sapi/v1/margin/orderList",
+                Sapi::MarginOpenOrders => "/sapi/v1/margin/openOrders",
+                Sapi::MarginAccount => "/sapi/v1/margin/account",
+                Sapi::IsolatedMarginAccount => "/sapi/v1/margin/isolated/account",
+                Sapi::MarginMaxBorrowable => "/sapi/v1/margin/maxBorrowable",
+                Sapi::MarginMaxTransferable => "/sapi/v1/margin/maxTransferable",
+                Sapi::MarginInterestHistory => "/sapi/v1/margin/interestHistory",
+                Sapi::MarginUserDataStream => "/sapi/v1/userDataStream",
+                Sapi::IsolatedMarginUserDataStream => "/sapi/v1/userDataStream/isolated",
             },
             API::Futures(route) => match route {
                 Futures::Ping => "/fapi/v1/ping",
@@ -152,6 +249,7 @@ impl From<API> for String {
                 Futures::UserTrades => "/fapi/v1/userTrades",
                 Futures::PositionSide => "/fapi/v1/positionSide/dual",
                 Futures::Order => "/fapi/v1/order",
+                Futures::BatchOrders => "/fapi/v1/batchOrders",
                 Futures::PositionRisk => "/fapi/v2/positionRisk",
                 Futures::Balance => "/fapi/v2/balance",
                 Futures::OpenInterest => "/fapi/v1/openInterest",
@@ -160,6 +258,7 @@ impl From<API> for String {
                 Futures::TopLongShortPositionRatio => "/futures/data/topLongShortPositionRatio",
                 Futures::GlobalLongShortAccountRatio => "/futures/data/globalLongShortAccountRatio",
                 Futures::TakerlongshortRatio => "/futures/data/takerlongshortRatio",
+                Futures::Basis => "/futures/data/basis",
                 Futures::LvtKlines => "/fapi/v1/lvtKlines",
                 Futures::IndexInfo => "/fapi/v1/indexInfo",
                 Futures::ChangeInitialLeverage => "/fapi/v1/leverage",
@@ -170,10 +269,203 @@ impl From<API> for String {
                 Futures::UserDataStream => "/fapi/v1/listenKey",
                 Futures::Income => "/fapi/v1/income",
             },
+            API::CoinFutures(route) => match route {
+                CoinFutures::Ping => "/dapi/v1/ping",
+                CoinFutures::Time => "/dapi/v1/time",
+                CoinFutures::ExchangeInfo => "/dapi/v1/exchangeInfo",
+                CoinFutures::Depth => "/dapi/v1/depth",
+                CoinFutures::Trades => "/dapi/v1/trades",
+                CoinFutures::AggTrades => "/dapi/v1/aggTrades",
+                CoinFutures::Klines => "/dapi/v1/klines",
+                CoinFutures::PremiumIndex => "/dapi/v1/premiumIndex",
+                CoinFutures::Ticker24hr => "/dapi/v1/ticker/24hr",
+                CoinFutures::TickerPrice => "/dapi/v1/ticker/price",
+                CoinFutures::BookTicker => "/dapi/v1/ticker/bookTicker",
+                CoinFutures::OpenInterest => "/dapi/v1/openInterest",
+                CoinFutures::Order => "/dapi/v1/order",
+                CoinFutures::OpenOrders => "/dapi/v1/openOrders",
+                CoinFutures::AllOpenOrders => "/dapi/v1/allOpenOrders",
+                CoinFutures::AllOrders => "/dapi/v1/allOrders",
+                CoinFutures::UserTrades => "/dapi/v1/userTrades",
+                CoinFutures::PositionRisk => "/dapi/v1/positionRisk",
+                CoinFutures::PositionSide => "/dapi/v1/positionSide/dual",
+                CoinFutures::ChangeInitialLeverage => "/dapi/v1/leverage",
+                CoinFutures::MarginType => "/dapi/v1/marginType",
+                CoinFutures::PositionMargin => "/dapi/v1/positionMargin",
+                CoinFutures::Account => "/dapi/v1/account",
+                CoinFutures::Balance => "/dapi/v1/balance",
+                CoinFutures::Income => "/dapi/v1/income",
+                CoinFutures::UserDataStream => "/dapi/v1/listenKey",
+            },
+            API::Options(route) => match route {
+                Options::Ping => "/eapi/v1/ping",
+                Options::Time => "/eapi/v1/time",
+                Options::ExchangeInfo => "/eapi/v1/exchangeInfo",
+                Options::Depth => "/eapi/v1/depth",
+                Options::Trades => "/eapi/v1/trades",
+                Options::Klines => "/eapi/v1/klines",
+                Options::Mark => "/eapi/v1/mark",
+                Options::Ticker => "/eapi/v1/ticker",
+                Options::Index => "/eapi/v1/index",
+                Options::OpenInterest => "/eapi/v1/openInterest",
+                Options::Order => "/eapi/v1/order",
+                Options::OpenOrders => "/eapi/v1/openOrders",
+                Options::AllOpenOrders => "/eapi/v1/allOpenOrders",
+                Options::Position => "/eapi/v1/position",
+                Options::Account => "/eapi/v1/account",
+                Options::UserTrades => "/eapi/v1/userTrades",
+                Options::UserDataStream => "/eapi/v1/listenKey",
+            },
         })
     }
 }
 
+impl API {
+    /// Request weight of the endpoint for a single symbol. `limit` is the
+    /// request's `limit` parameter, which scales the weight of order book
+    /// and futures kline requests; `None` stands for the endpoint default.
+    /// Calls without a symbol can cost more; the used-weight headers of the
+    /// response correct the count.
+    pub fn weight(&self, limit: Option<u64>) -> u32 {
+        match self {
+            API::Spot(route) => match route {
+                Spot::Ping | Spot::Time => 1,
+                Spot::ExchangeInfo => 20,
+                Spot::Depth => spot_depth_weight(limit.unwrap_or(100)),
+                Spot::Trades | Spot::HistoricalTrades => 25,
+                Spot::AggTrades | Spot::Klines | Spot::AvgPrice => 2,
+                Spot::Ticker24hr | Spot::Price | Spot::BookTicker => 2,
+                Spot::Order | Spot::OrderTest | Spot::CancelReplace | Spot::Oco => 1,
+                Spot::OrderListOco | Spot::OrderListOto | Spot::OrderListOtoco => 1,
+                Spot::OpenOrders | Spot::OpenOrderList => 6,
+                Spot::OrderList => 4,
+                Spot::AllOrders | Spot::AllOrderList => 20,
+                Spot::Account | Spot::MyTrades => 20,
+                Spot::UserDataStream => 2,
+            },
+            API::Savings(route) => match route {
+                Sapi::AllCoins | Sapi::DepositAddress => 10,
+                Sapi::AssetDetail | Sapi::SpotFuturesTransfer => 1,
+                Sapi::MarginBorrowRepay | Sapi::MarginOrder | Sapi::MarginOco => 10,
+                Sapi::MarginOrderList | Sapi::MarginOpenOrders => 10,
+                Sapi::MarginAccount | Sapi::IsolatedMarginAccount => 10,
+                Sapi::MarginMaxBorrowable | Sapi::MarginMaxTransferable => 50,
+                Sapi::MarginInterestHistory => 1,
+                Sapi::MarginUserDataStream | Sapi::IsolatedMarginUserDataStream => 1,
+            },
+            API::Futures(route) => match route {
+                Futures::Ping | Futures::Time | Futures::ExchangeInfo => 1,
+                Futures::Depth => futures_depth_weight(limit.unwrap_or(500)),
+                Futures::Trades => 5,
+                Futures::HistoricalTrades | Futures::AggTrades => 20,
+                Futures::Klines
+                | Futures::ContinuousKlines
+                | Futures::IndexPriceKlines
+                | Futures::MarkPriceKlines => futures_kline_weight(limit.unwrap_or(500)),
+                Futures::PremiumIndex | Futures::FundingRate => 1,
+                Futures::Ticker24hr | Futures::TickerPrice => 1,
+                Futures::BookTicker => 2,
+                Futures::AllForceOrders => 20,
+                Futures::AllOpenOrders | Futures::Order | Futures::OpenOrders => 1,
+                Futures::AllOrders | Futures::UserTrades => 5,
+                Futures::BatchOrders => 5,
+                Futures::PositionRisk | Futures::Balance | Futures::Account => 5,
+                Futures::PositionSide => 1,
+                Futures::OpenInterest => 1,
+                // The /futures/data endpoints have their own limit and no weight.
+                Futures::OpenInterestHist
+                | Futures::TopLongShortAccountRatio
+                | Futures::TopLongShortPositionRatio
+                | Futures::GlobalLongShortAccountRatio
+                | Futures::TakerlongshortRatio
+                | Futures::Basis => 0,
+                Futures::LvtKlines | Futures::IndexInfo => 1,
+                Futures::ChangeInitialLeverage | Futures::MarginType | Futures::PositionMargin => 1,
+                Futures::UserDataStream => 1,
+                Futures::Income => 30,
+            },
+            API::CoinFutures(route) => match route {
+                CoinFutures::Ping | CoinFutures::Time | CoinFutures::ExchangeInfo => 1,
+                CoinFutures::Depth => futures_depth_weight(limit.unwrap_or(500)),
+                CoinFutures::Trades => 5,
+                CoinFutures::AggTrades => 20,
+                CoinFutures::Klines => futures_kline_weight(limit.unwrap_or(500)),
+                CoinFutures::PremiumIndex => 10,
+                CoinFutures::Ticker24hr | CoinFutures::TickerPrice => 1,
+                CoinFutures::BookTicker => 2,
+                CoinFutures::OpenInterest => 1,
+                CoinFutures::Order | CoinFutures::OpenOrders | CoinFutures::AllOpenOrders => 1,
+                CoinFutures::AllOrders | CoinFutures::UserTrades => 20,
+                CoinFutures::PositionRisk | CoinFutures::PositionSide => 1,
+                CoinFutures::ChangeInitialLeverage
+                | CoinFutures::MarginType
+                | CoinFutures::PositionMargin => 1,
+                CoinFutures::Account => 5,
+                CoinFutures::Balance | CoinFutures::UserDataStream => 1,
+                CoinFutures::Income => 20,
+            },
+            API::Options(route) => match route {
+                Options::Ping | Options::Time | Options::ExchangeInfo => 1,
+                Options::Depth => futures_depth_weight(limit.unwrap_or(100)),
+                Options::Trades | Options::Klines | Options::Mark => 5,
+                Options::Ticker | Options::Index => 1,
+                Options::OpenInterest => 0,
+                Options::Order | Options::AllOpenOrders => 1,
+                Options::OpenOrders => 40,
+                Options::Position | Options::Account => 5,
+                Options::UserTrades => 5,
+                Options::UserDataStream => 1,
+            },
+        }
+    }
+
+    /// Whether placing or modifying through this endpoint counts toward the ORDERS limits.
+    pub fn counts_as_order(&self) -> bool {
+        matches!(
+            self,
+            API::Spot(
+                Spot::Order
+                    | Spot::CancelReplace
+                    | Spot::Oco
+                    | Spot::OrderListOco
+                    | Spot::OrderListOto
+                    | Spot::OrderListOtoco
+            ) | API::Futures(Futures::Order | Futures::BatchOrders)
+                | API::CoinFutures(CoinFutures::Order)
+                | API::Options(Options::Order)
+                | API::Savings(Sapi::MarginOrder | Sapi::MarginOco)
+        )
+    }
+}
+
+fn spot_depth_weight(limit: u64) -> u32 {
+    match limit {
+        0..=100 => 5,
+        101..=500 => 25,
+        501..=1000 => 50,
+        _ => 250,
+    }
+}
+
+// Shared by the USD-M, COIN-M and options order books.
+fn futures_depth_weight(limit: u64) -> u32 {
+    match limit {
+        0..=50 => 2,
+        51..=100 => 5,
+        101..=500 => 10,
+        _ => 20,
+    }
+}
+
+fn futures_kline_weight(limit: u64) -> u32 {
+    match limit {
+        0..=99 => 1,
+        100..=499 => 2,
+        500..=1000 => 5,
+        _ => 10,
+    }
+}
+
 pub trait Binance {
     fn new(api_key: Option<String>, secret_key: Option<String>) -> Self;
     fn new_with_config(
@@ -190,7 +482,10 @@ impl Binance for General {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> General {
         General {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone())
+                .with_time_sync(API::Spot(Spot::Time), config.time_sync_interval)
+                .with_rate_limiter(config.rate_limiter.clone())
+                .with_signer(config.signer.clone()),
         }
     }
 }
@@ -204,8 +499,12 @@ impl Binance for Account {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> Account {
         Account {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone())
+                .with_time_sync(API::Spot(Spot::Time), config.time_sync_interval)
+                .with_rate_limiter(config.rate_limiter.clone())
+                .with_signer(config.signer.clone()),
             recv_window: config.recv_window,
+            symbol_rules: HashMap::new(),
         }
     }
 }
@@ -219,7 +518,28 @@ impl Binance for Savings {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone())
+                .with_time_sync(API::Spot(Spot::Time), config.time_sync_interval)
+                .with_rate_limiter(config.rate_limiter.clone())
+                .with_signer(config.signer.clone()),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for Margin {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone())
+                .with_time_sync(API::Spot(Spot::Time), config.time_sync_interval)
+                .with_rate_limiter(config.rate_limiter.clone())
+                .with_signer(config.signer.clone()),
             recv_window: config.recv_window,
         }
     }
@@ -234,7 +554,10 @@ impl Binance for Market {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> Market {
         Market {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone())
+                .with_time_sync(API::Spot(Spot::Time), config.time_sync_interval)
+                .with_rate_limiter(config.rate_limiter.clone())
+                .with_signer(config.signer.clone()),
             recv_window: config.recv_window,
         }
     }
@@ -249,7 +572,10 @@ impl Binance for UserStream {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> UserStream {
         UserStream {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone())
+                .with_time_sync(API::Spot(Spot::Time), config.time_sync_interval)
+                .with_rate_limiter(config.rate_limiter.clone())
+                .with_signer(config.signer.clone()),
             recv_window: config.recv_window,
         }
     }
@@ -272,7 +598,10 @@ impl Binance for FuturesGeneral {
                 api_key,
                 secret_key,
                 config.futures_rest_api_endpoint.clone(),
-            ),
+            )
+            .with_time_sync(API::Futures(Futures::Time), config.time_sync_interval)
+            .with_rate_limiter(config.futures_rate_limiter.clone())
+            .with_signer(config.signer.clone()),
         }
     }
 }
@@ -290,7 +619,10 @@ impl Binance for FuturesMarket {
                 api_key,
                 secret_key,
                 config.futures_rest_api_endpoint.clone(),
-            ),
+            )
+            .with_time_sync(API::Futures(Futures::Time), config.time_sync_interval)
+            .with_rate_limiter(config.futures_rate_limiter.clone())
+            .with_signer(config.signer.clone()),
             recv_window: config.recv_window,
         }
     }
@@ -309,8 +641,12 @@ impl Binance for FuturesAccount {
                 api_key,
                 secret_key,
                 config.futures_rest_api_endpoint.clone(),
-            ),
+            )
+            .with_time_sync(API::Futures(Futures::Time), config.time_sync_interval)
+            .with_rate_limiter(config.futures_rate_limiter.clone())
+            .with_signer(config.signer.clone()),
             recv_window: config.recv_window,
+            symbol_rules: HashMap::new(),
         }
     }
 }
@@ -328,8 +664,165 @@ impl Binance for FuturesUserStream {
                 api_key,
                 secret_key,
                 config.futures_rest_api_endpoint.clone(),
-            ),
+            )
+            .with_time_sync(API::Futures(Futures::Time), config.time_sync_interval)
+            .with_rate_limiter(config.futures_rate_limiter.clone())
+            .with_signer(config.signer.clone()),
             recv_window: config.recv_window,
         }
     }
 }
+
+// *****************************************************
+//              Binance COIN-M Futures API
+// *****************************************************
+
+impl Binance for CoinFuturesGeneral {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: coin_futures_client(api_key, secret_key, config),
+        }
+    }
+}
+
+impl Binance for CoinFuturesMarket {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: coin_futures_client(api_key, secret_key, config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for CoinFuturesAccount {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: coin_futures_client(api_key, secret_key, config),
+            recv_window: config.recv_window,
+            symbol_rules: HashMap::new(),
+        }
+    }
+}
+
+impl Binance for CoinFuturesUserStream {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: coin_futures_client(api_key, secret_key, config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+fn coin_futures_client(
+    api_key: Option<String>, secret_key: Option<String>, config: &Config,
+) -> Client {
+    Client::new(
+        api_key,
+        secret_key,
+        config.coin_futures_rest_api_endpoint.clone(),
+    )
+    .with_time_sync(
+        API::CoinFutures(CoinFutures::Time),
+        config.time_sync_interval,
+    )
+    .with_rate_limiter(config.coin_futures_rate_limiter.clone())
+    .with_signer(config.signer.clone())
+}
+
+// *****************************************************
+//              Binance European Options API
+// *****************************************************
+
+impl Binance for OptionsGeneral {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: options_client(api_key, secret_key, config),
+        }
+    }
+}
+
+impl Binance for OptionsMarket {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: options_client(api_key, secret_key, config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for OptionsAccount {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: options_client(api_key, secret_key, config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for OptionsUserStream {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: options_client(api_key, secret_key, config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+fn options_client(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Client {
+    Client::new(
+        api_key,
+        secret_key,
+        config.options_rest_api_endpoint.clone(),
+    )
+    .with_time_sync(API::Options(Options::Time), config.time_sync_interval)
+    .with_rate_limiter(config.options_rate_limiter.clone())
+    .with_signer(config.signer.clone())
+}
@@ -1,15 +1,258 @@
 use crate::errors::{Error, Result};
+use crate::time_sync::local_timestamp;
+use futures_util::stream::{SplitSink, SplitStream};
 use futures_util::{StreamExt, SinkExt};
 use tokio_tungstenite::{
     connect_async, tungstenite::protocol::Message, MaybeTlsStream, WebSocketStream,
 };
 use tokio::net::TcpStream;
 use url::Url;
+use std::collections::HashMap;
+use std::fmt::Display;
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::sync::{oneshot, Mutex};
 use serde::de::DeserializeOwned;
 use std::future::Future;
+use std::ops::Deref;
 use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::task::{AbortHandle, JoinHandle};
+use std::hash::{BuildHasher, Hasher};
+use std::collections::hash_map::RandomState;
+use serde::{Deserialize, Serialize};
+
+/// Connection lifecycle notifications delivered to the handler when a
+/// [`ReconnectPolicy`] is set on the client.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum ConnectionEvent {
+    /// The connection dropped. `reason` describes what ended it.
+    Disconnected { reason: String },
+    /// A reconnect attempt will be made once `delay` has elapsed.
+    Reconnecting { attempt: u32, delay: Duration },
+    /// The connection was re-established after `attempts` tries.
+    Reconnected { attempts: u32 },
+}
+
+/// An event together with the stream it arrived on and when.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StreamEvent<E> {
+    /// Stream name of the combined-stream envelope. `None` for messages that
+    /// arrive unwrapped (single-stream connections) and for [`ConnectionEvent`]s.
+    pub stream: Option<String>,
+    /// Local time the message was received, in milliseconds since the Unix epoch.
+    pub received_at: u64,
+    pub event: E,
+}
+
+impl<E> StreamEvent<E> {
+    /// Converts the event, keeping the stream name and receive time.
+    pub fn map<T, F: FnOnce(E) -> T>(self, f: F) -> StreamEvent<T> {
+        StreamEvent {
+            stream: self.stream,
+            received_at: self.received_at,
+            event: f(self.event),
+        }
+    }
+}
+
+/// A socket whose event loop runs on its own tokio task, returned by
+/// `spawn` on [`WebSockets`](crate::websockets::WebSockets) and the other
+/// market sockets.
+///
+/// It derefs to the socket, so `subscribe`, `unsubscribe` and
+/// `list_subscriptions` can be called while the loop runs. Awaiting it
+/// returns the result of the loop; dropping it leaves the loop running.
+pub struct SpawnedSocket<W> {
+    socket: Arc<W>,
+    task: JoinHandle<Result<()>>,
+}
+
+impl<W: Send + Sync + 'static> SpawnedSocket<W> {
+    pub(crate) fn spawn<F, Fut>(socket: W, run: F) -> Self
+    where
+        F: FnOnce(Arc<W>) -> Fut,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let socket = Arc::new(socket);
+        let task = tokio::spawn(run(Arc::clone(&socket)));
+        SpawnedSocket { socket, task }
+    }
+}
+
+impl<W> SpawnedSocket<W> {
+    /// Stops the event loop.
+    pub fn abort(&self) {
+        self.task.abort();
+    }
+
+    pub fn abort_handle(&self) -> AbortHandle {
+        self.task.abort_handle()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+impl<W> Deref for SpawnedSocket<W> {
+    type Target = W;
+
+    fn deref(&self) -> &W {
+        &self.socket
+    }
+}
+
+impl<W> Future for SpawnedSocket<W> {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        Pin::new(&mut self.task).poll(cx).map(|joined| {
+            joined.map_err(|e| Error::Custom(format!("Websocket task failed: {}", e)))?
+        })
+    }
+}
+
+/// Exponential backoff with jitter used by [`AsyncWebsocketClient`] to
+/// re-establish a dropped connection.
+///
+/// The delay before attempt `n` is `initial_backoff * multiplier^(n-1)`,
+/// capped at `max_backoff` and then scaled by a random factor in
+/// `[1 - jitter, 1 + jitter]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconnectPolicy {
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub multiplier: f64,
+    pub jitter: f64,
+    /// `None` retries forever.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: 0.2,
+            max_attempts: Some(10),
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    pub fn set_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    pub fn set_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    pub fn set_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn set_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn set_max_attempts<N: Into<Option<u32>>>(mut self, max_attempts: N) -> Self {
+        self.max_attempts = max_attempts.into();
+        self
+    }
+
+    /// Backoff before `attempt` (starting at 1), without jitter.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let millis = self.initial_backoff.as_millis() as f64 * self.multiplier.powi(exponent);
+        let capped = millis.min(self.max_backoff.as_millis() as f64);
+        Duration::from_millis(capped as u64)
+    }
+
+    /// Backoff before `attempt` with jitter applied.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let base = self.backoff(attempt).as_millis() as f64;
+        if self.jitter <= 0.0 {
+            return Duration::from_millis(base as u64);
+        }
+        let factor = 1.0 - self.jitter + 2.0 * self.jitter * random_unit();
+        Duration::from_millis((base * factor) as u64)
+    }
+
+    pub(crate) fn allows(&self, attempt: u32) -> bool {
+        self.max_attempts.is_none_or(|max| attempt <= max)
+    }
+}
+
+// Uniform value in [0, 1), seeded from the process-wide random hasher keys so
+// that concurrent clients do not retry in lockstep.
+fn random_unit() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+// How long `subscribe`, `unsubscribe` and `list_subscriptions` wait for the
+// server to acknowledge a request.
+const CONTROL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Reply to a SUBSCRIBE, UNSUBSCRIBE or LIST_SUBSCRIPTIONS request.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ControlResponse {
+    id: u64,
+    #[serde(default)]
+    result: serde_json::Value,
+    error: Option<ControlError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ControlError {
+    code: i64,
+    msg: String,
+}
+
+// Changes made on the live connection, replayed after a reconnect since the
+// connection URL only names the original streams.
+#[derive(Debug, Default)]
+struct SubscriptionChanges {
+    added: Vec<String>,
+    removed: Vec<String>,
+}
+
+impl SubscriptionChanges {
+    fn subscribe(&mut self, streams: &[String]) {
+        self.removed.retain(|s| !streams.contains(s));
+        for stream in streams {
+            if !self.added.contains(stream) {
+                self.added.push(stream.clone());
+            }
+        }
+    }
+
+    fn unsubscribe(&mut self, streams: &[String]) {
+        self.added.retain(|s| !streams.contains(s));
+        for stream in streams {
+            if !self.removed.contains(stream) {
+                self.removed.push(stream.clone());
+            }
+        }
+    }
+}
 
 /// A generic asynchronous WebSocket client.
 ///
@@ -17,110 +260,301 @@ use std::pin::Pin;
 /// H: The type of the handler function.
 pub struct AsyncWebsocketClient<'a, E, H>
 where
-    E: DeserializeOwned + Send + std::fmt::Debug + 'a, // Added Debug
-    H: FnMut(E) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> + Send + Sync + 'a,
+    E: DeserializeOwned + From<ConnectionEvent> + Send + std::fmt::Debug + 'a, // Added Debug
+    H: FnMut(StreamEvent<E>) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>
+        + Send
+        + Sync
+        + 'a,
 {
-    socket: Arc<Mutex<Option<WebSocketStream<MaybeTlsStream<TcpStream>>>>>,
+    // Split so that control messages can be sent while `event_loop` waits for the next message
+    sink: Arc<Mutex<Option<SplitSink<WsStream, Message>>>>,
+    stream: Arc<Mutex<Option<SplitStream<WsStream>>>>,
     handler: Arc<Mutex<H>>,
+    url: Mutex<Option<String>>,
+    reconnect_policy: Option<ReconnectPolicy>,
+    next_id: AtomicU64,
+    pending: std::sync::Mutex<HashMap<u64, oneshot::Sender<Result<serde_json::Value>>>>,
+    changes: std::sync::Mutex<SubscriptionChanges>,
     phantom: std::marker::PhantomData<&'a E>,
 }
 
 impl<'a, E, H> AsyncWebsocketClient<'a, E, H>
 where
-    E: DeserializeOwned + Send + std::fmt::Debug + 'a, // Added Debug
-    H: FnMut(E) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> + Send + Sync + 'a,
+    E: DeserializeOwned + From<ConnectionEvent> + Send + std::fmt::Debug + 'a, // Added Debug
+    H: FnMut(StreamEvent<E>) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>
+        + Send
+        + Sync
+        + 'a,
 {
     pub fn new(handler: H) -> Self {
         AsyncWebsocketClient {
-            socket: Arc::new(Mutex::new(None)),
+            sink: Arc::new(Mutex::new(None)),
+            stream: Arc::new(Mutex::new(None)),
             handler: Arc::new(Mutex::new(handler)),
+            url: Mutex::new(None),
+            reconnect_policy: None,
+            next_id: AtomicU64::new(1),
+            pending: std::sync::Mutex::new(HashMap::new()),
+            changes: std::sync::Mutex::new(SubscriptionChanges::default()),
             phantom: std::marker::PhantomData,
         }
     }
 
+    /// Reconnect to the last connected URL when the connection drops instead
+    /// of returning from `event_loop`. Passing `None` restores the default
+    /// behaviour of surfacing the disconnect as an error.
+    pub fn set_reconnect_policy<P: Into<Option<ReconnectPolicy>>>(&mut self, policy: P) {
+        self.reconnect_policy = policy.into();
+    }
+
+    pub fn reconnect_policy(&self) -> Option<&ReconnectPolicy> {
+        self.reconnect_policy.as_ref()
+    }
+
     pub async fn connect(&self, wss_url: &str) -> Result<()> {
+        self.open(wss_url).await?;
+        *self.changes.lock().unwrap() = SubscriptionChanges::default();
+        Ok(())
+    }
+
+    async fn open(&self, wss_url: &str) -> Result<()> {
         let url_obj = Url::parse(wss_url).map_err(Error::UrlParser)?;
         let (ws_stream, _response) = connect_async(url_obj.as_str()) // Convert Url to &str
             .await
             .map_err(Error::WebSocket)?;
 
-        let mut socket_guard = self.socket.lock().await;
-        *socket_guard = Some(ws_stream);
+        let (sink, stream) = ws_stream.split();
+        *self.sink.lock().await = Some(sink);
+        *self.stream.lock().await = Some(stream);
+        *self.url.lock().await = Some(wss_url.to_string());
         Ok(())
     }
 
     pub async fn disconnect(&self) -> Result<()> {
-        let mut socket_guard = self.socket.lock().await;
-        if let Some(stream) = socket_guard.as_mut() {
-            stream.close(None).await.map_err(Error::WebSocket)?;
-            *socket_guard = None;
+        let mut sink_guard = self.sink.lock().await;
+        if let Some(sink) = sink_guard.as_mut() {
+            sink.close().await.map_err(Error::WebSocket)?;
+            *sink_guard = None;
+            *self.url.lock().await = None;
+            // A running event loop holds the read half until the server's
+            // close frame arrives and drops it then.
+            if let Ok(mut stream_guard) = self.stream.try_lock() {
+                *stream_guard = None;
+            }
             Ok(())
         } else {
             Err(Error::Custom("Not connected".to_string()))
         }
     }
 
-    async fn handle_message_text(&self, msg_text: String) -> Result<()> {
-        // This parsing logic might need to be customized based on how
-        // Binance wraps multi-stream data or other specific message formats.
-        // For now, assuming direct deserialization or a simple 'data' field check.
+    /// Adds streams to the open connection and waits for the server to acknowledge them.
+    ///
+    /// The acknowledgement is read by `event_loop`, which must be running
+    /// concurrently (for example in the same `tokio::join!`).
+    pub async fn subscribe<S: Display>(&self, streams: &[S]) -> Result<()> {
+        let streams: Vec<String> = streams.iter().map(|s| s.to_string()).collect();
+        self.request("SUBSCRIBE", serde_json::json!(streams))
+            .await?;
+        self.changes.lock().unwrap().subscribe(&streams);
+        Ok(())
+    }
 
-        // Attempt direct deserialization
-        if let Ok(event) = serde_json::from_str::<E>(&msg_text) {
-            let mut handler_guard = self.handler.lock().await;
-            (handler_guard)(event).await?;
-            Ok(())
-        } else {
-            // If direct deserialization fails, check for a common "data" wrapper
-            // This is a simplified example; real-world scenarios might be more complex.
-            if let Ok(value) = serde_json::from_str::<serde_json::Value>(&msg_text) {
-                if let Some(data_val) = value.get("data") {
-                    match serde_json::from_value::<E>(data_val.clone()) {
-                        Ok(event) => {
-                            let mut handler_guard = self.handler.lock().await;
-                            (handler_guard)(event).await?;
-                            return Ok(());
-                        }
-                        Err(e_inner) => {
-                            return Err(Error::Json(e_inner));
-                        }
-                    }
-                }
-                // If not a "data" wrapper, or if that also fails to parse as E
-                if let Ok(stream_value) = serde_json::from_str::<serde_json::Value>(&msg_text) {
-                    if let Some(_stream_name) = stream_value.get("stream") {
-                        if let Some(data_val_stream) = stream_value.get("data") {
-                            match serde_json::from_value::<E>(data_val_stream.clone()) {
-                                Ok(event) => {
-                                    let mut handler_guard = self.handler.lock().await;
-                                    (handler_guard)(event).await?;
-                                    return Ok(());
-                                }
-                                Err(e_inner_stream) => {
-                                    return Err(Error::Json(e_inner_stream));
-                                }
-                            }
-                        }
-                    }
+    /// Removes streams from the open connection and waits for the acknowledgement.
+    pub async fn unsubscribe<S: Display>(&self, streams: &[S]) -> Result<()> {
+        let streams: Vec<String> = streams.iter().map(|s| s.to_string()).collect();
+        self.request("UNSUBSCRIBE", serde_json::json!(streams))
+            .await?;
+        self.changes.lock().unwrap().unsubscribe(&streams);
+        Ok(())
+    }
+
+    /// Streams the open connection is currently subscribed to.
+    pub async fn list_subscriptions(&self) -> Result<Vec<String>> {
+        let result = self
+            .request("LIST_SUBSCRIPTIONS", serde_json::Value::Null)
+            .await?;
+        Ok(serde_json::from_value(result)?)
+    }
+
+    async fn request(&self, method: &str, params: serde_json::Value) -> Result<serde_json::Value> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (tx, rx) = oneshot::channel();
+        self.pending.lock().unwrap().insert(id, tx);
+
+        if let Err(e) = self.send_control(id, method, params).await {
+            self.pending.lock().unwrap().remove(&id);
+            return Err(e);
+        }
+
+        match tokio::time::timeout(CONTROL_TIMEOUT, rx).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(_)) => Err(Error::Custom(format!(
+                "Connection closed before {} was acknowledged",
+                method
+            ))),
+            Err(_) => {
+                self.pending.lock().unwrap().remove(&id);
+                Err(Error::Custom(format!(
+                    "No acknowledgement for {} within {:?}",
+                    method, CONTROL_TIMEOUT
+                )))
+            }
+        }
+    }
+
+    async fn send_control(&self, id: u64, method: &str, params: serde_json::Value) -> Result<()> {
+        let mut request = serde_json::json!({ "method": method, "id": id });
+        if !params.is_null() {
+            request["params"] = params;
+        }
+        let mut sink_guard = self.sink.lock().await;
+        match sink_guard.as_mut() {
+            Some(sink) => sink
+                .send(Message::Text(request.to_string()))
+                .await
+                .map_err(Error::WebSocket),
+            None => Err(Error::Custom("Not connected".to_string())),
+        }
+    }
+
+    // Completes the request waiting for this response. Returns false if the
+    // message is not a control response.
+    fn handle_control_response(&self, message: &serde_json::Value) -> bool {
+        if message.get("id").is_none() {
+            return false;
+        }
+        let Ok(response) = ControlResponse::deserialize(message) else {
+            return false;
+        };
+        let outcome = match response.error {
+            Some(error) => Err(Error::Custom(format!(
+                "Websocket request {} failed: code: {}, message: {}",
+                response.id, error.code, error.msg
+            ))),
+            None => Ok(response.result),
+        };
+        // Replays after a reconnect have no waiter.
+        if let Some(tx) = self.pending.lock().unwrap().remove(&response.id) {
+            let _ = tx.send(outcome);
+        }
+        true
+    }
+
+    // Re-applies live subscription changes to a new connection without
+    // waiting; the event loop that would read the replies is the caller.
+    async fn replay_changes(&self) -> Result<()> {
+        let (added, removed) = {
+            let changes = self.changes.lock().unwrap();
+            (changes.added.clone(), changes.removed.clone())
+        };
+        if !added.is_empty() {
+            let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+            self.send_control(id, "SUBSCRIBE", serde_json::json!(added))
+                .await?;
+        }
+        if !removed.is_empty() {
+            let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+            self.send_control(id, "UNSUBSCRIBE", serde_json::json!(removed))
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn notify(&self, event: ConnectionEvent) -> Result<()> {
+        let mut handler_guard = self.handler.lock().await;
+        (handler_guard)(StreamEvent {
+            stream: None,
+            received_at: local_timestamp(),
+            event: E::from(event),
+        })
+        .await
+    }
+
+    // Called when the connection ends. Returns `Ok(())` once a new connection
+    // is up or if the client was disconnected on purpose, and the error that
+    // ended the connection if no policy is set or the attempts ran out.
+    async fn reconnect(
+        &self, cause: Error, running: &Arc<std::sync::atomic::AtomicBool>,
+    ) -> Result<()> {
+        *self.sink.lock().await = None;
+        *self.stream.lock().await = None;
+        // Requests in flight on the old connection will never be answered.
+        self.pending.lock().unwrap().clear();
+        let Some(url) = self.url.lock().await.clone() else {
+            return Ok(());
+        };
+        let Some(policy) = self.reconnect_policy.as_ref() else {
+            return Err(cause);
+        };
+
+        self.notify(ConnectionEvent::Disconnected {
+            reason: cause.to_string(),
+        })
+        .await?;
+
+        let mut last_error = cause;
+        let mut attempt = 1;
+        while policy.allows(attempt) {
+            if !running.load(std::sync::atomic::Ordering::Relaxed) {
+                return Ok(());
+            }
+            let delay = policy.delay(attempt);
+            self.notify(ConnectionEvent::Reconnecting { attempt, delay })
+                .await?;
+            tokio::time::sleep(delay).await;
+
+            match self.open(&url).await {
+                Ok(()) => {
+                    self.replay_changes().await?;
+                    return self
+                        .notify(ConnectionEvent::Reconnected { attempts: attempt })
+                        .await;
                 }
+                Err(e) => last_error = e,
             }
-            // If all attempts fail, return original direct deserialization error
-            Err(Error::Json(
-                serde_json::from_str::<E>(&msg_text).unwrap_err(),
-            ))
+            attempt += 1;
         }
+        Err(last_error)
+    }
+
+    // Parses the message once. Control responses complete their request;
+    // anything else is taken out of the combined-stream envelope, if any, and
+    // handed to the handler with the stream name.
+    async fn handle_message_text(&self, msg_text: &str, received_at: u64) -> Result<()> {
+        let value: serde_json::Value = serde_json::from_str(msg_text)?;
+        if self.handle_control_response(&value) {
+            return Ok(());
+        }
+        let (stream, data) = match value {
+            serde_json::Value::Object(mut envelope) if envelope.contains_key("stream") => {
+                let stream = match envelope.remove("stream") {
+                    Some(serde_json::Value::String(stream)) => Some(stream),
+                    _ => None,
+                };
+                (stream, envelope.remove("data").unwrap_or_default())
+            }
+            value => (None, value),
+        };
+        let event = StreamEvent {
+            stream,
+            received_at,
+            event: E::deserialize(data)?,
+        };
+        let mut handler_guard = self.handler.lock().await;
+        (handler_guard)(event).await
     }
 
     pub async fn event_loop(&self, running: Arc<std::sync::atomic::AtomicBool>) -> Result<()> {
         while running.load(std::sync::atomic::Ordering::Relaxed) {
-            let mut socket_guard = self.socket.lock().await;
-            if let Some(stream) = socket_guard.as_mut() {
+            let mut stream_guard = self.stream.lock().await;
+            if let Some(stream) = stream_guard.as_mut() {
                 match stream.next().await {
                     Some(Ok(message)) => {
-                        drop(socket_guard); // Release lock before handling message
+                        drop(stream_guard); // Release lock before handling message
                         match message {
                             Message::Text(text) => {
-                                if let Err(e) = self.handle_message_text(text).await {
+                                let received_at = local_timestamp();
+                                if let Err(e) = self.handle_message_text(&text, received_at).await {
                                     // Log error or propagate? For now, let's propagate critical parsing/handling errors.
                                     // Specific errors like pings being unhandled by user might be logged and continued.
                                     eprintln!("Error handling message: {:?}", e); // Temporary logging
@@ -131,22 +565,25 @@ where
                             }
                             Message::Binary(_) => { /* Handle binary data if necessary */ }
                             Message::Ping(payload) => {
-                                // Re-acquire lock to send Pong
-                                let mut new_socket_guard = self.socket.lock().await;
-                                if let Some(s) = new_socket_guard.as_mut() {
+                                let mut sink_guard = self.sink.lock().await;
+                                if let Some(s) = sink_guard.as_mut() {
                                     s.send(Message::Pong(payload))
                                         .await
                                         .map_err(Error::WebSocket)?;
                                 }
-                                drop(new_socket_guard);
+                                drop(sink_guard);
                             }
                             Message::Pong(_) => { /* Pong received */ }
                             Message::Close(close_frame) => {
                                 eprintln!("WebSocket closed by server: {:?}", close_frame);
-                                return Err(Error::Custom(format!(
-                                    "WebSocket closed by server: {:?}",
-                                    close_frame
-                                )));
+                                self.reconnect(
+                                    Error::Custom(format!(
+                                        "WebSocket closed by server: {:?}",
+                                        close_frame
+                                    )),
+                                    &running,
+                                )
+                                .await?;
                             }
                             Message::Frame(_) => { /* Low-level frame, usually not handled directly */
                             }
@@ -154,16 +591,22 @@ where
                     }
                     Some(Err(e)) => {
                         // WebSocket stream error
-                        return Err(Error::WebSocket(e));
+                        drop(stream_guard);
+                        self.reconnect(Error::WebSocket(e), &running).await?;
                     }
                     None => {
                         // Stream ended (disconnected)
-                        return Err(Error::Custom("WebSocket stream ended".to_string()));
+                        drop(stream_guard);
+                        self.reconnect(
+                            Error::Custom("WebSocket stream ended".to_string()),
+                            &running,
+                        )
+                        .await?;
                     }
                 }
             } else {
                 // Socket not connected, maybe wait and retry or break
-                drop(socket_guard);
+                drop(stream_guard);
                 tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
             }
         }
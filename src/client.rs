@@ -1,79 +1,171 @@
-use hex::encode as hex_encode;
-use hmac::{Hmac, Mac};
-use crate::errors::{BinanceContentError, Error, Result}; // Updated
+use crate::errors::{BinanceContentError, BinanceErrorCode, Error, HttpError, Result}; // Updated
 use reqwest::StatusCode;
 use reqwest::Response; // Updated
-use reqwest::header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT, CONTENT_TYPE};
-use sha2::Sha256;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT, CONTENT_TYPE, RETRY_AFTER};
 use serde::de::DeserializeOwned;
 use crate::api::API;
+use crate::model::ServerTime;
+use crate::rate_limit::RateLimiter;
+use crate::signer::{sign_query, HmacSigner, Signer};
+use crate::time_sync::{local_timestamp, TimeOffset, TimeSync};
+use reqwest::Method;
+use std::sync::Arc;
+use std::time::Duration;
 
 #[derive(Clone)]
 pub struct Client {
     api_key: String,
-    secret_key: String,
+    signer: Arc<dyn Signer>,
     host: String,
     inner_client: reqwest::Client, // Updated
+    time_endpoint: Option<String>,
+    time_sync: Arc<TimeSync>,
+    rate_limiter: Arc<RateLimiter>,
 }
 
 impl Client {
     pub fn new(api_key: Option<String>, secret_key: Option<String>, host: String) -> Self {
         Client {
             api_key: api_key.unwrap_or_default(),
-            secret_key: secret_key.unwrap_or_default(),
+            signer: Arc::new(HmacSigner::new(secret_key.unwrap_or_default())),
             host,
             inner_client: reqwest::Client::builder() // Updated
                 .pool_idle_timeout(None)
                 .build()
                 .unwrap(),
+            time_endpoint: None,
+            time_sync: Arc::new(TimeSync::default()),
+            rate_limiter: Arc::new(RateLimiter::default()),
         }
     }
 
+    /// Tracks the server clock through `endpoint` (the spot or futures `/time`
+    /// route), refreshing every `refresh_interval` if one is given.
+    pub fn with_time_sync(mut self, endpoint: API, refresh_interval: Option<Duration>) -> Self {
+        self.time_endpoint = Some(String::from(endpoint));
+        self.time_sync = Arc::new(TimeSync::new(refresh_interval));
+        self
+    }
+
+    /// Signs with `signer` instead of HMAC with the secret key.
+    pub fn with_signer(mut self, signer: Option<Arc<dyn Signer>>) -> Self {
+        if let Some(signer) = signer {
+            self.signer = signer;
+        }
+        self
+    }
+
+    /// Shares `rate_limiter` with every other client that uses it.
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
     pub async fn get_signed<T: DeserializeOwned>(
         &self, endpoint: API, request: Option<String>,
     ) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
-        let client = &self.inner_client;
-        let response = client
-            .get(url.as_str())
-            .headers(self.build_headers(true)?)
-            .send()
-            .await?; // Updated
-
-        self.handler(response).await // Updated
+        self.send_signed(Method::GET, endpoint, request).await
     }
 
     pub async fn post_signed<T: DeserializeOwned>(
         &self, endpoint: API, request: String,
     ) -> Result<T> {
-        let url = self.sign_request(endpoint, Some(request));
-        let client = &self.inner_client;
-        let response = client
-            .post(url.as_str())
-            .headers(self.build_headers(true)?)
-            .send()
-            .await?; // Updated
+        self.send_signed(Method::POST, endpoint, Some(request))
+            .await
+    }
 
-        self.handler(response).await // Updated
+    pub async fn put_signed<T: DeserializeOwned>(
+        &self, endpoint: API, request: String,
+    ) -> Result<T> {
+        self.send_signed(Method::PUT, endpoint, Some(request)).await
     }
 
     pub async fn delete_signed<T: DeserializeOwned>(
         &self, endpoint: API, request: Option<String>,
     ) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
+        self.send_signed(Method::DELETE, endpoint, request).await
+    }
+
+    /// Last measured difference between the server clock and the local clock.
+    pub fn time_offset(&self) -> Option<TimeOffset> {
+        self.time_sync.offset()
+    }
+
+    pub fn time_sync(&self) -> &TimeSync {
+        &self.time_sync
+    }
+
+    /// Measures the server time offset now and applies it to later signed requests.
+    pub async fn sync_time(&self) -> Result<TimeOffset> {
+        let endpoint = self
+            .time_endpoint
+            .as_ref()
+            .ok_or_else(|| Error::Custom("No server time endpoint configured".to_string()))?;
+        let url = format!("{}{}", self.host, endpoint);
+
+        self.rate_limiter.acquire(1, false).await?;
+        let sent = local_timestamp();
+        let response = self.inner_client.get(url.as_str()).send().await?;
+        let server_time: ServerTime = self.handler(response).await?;
+        let received = local_timestamp();
+
+        let offset = TimeOffset::measure(sent, server_time.server_time, received);
+        self.time_sync.update(offset);
+        Ok(offset)
+    }
+
+    // Signs and sends the request, re-syncing the clock and retrying once if
+    // Binance reports the timestamp outside recvWindow.
+    async fn send_signed<T: DeserializeOwned>(
+        &self, method: Method, endpoint: API, request: Option<String>,
+    ) -> Result<T> {
+        let weight = endpoint.weight(query_limit(request.as_deref()));
+        let order = counts_as_order(&endpoint, &method);
+        let path = String::from(endpoint);
+        let can_sync = self.time_endpoint.is_some();
+        if can_sync && self.time_sync.is_stale() {
+            self.sync_time().await?;
+        }
+
+        match self
+            .try_send_signed(method.clone(), &path, request.as_deref(), weight, order)
+            .await
+        {
+            Err(ref e)
+                if can_sync && e.binance_code() == Some(BinanceErrorCode::InvalidTimestamp) =>
+            {
+                self.sync_time().await?;
+                self.try_send_signed(method, &path, request.as_deref(), weight, order)
+                    .await
+            }
+            result => result,
+        }
+    }
+
+    async fn try_send_signed<T: DeserializeOwned>(
+        &self, method: Method, path: &str, request: Option<&str>, weight: u32, order: bool,
+    ) -> Result<T> {
+        self.rate_limiter.acquire(weight, order).await?;
+        let url = self.sign_request(path, request)?;
         let client = &self.inner_client;
         let response = client
-            .delete(url.as_str())
+            .request(method, url.as_str())
             .headers(self.build_headers(true)?)
             .send()
-            .await?; // Updated
+            .await?;
 
-        self.handler(response).await // Updated
+        self.handler(response).await
     }
 
     pub async fn get<T: DeserializeOwned>(
         &self, endpoint: API, request: Option<String>,
     ) -> Result<T> {
+        let weight = endpoint.weight(query_limit(request.as_deref()));
+        self.rate_limiter.acquire(weight, false).await?;
         let mut url: String = format!("{}{}", self.host, String::from(endpoint));
         if let Some(request_str) = request {
             // Renamed to avoid conflict
@@ -89,72 +181,90 @@ impl Client {
     }
 
     pub async fn post<T: DeserializeOwned>(&self, endpoint: API) -> Result<T> {
-        let url: String = format!("{}{}", self.host, String::from(endpoint));
+        self.send_keyed(Method::POST, endpoint, None).await
+    }
 
-        let client = &self.inner_client;
-        let response = client
-            .post(url.as_str())
-            .headers(self.build_headers(false)?)
-            .send()
-            .await?; // Updated
+    pub async fn put<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
+        self.send_keyed(
+            Method::PUT,
+            endpoint,
+            Some(format!("listenKey={}", listen_key)),
+        )
+        .await
+    }
 
-        self.handler(response).await // Updated
+    pub async fn delete<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
+        self.send_keyed(
+            Method::DELETE,
+            endpoint,
+            Some(format!("listenKey={}", listen_key)),
+        )
+        .await
     }
 
-    pub async fn put<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
-        let url: String = format!("{}{}", self.host, String::from(endpoint));
-        let data: String = format!("listenKey={}", listen_key);
+    /// Like [`post`](Self::post), with form parameters for API-key-only
+    /// endpoints that take more than a listen key.
+    pub async fn post_with_params<T: DeserializeOwned>(
+        &self, endpoint: API, request: String,
+    ) -> Result<T> {
+        self.send_keyed(Method::POST, endpoint, Some(request)).await
+    }
 
-        let client = &self.inner_client;
-        let response = client
-            .put(url.as_str())
-            .headers(self.build_headers(false)?)
-            .body(data)
-            .send()
-            .await?; // Updated
+    pub async fn put_with_params<T: DeserializeOwned>(
+        &self, endpoint: API, request: String,
+    ) -> Result<T> {
+        self.send_keyed(Method::PUT, endpoint, Some(request)).await
+    }
 
-        self.handler(response).await // Updated
+    pub async fn delete_with_params<T: DeserializeOwned>(
+        &self, endpoint: API, request: String,
+    ) -> Result<T> {
+        self.send_keyed(Method::DELETE, endpoint, Some(request))
+            .await
     }
 
-    pub async fn delete<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
+    // Sends a request authenticated by the API key only, with `body` as form data.
+    async fn send_keyed<T: DeserializeOwned>(
+        &self, method: Method, endpoint: API, body: Option<String>,
+    ) -> Result<T> {
+        let weight = endpoint.weight(query_limit(body.as_deref()));
+        self.rate_limiter.acquire(weight, false).await?;
         let url: String = format!("{}{}", self.host, String::from(endpoint));
-        let data: String = format!("listenKey={}", listen_key);
 
-        let client = &self.inner_client;
-        let response = client
-            .delete(url.as_str())
-            .headers(self.build_headers(false)?)
-            .body(data)
-            .send()
-            .await?; // Updated
+        let mut request = self
+            .inner_client
+            .request(method, url.as_str())
+            .headers(self.build_headers(false)?);
+        if let Some(data) = body {
+            request = request.body(data);
+        }
+        let response = request.send().await?;
 
-        self.handler(response).await // Updated
+        self.handler(response).await
     }
 
     // Request must be signed
-    fn sign_request(&self, endpoint: API, request: Option<String>) -> String {
-        if let Some(request_str) = request {
-            // Renamed to avoid conflict
-            let mut signed_key =
-                Hmac::<Sha256>::new_from_slice(self.secret_key.as_bytes()).unwrap();
-            signed_key.update(request_str.as_bytes());
-            let signature = hex_encode(signed_key.finalize().into_bytes());
-            let request_body: String = format!("{}&signature={}", request_str, signature);
-            format!("{}{}?{}", self.host, String::from(endpoint), request_body)
-        } else {
-            // HMAC for empty query string still needs a timestamp if that's part of the requirements,
-            // but current logic doesn't include it. Assuming it's correct.
-            let mut signed_key =
-                Hmac::<Sha256>::new_from_slice(self.secret_key.as_bytes()).unwrap();
-            // If there's no request string, what are we signing?
-            // Binance typically requires a timestamp even for parameter-less signed requests.
-            // For now, replicating existing logic of signing an empty string if request is None.
-            // This might need review based on Binance API spec for parameter-less signed endpoints.
-            signed_key.update(b""); // Sign an empty string if no params
-            let signature = hex_encode(signed_key.finalize().into_bytes());
-            let request_body: String = format!("signature={}", signature); // Removed leading '&'
-            format!("{}{}?{}", self.host, String::from(endpoint), request_body)
+    fn sign_request(&self, path: &str, request: Option<&str>) -> Result<String> {
+        // Parameter-less signed requests sign the empty string
+        let request_str = request.map(|r| self.stamp(r)).unwrap_or_default();
+        let request_body = sign_query(self.signer.as_ref(), &request_str)?;
+        Ok(format!("{}{}?{}", self.host, path, request_body))
+    }
+
+    // Replaces the local `timestamp` parameter with server time once an offset is known
+    fn stamp(&self, request: &str) -> String {
+        if self.time_sync.offset().is_none() {
+            return request.to_string();
         }
+        let timestamp = self.time_sync.timestamp();
+        request
+            .split('&')
+            .map(|param| match param.strip_prefix("timestamp=") {
+                Some(_) => format!("timestamp={}", timestamp),
+                None => param.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("&")
     }
 
     fn build_headers(&self, content_type: bool) -> Result<HeaderMap> {
@@ -177,29 +287,46 @@ impl Client {
 
     async fn handler<T: DeserializeOwned>(&self, response: Response) -> Result<T> {
         // Updated
-        match response.status() {
-            StatusCode::OK => Ok(response.json::<T>().await?), // Updated
-            StatusCode::INTERNAL_SERVER_ERROR => {
-                Err(Error::Custom("Internal Server Error".to_string()))
-            }
-            StatusCode::SERVICE_UNAVAILABLE => {
-                Err(Error::Custom("Service Unavailable".to_string()))
-            }
-            StatusCode::UNAUTHORIZED => Err(Error::Custom("Unauthorized".to_string())),
-            StatusCode::BAD_REQUEST => {
-                let error_content = response.text().await?; // Read as text first for better error diagnosis
-                match serde_json::from_str::<BinanceContentError>(&error_content) {
-                    Ok(binance_error) => Err(Error::BinanceError(binance_error)),
-                    Err(json_err) => Err(Error::Custom(format!(
-                        "Failed to parse Binance error response (HTTP 400): {}. Original content: {}",
-                        json_err, error_content
-                    ))),
-                }
-            }
-            s => Err(Error::Custom(format!(
-                "Received unexpected status code: {:?}",
-                s
-            ))),
+        self.rate_limiter.update_from_headers(response.headers());
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response.json::<T>().await?); // Updated
         }
+
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(Duration::from_secs);
+        if status == StatusCode::TOO_MANY_REQUESTS || status == StatusCode::IM_A_TEAPOT {
+            self.rate_limiter
+                .block_for(retry_after.unwrap_or(DEFAULT_BAN));
+        }
+
+        // Binance sends {"code": .., "msg": ..} with most error statuses; keep the raw body otherwise
+        let body = response.text().await?;
+        let content = serde_json::from_str::<BinanceContentError>(&body).ok();
+        Err(Error::Http(HttpError {
+            status,
+            content,
+            body,
+            retry_after,
+        }))
     }
 }
+
+// How long to back off after a 429/418 that carries no Retry-After header
+const DEFAULT_BAN: Duration = Duration::from_secs(60);
+
+// The `limit` parameter of a query, which scales some endpoint weights.
+fn query_limit(query: Option<&str>) -> Option<u64> {
+    query?
+        .split('&')
+        .find_map(|param| param.strip_prefix("limit="))
+        .and_then(|limit| limit.parse().ok())
+}
+
+fn counts_as_order(endpoint: &API, method: &Method) -> bool {
+    endpoint.counts_as_order() && (method == Method::POST || method == Method::PUT)
+}
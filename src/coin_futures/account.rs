@@ -0,0 +1,381 @@
+/*!
+COIN-M account, positions and orders. Quantities are a number of contracts;
+order parameters and enums are shared with the USD-M
+[`FuturesAccount`](crate::futures::account::FuturesAccount).
+*/
+
+use std::collections::{BTreeMap, HashMap};
+use crate::util::build_signed_request;
+use crate::errors::Result;
+use crate::client::Client;
+use crate::symbol_rules::SymbolRules;
+use crate::api::{API, CoinFutures};
+use crate::model::{Empty, Number};
+use crate::account::OrderSide;
+use crate::futures::account::{
+    build_order_params, CustomOrderRequest, IncomeRequest, OrderType, TimeInForce,
+};
+use crate::coin_futures::model::{
+    AccountBalance, AccountInformation, ChangeLeverageResponse, Income, Order, PositionRisk,
+    TradeHistory,
+};
+
+#[derive(Clone)]
+pub struct CoinFuturesAccount {
+    pub client: Client,
+    pub recv_window: u64,
+    /// Filters checked before orders for these symbols are sent, keyed by symbol.
+    pub symbol_rules: HashMap<String, SymbolRules>,
+}
+
+impl CoinFuturesAccount {
+    /// Check orders for `rules.symbol` against its exchange filters before
+    /// they are sent. Violations fail with `Error::Filter`.
+    pub fn set_symbol_rules(&mut self, rules: SymbolRules) {
+        self.symbol_rules.insert(rules.symbol.clone(), rules);
+    }
+
+    pub async fn limit_buy(
+        &self, symbol: impl Into<String>, qty: impl Into<Number>, price: Number,
+        time_in_force: TimeInForce,
+    ) -> Result<Order> {
+        self.custom_order(simple_order(
+            symbol.into(),
+            OrderSide::Buy,
+            OrderType::Limit,
+            qty.into(),
+            Some(price),
+            Some(time_in_force),
+        ))
+        .await
+    }
+
+    pub async fn limit_sell(
+        &self, symbol: impl Into<String>, qty: impl Into<Number>, price: Number,
+        time_in_force: TimeInForce,
+    ) -> Result<Order> {
+        self.custom_order(simple_order(
+            symbol.into(),
+            OrderSide::Sell,
+            OrderType::Limit,
+            qty.into(),
+            Some(price),
+            Some(time_in_force),
+        ))
+        .await
+    }
+
+    pub async fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Order>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        self.custom_order(simple_order(
+            symbol.into(),
+            OrderSide::Buy,
+            OrderType::Market,
+            qty.into(),
+            None,
+            None,
+        ))
+        .await
+    }
+
+    pub async fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<Order>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        self.custom_order(simple_order(
+            symbol.into(),
+            OrderSide::Sell,
+            OrderType::Market,
+            qty.into(),
+            None,
+            None,
+        ))
+        .await
+    }
+
+    pub async fn custom_order(&self, order_request: CustomOrderRequest) -> Result<Order> {
+        let order_params = build_order_params(&self.symbol_rules, order_request.into())?;
+        let request = build_signed_request(order_params, self.recv_window)?;
+        self.client
+            .post_signed(API::CoinFutures(CoinFutures::Order), request)
+            .await
+    }
+
+    pub async fn order_status<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::CoinFutures(CoinFutures::Order), Some(request))
+            .await
+    }
+
+    pub async fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::CoinFutures(CoinFutures::Order), Some(request))
+            .await
+    }
+
+    pub async fn cancel_order_with_client_id<S>(
+        &self, symbol: S, orig_client_order_id: String,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::CoinFutures(CoinFutures::Order), Some(request))
+            .await
+    }
+
+    pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed::<Empty>(API::CoinFutures(CoinFutures::AllOpenOrders), Some(request))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_all_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::CoinFutures(CoinFutures::OpenOrders), Some(request))
+            .await
+    }
+
+    pub async fn get_all_orders<S, F, N>(
+        &self, symbol: S, order_id: F, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<Order>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(oid) = order_id.into() {
+            parameters.insert("orderId".into(), oid.to_string());
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(lim) = limit.into() {
+            parameters.insert("limit".into(), lim.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::CoinFutures(CoinFutures::AllOrders), Some(request))
+            .await
+    }
+
+    pub async fn get_user_trades<S, F, N>(
+        &self, symbol: S, from_id: F, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<TradeHistory>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(fid) = from_id.into() {
+            parameters.insert("fromId".into(), fid.to_string());
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(lim) = limit.into() {
+            parameters.insert("limit".into(), lim.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::CoinFutures(CoinFutures::UserTrades), Some(request))
+            .await
+    }
+
+    // Positions of every contract of a pair, e.g. "BTCUSD"
+    pub async fn position_information<S>(&self, pair: S) -> Result<Vec<PositionRisk>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("pair".into(), pair.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::CoinFutures(CoinFutures::PositionRisk), Some(request))
+            .await
+    }
+
+    pub async fn all_position_information(&self) -> Result<Vec<PositionRisk>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::CoinFutures(CoinFutures::PositionRisk), Some(request))
+            .await
+    }
+
+    pub async fn account_information(&self) -> Result<AccountInformation> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::CoinFutures(CoinFutures::Account), Some(request))
+            .await
+    }
+
+    pub async fn account_balance(&self) -> Result<Vec<AccountBalance>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::CoinFutures(CoinFutures::Balance), Some(request))
+            .await
+    }
+
+    pub async fn change_initial_leverage<S>(
+        &self, symbol: S, leverage: u8,
+    ) -> Result<ChangeLeverageResponse>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("leverage".into(), leverage.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(
+                API::CoinFutures(CoinFutures::ChangeInitialLeverage),
+                request,
+            )
+            .await
+    }
+
+    pub async fn change_margin_type<S>(&self, symbol: S, isolated: bool) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert(
+            "marginType".into(),
+            if isolated {
+                "ISOLATED".to_string()
+            } else {
+                "CROSSED".to_string()
+            },
+        );
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::CoinFutures(CoinFutures::MarginType), request)
+            .await?;
+        Ok(())
+    }
+
+    // margin_type: 1 adds margin to an isolated position, 2 reduces it
+    pub async fn change_position_margin<S>(
+        &self, symbol: S, amount: Number, margin_type: u8,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("amount".into(), amount.to_string());
+        parameters.insert("type".into(), margin_type.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::CoinFutures(CoinFutures::PositionMargin), request)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn change_position_mode(&self, dual_side_position: bool) -> Result<()> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("dualSidePosition".into(), dual_side_position.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::CoinFutures(CoinFutures::PositionSide), request)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_income(&self, income_request: IncomeRequest) -> Result<Vec<Income>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(symbol) = income_request.symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+        if let Some(income_type) = income_request.income_type {
+            parameters.insert("incomeType".into(), income_type.to_string());
+        }
+        if let Some(start_time) = income_request.start_time {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = income_request.end_time {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = income_request.limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::CoinFutures(CoinFutures::Income), Some(request))
+            .await
+    }
+}
+
+fn simple_order(
+    symbol: String, side: OrderSide, order_type: OrderType, quantity: Number,
+    price: Option<Number>, time_in_force: Option<TimeInForce>,
+) -> CustomOrderRequest {
+    CustomOrderRequest {
+        symbol,
+        side,
+        position_side: None,
+        order_type,
+        time_in_force,
+        quantity: Some(quantity),
+        reduce_only: None,
+        price,
+        new_client_order_id: None,
+        stop_price: None,
+        close_position: None,
+        activation_price: None,
+        callback_rate: None,
+        working_type: None,
+        price_protect: None,
+    }
+}
@@ -0,0 +1,56 @@
+use crate::model::Empty;
+use crate::coin_futures::model::{ExchangeInformation, ServerTime, Symbol};
+use crate::client::Client;
+use crate::errors::{Result, Error};
+use crate::api::API;
+use crate::api::CoinFutures;
+
+#[derive(Clone)]
+pub struct CoinFuturesGeneral {
+    pub client: Client,
+}
+
+impl CoinFuturesGeneral {
+    // Test connectivity
+    pub async fn ping(&self) -> Result<String> {
+        self.client
+            .get::<Empty>(API::CoinFutures(CoinFutures::Ping), None)
+            .await?;
+        Ok("pong".into())
+    }
+
+    // Check server time
+    pub async fn get_server_time(&self) -> Result<ServerTime> {
+        self.client
+            .get(API::CoinFutures(CoinFutures::Time), None)
+            .await
+    }
+
+    // Obtain exchange information
+    // - Current exchange trading rules and symbol information
+    // - Also applies the reported rate limits to the client's rate limiter
+    pub async fn exchange_info(&self) -> Result<ExchangeInformation> {
+        let info: ExchangeInformation = self
+            .client
+            .get(API::CoinFutures(CoinFutures::ExchangeInfo), None)
+            .await?;
+        self.client
+            .rate_limiter()
+            .set_exchange_limits(&info.rate_limits);
+        Ok(info)
+    }
+
+    // Get Symbol information, e.g. "BTCUSD_PERP" or "BTCUSD_250926"
+    pub async fn get_symbol_info<S>(&self, symbol: S) -> Result<Symbol>
+    where
+        S: Into<String>,
+    {
+        let upper_symbol = symbol.into().to_uppercase();
+        self.exchange_info()
+            .await?
+            .symbols
+            .into_iter()
+            .find(|item| item.symbol == upper_symbol)
+            .ok_or_else(|| Error::Custom("Symbol not found".to_string()))
+    }
+}
@@ -0,0 +1,226 @@
+/*!
+COIN-M market data. The ticker endpoints answer with a list even when asked
+for one symbol, and accept a `pair` (e.g. "BTCUSD") to get every contract
+of that pair.
+*/
+
+use crate::util::build_request;
+use crate::coin_futures::model::{
+    AggTrades, BookTicker, KlineSummaries, KlineSummary, MarkPrice, OpenInterest, OrderBook,
+    PriceStats, SymbolPrice, Trade,
+};
+use crate::client::Client;
+use crate::errors::Result;
+use std::collections::BTreeMap;
+use serde_json::Value;
+use crate::api::API;
+use crate::api::CoinFutures;
+
+#[derive(Clone)]
+pub struct CoinFuturesMarket {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl CoinFuturesMarket {
+    // Order book (Default 500; max 1000)
+    pub async fn get_depth<S>(&self, symbol: S) -> Result<OrderBook>
+    where
+        S: Into<String>,
+    {
+        self.get_with(CoinFutures::Depth, "symbol", symbol.into())
+            .await
+    }
+
+    // Order book at a custom depth. Currently supported values
+    // are 5, 10, 20, 50, 100, 500, 1000
+    pub async fn get_custom_depth<S>(&self, symbol: S, depth: u64) -> Result<OrderBook>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("limit".into(), depth.to_string());
+        let request = build_request(parameters);
+        self.client
+            .get(API::CoinFutures(CoinFutures::Depth), Some(request))
+            .await
+    }
+
+    pub async fn get_trades<S>(&self, symbol: S) -> Result<Vec<Trade>>
+    where
+        S: Into<String>,
+    {
+        self.get_with(CoinFutures::Trades, "symbol", symbol.into())
+            .await
+    }
+
+    pub async fn get_agg_trades<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, from_id: S2, start_time: S3, end_time: S4, limit: S5,
+    ) -> Result<AggTrades>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), format!("{}", st));
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), format!("{}", et));
+        }
+        if let Some(fi) = from_id.into() {
+            parameters.insert("fromId".into(), format!("{}", fi));
+        }
+        let request = build_request(parameters);
+        self.client
+            .get(API::CoinFutures(CoinFutures::AggTrades), Some(request))
+            .await
+    }
+
+    // Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...).
+    // The volume is in contracts and the quote asset volume in the base asset.
+    pub async fn get_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), format!("{}", st));
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), format!("{}", et));
+        }
+        let request = build_request(parameters);
+
+        let data: Vec<Vec<Value>> = self
+            .client
+            .get(API::CoinFutures(CoinFutures::Klines), Some(request))
+            .await?;
+
+        let klines = KlineSummaries::AllKlineSummaries(
+            data.iter()
+                .map(|row| row.try_into())
+                .collect::<Result<Vec<KlineSummary>>>()?,
+        );
+        Ok(klines)
+    }
+
+    // 24hr ticker price change statistics of one symbol
+    pub async fn get_24h_price_stats<S>(&self, symbol: S) -> Result<Vec<PriceStats>>
+    where
+        S: Into<String>,
+    {
+        self.get_with(CoinFutures::Ticker24hr, "symbol", symbol.into())
+            .await
+    }
+
+    // 24hr ticker price change statistics of every contract of a pair
+    pub async fn get_pair_24h_price_stats<S>(&self, pair: S) -> Result<Vec<PriceStats>>
+    where
+        S: Into<String>,
+    {
+        self.get_with(CoinFutures::Ticker24hr, "pair", pair.into())
+            .await
+    }
+
+    // 24hr ticker price change statistics for all symbols
+    pub async fn get_all_24h_price_stats(&self) -> Result<Vec<PriceStats>> {
+        self.client
+            .get(API::CoinFutures(CoinFutures::Ticker24hr), None)
+            .await
+    }
+
+    // Latest price for ONE symbol
+    pub async fn get_price<S>(&self, symbol: S) -> Result<Vec<SymbolPrice>>
+    where
+        S: Into<String>,
+    {
+        self.get_with(CoinFutures::TickerPrice, "symbol", symbol.into())
+            .await
+    }
+
+    // Latest price for every contract of a pair
+    pub async fn get_pair_prices<S>(&self, pair: S) -> Result<Vec<SymbolPrice>>
+    where
+        S: Into<String>,
+    {
+        self.get_with(CoinFutures::TickerPrice, "pair", pair.into())
+            .await
+    }
+
+    // Latest price for all symbols
+    pub async fn get_all_prices(&self) -> Result<Vec<SymbolPrice>> {
+        self.client
+            .get(API::CoinFutures(CoinFutures::TickerPrice), None)
+            .await
+    }
+
+    // Best price/qty on the order book for ONE symbol
+    pub async fn get_book_ticker<S>(&self, symbol: S) -> Result<Vec<BookTicker>>
+    where
+        S: Into<String>,
+    {
+        self.get_with(CoinFutures::BookTicker, "symbol", symbol.into())
+            .await
+    }
+
+    // Best price/qty on the order book for ALL symbols
+    pub async fn get_all_book_tickers(&self) -> Result<Vec<BookTicker>> {
+        self.client
+            .get(API::CoinFutures(CoinFutures::BookTicker), None)
+            .await
+    }
+
+    // Mark price, index price and funding rate of every contract of a pair
+    pub async fn get_pair_mark_prices<S>(&self, pair: S) -> Result<Vec<MarkPrice>>
+    where
+        S: Into<String>,
+    {
+        self.get_with(CoinFutures::PremiumIndex, "pair", pair.into())
+            .await
+    }
+
+    pub async fn get_mark_prices(&self) -> Result<Vec<MarkPrice>> {
+        self.client
+            .get(API::CoinFutures(CoinFutures::PremiumIndex), None)
+            .await
+    }
+
+    pub async fn open_interest<S>(&self, symbol: S) -> Result<OpenInterest>
+    where
+        S: Into<String>,
+    {
+        self.get_with(CoinFutures::OpenInterest, "symbol", symbol.into())
+            .await
+    }
+
+    async fn get_with<T: serde::de::DeserializeOwned>(
+        &self, route: CoinFutures, key: &str, value: String,
+    ) -> Result<T> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert(key.into(), value);
+        let request = build_request(parameters);
+        self.client
+            .get(API::CoinFutures(route), Some(request))
+            .await
+    }
+}
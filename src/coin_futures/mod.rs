@@ -0,0 +1,15 @@
+//! COIN-M (coin-margined) futures on `/dapi`: inverse perpetuals and
+//! quarterly delivery contracts, margined and settled in the base asset.
+//!
+//! The services mirror the USD-M ones in [`crate::futures`] and are built the
+//! same way, from `Config::coin_futures_rest_api_endpoint`. Market data
+//! streams go through [`FuturesWebSockets`] with [`FuturesMarket::COINM`].
+//!
+//! [`FuturesWebSockets`]: crate::futures::websockets::FuturesWebSockets
+//! [`FuturesMarket::COINM`]: crate::futures::websockets::FuturesMarket::COINM
+
+pub mod account;
+pub mod general;
+pub mod market;
+pub mod model;
+pub mod userstream;
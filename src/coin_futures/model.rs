@@ -0,0 +1,346 @@
+use serde::{Deserialize, Serialize};
+use crate::model::{string_or_float, string_or_float_opt, string_or_bool, Number};
+
+pub use crate::futures::model::{AggTrade, AggTrades, Income, OrderBook};
+pub use crate::model::{Asks, Bids, Filters, KlineSummaries, KlineSummary, RateLimit, ServerTime};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInformation {
+    pub timezone: String,
+    pub server_time: u64,
+    pub rate_limits: Vec<RateLimit>,
+    pub symbols: Vec<Symbol>,
+}
+
+/// A COIN-M contract. Order quantities are a number of contracts, each worth
+/// `contract_size` units of the quote asset (USD).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Symbol {
+    pub symbol: String,
+    pub pair: String,
+    pub contract_type: String,
+    pub delivery_date: u64,
+    pub onboard_date: u64,
+    pub contract_status: String,
+    pub contract_size: u64,
+    pub margin_asset: String,
+    pub maint_margin_percent: String,
+    pub required_margin_percent: String,
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub price_precision: u16,
+    pub quantity_precision: u16,
+    pub base_asset_precision: u64,
+    pub quote_precision: u64,
+    pub filters: Vec<Filters>,
+    #[serde(rename = "OrderType", default)]
+    pub order_types: Vec<String>,
+    pub time_in_force: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    pub is_buyer_maker: bool,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    /// Number of contracts.
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    /// Value of the trade in the base asset.
+    #[serde(with = "string_or_float")]
+    pub base_qty: Number,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceStats {
+    pub symbol: String,
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub price_change: Number,
+    #[serde(with = "string_or_float")]
+    pub price_change_percent: Number,
+    #[serde(with = "string_or_float")]
+    pub weighted_avg_price: Number,
+    #[serde(with = "string_or_float")]
+    pub last_price: Number,
+    #[serde(with = "string_or_float")]
+    pub last_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub open_price: Number,
+    #[serde(with = "string_or_float")]
+    pub high_price: Number,
+    #[serde(with = "string_or_float")]
+    pub low_price: Number,
+    /// Volume in contracts.
+    #[serde(with = "string_or_float")]
+    pub volume: Number,
+    /// Volume in the base asset.
+    #[serde(with = "string_or_float")]
+    pub base_volume: Number,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: u64,
+    pub last_id: u64,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolPrice {
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BookTicker {
+    pub symbol: String,
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub bid_price: Number,
+    #[serde(with = "string_or_float")]
+    pub bid_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub ask_price: Number,
+    #[serde(with = "string_or_float")]
+    pub ask_qty: Number,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPrice {
+    pub symbol: String,
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Number,
+    #[serde(with = "string_or_float")]
+    pub index_price: Number,
+    #[serde(with = "string_or_float")]
+    pub estimated_settle_price: Number,
+    /// Empty for delivery contracts.
+    pub last_funding_rate: String,
+    /// Empty for delivery contracts.
+    pub interest_rate: String,
+    pub next_funding_time: u64,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    pub symbol: String,
+    pub pair: String,
+    /// Open interest in contracts.
+    #[serde(with = "string_or_float")]
+    pub open_interest: Number,
+    pub contract_type: String,
+    pub time: u64,
+}
+
+/// An order as returned by the order endpoints. `time` is only set when
+/// querying orders, `cum_qty` only when placing them.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub client_order_id: String,
+    #[serde(default, with = "string_or_float_opt")]
+    pub cum_qty: Option<Number>,
+    /// Filled value in the base asset.
+    #[serde(with = "string_or_float")]
+    pub cum_base: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    pub order_id: u64,
+    #[serde(with = "string_or_float")]
+    pub avg_price: Number,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    pub reduce_only: bool,
+    pub side: String,
+    pub position_side: String,
+    pub status: String,
+    #[serde(with = "string_or_float")]
+    pub stop_price: Number,
+    pub close_position: bool,
+    pub symbol: String,
+    pub pair: String,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub orig_type: String,
+    #[serde(default, with = "string_or_float_opt")]
+    pub activate_price: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub price_rate: Option<Number>,
+    #[serde(default)]
+    pub time: Option<u64>,
+    pub update_time: u64,
+    pub working_type: String,
+    pub price_protect: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeHistory {
+    pub symbol: String,
+    pub id: u64,
+    pub order_id: u64,
+    pub pair: String,
+    pub side: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub realized_pnl: Number,
+    pub margin_asset: String,
+    #[serde(with = "string_or_float")]
+    pub base_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub commission: Number,
+    pub commission_asset: String,
+    pub time: u64,
+    pub position_side: String,
+    pub buyer: bool,
+    pub maker: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRisk {
+    pub symbol: String,
+    #[serde(with = "string_or_float", rename = "positionAmt")]
+    pub position_amount: Number,
+    #[serde(with = "string_or_float")]
+    pub entry_price: Number,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Number,
+    #[serde(with = "string_or_float", rename = "unRealizedProfit")]
+    pub unrealized_profit: Number,
+    #[serde(with = "string_or_float")]
+    pub liquidation_price: Number,
+    pub leverage: String,
+    /// Maximum position size in contracts at the current leverage.
+    #[serde(with = "string_or_float")]
+    pub max_qty: Number,
+    pub margin_type: String,
+    #[serde(with = "string_or_float")]
+    pub isolated_margin: Number,
+    #[serde(with = "string_or_bool")]
+    pub is_auto_add_margin: bool,
+    pub position_side: String,
+    /// Position value in the margin asset.
+    #[serde(with = "string_or_float")]
+    pub notional_value: Number,
+    #[serde(with = "string_or_float")]
+    pub isolated_wallet: Number,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub wallet_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: Number,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub max_withdraw_amount: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_un_pnl: Number,
+    #[serde(with = "string_or_float")]
+    pub available_balance: Number,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPosition {
+    pub symbol: String,
+    #[serde(with = "string_or_float", rename = "positionAmt")]
+    pub position_amount: Number,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: Number,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: Number,
+    pub leverage: String,
+    pub isolated: bool,
+    pub position_side: String,
+    #[serde(with = "string_or_float")]
+    pub entry_price: Number,
+    #[serde(with = "string_or_float")]
+    pub max_qty: Number,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    pub assets: Vec<AccountAsset>,
+    pub positions: Vec<AccountPosition>,
+    pub can_deposit: bool,
+    pub can_trade: bool,
+    pub can_withdraw: bool,
+    pub fee_tier: u32,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalance {
+    pub account_alias: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub balance: Number,
+    #[serde(with = "string_or_float")]
+    pub withdraw_available: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: Number,
+    #[serde(with = "string_or_float", rename = "crossUnPnl")]
+    pub cross_unrealized_pnl: Number,
+    #[serde(with = "string_or_float")]
+    pub available_balance: Number,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeLeverageResponse {
+    pub leverage: u8,
+    /// Maximum position size in contracts at the new leverage.
+    #[serde(with = "string_or_float")]
+    pub max_qty: Number,
+    pub symbol: String,
+}
@@ -0,0 +1,32 @@
+use crate::model::{Success, UserDataStream};
+use crate::client::Client;
+use crate::errors::Result;
+use crate::api::API;
+use crate::api::CoinFutures;
+
+#[derive(Clone)]
+pub struct CoinFuturesUserStream {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl CoinFuturesUserStream {
+    // User Stream
+    pub async fn start(&self) -> Result<UserDataStream> {
+        self.client
+            .post(API::CoinFutures(CoinFutures::UserDataStream))
+            .await
+    }
+
+    pub async fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .put(API::CoinFutures(CoinFutures::UserDataStream), listen_key)
+            .await
+    }
+
+    pub async fn close(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .delete(API::CoinFutures(CoinFutures::UserDataStream), listen_key)
+            .await
+    }
+}
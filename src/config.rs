@@ -1,3 +1,8 @@
+use crate::rate_limit::RateLimiter;
+use crate::signer::Signer;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
 #[derive(Clone, Debug)]
 pub struct Config {
     pub rest_api_endpoint: String,
@@ -6,7 +11,41 @@ pub struct Config {
     pub futures_rest_api_endpoint: String,
     pub futures_ws_endpoint: String,
 
+    pub coin_futures_rest_api_endpoint: String,
+    pub coin_futures_ws_endpoint: String,
+
+    pub options_rest_api_endpoint: String,
+    pub options_ws_endpoint: String,
+
     pub recv_window: u64,
+
+    /// How often signed requests re-measure the server clock offset. With
+    /// `None` it is only measured after a -1021 error or an explicit
+    /// `client.sync_time()`.
+    pub time_sync_interval: Option<Duration>,
+
+    /// Governors shared by every service built from this config (and its clones).
+    /// `Config::default()` hands out one process-wide limiter per API family.
+    pub rate_limiter: Arc<RateLimiter>,
+    pub futures_rate_limiter: Arc<RateLimiter>,
+    pub coin_futures_rate_limiter: Arc<RateLimiter>,
+    pub options_rate_limiter: Arc<RateLimiter>,
+
+    /// Signs `SIGNED` requests; `None` uses HMAC with the secret key.
+    pub signer: Option<Arc<dyn Signer>>,
+}
+
+// Services built with `Binance::new` all use the same IP, so they share
+// these instead of each starting from an empty budget.
+static SPOT_LIMITER: OnceLock<Arc<RateLimiter>> = OnceLock::new();
+static FUTURES_LIMITER: OnceLock<Arc<RateLimiter>> = OnceLock::new();
+static COIN_FUTURES_LIMITER: OnceLock<Arc<RateLimiter>> = OnceLock::new();
+static OPTIONS_LIMITER: OnceLock<Arc<RateLimiter>> = OnceLock::new();
+
+fn shared_limiter(
+    cell: &'static OnceLock<Arc<RateLimiter>>, init: fn() -> RateLimiter,
+) -> Arc<RateLimiter> {
+    cell.get_or_init(|| Arc::new(init())).clone()
 }
 
 impl Default for Config {
@@ -18,18 +57,40 @@ impl Default for Config {
             futures_rest_api_endpoint: "https://fapi.binance.com".into(),
             futures_ws_endpoint: "wss://fstream.binance.com/ws".into(),
 
+            coin_futures_rest_api_endpoint: "https://dapi.binance.com".into(),
+            coin_futures_ws_endpoint: "wss://dstream.binance.com/ws".into(),
+
+            options_rest_api_endpoint: "https://eapi.binance.com".into(),
+            options_ws_endpoint: "wss://nbstream.binance.com/eoptions/ws".into(),
+
             recv_window: 5000,
+
+            time_sync_interval: None,
+
+            rate_limiter: shared_limiter(&SPOT_LIMITER, RateLimiter::spot),
+            futures_rate_limiter: shared_limiter(&FUTURES_LIMITER, RateLimiter::futures),
+            coin_futures_rate_limiter: shared_limiter(
+                &COIN_FUTURES_LIMITER,
+                RateLimiter::coin_futures,
+            ),
+            options_rate_limiter: shared_limiter(&OPTIONS_LIMITER, RateLimiter::options),
+
+            signer: None,
         }
     }
 }
 
 impl Config {
+    /// Testnet endpoints. There is no options testnet, so the options
+    /// endpoints keep their production values.
     pub fn testnet() -> Self {
         Self::default()
             .set_rest_api_endpoint("https://testnet.binance.vision")
             .set_ws_endpoint("wss://testnet.binance.vision/ws")
             .set_futures_rest_api_endpoint("https://testnet.binancefuture.com")
             .set_futures_ws_endpoint("https://testnet.binancefuture.com/ws")
+            .set_coin_futures_rest_api_endpoint("https://testnet.binancefuture.com")
+            .set_coin_futures_ws_endpoint("wss://dstream.binancefuture.com/ws")
     }
 
     pub fn set_rest_api_endpoint<T: Into<String>>(mut self, rest_api_endpoint: T) -> Self {
@@ -53,8 +114,68 @@ impl Config {
         self
     }
 
+    pub fn set_coin_futures_rest_api_endpoint<T: Into<String>>(
+        mut self, coin_futures_rest_api_endpoint: T,
+    ) -> Self {
+        self.coin_futures_rest_api_endpoint = coin_futures_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn set_coin_futures_ws_endpoint<T: Into<String>>(
+        mut self, coin_futures_ws_endpoint: T,
+    ) -> Self {
+        self.coin_futures_ws_endpoint = coin_futures_ws_endpoint.into();
+        self
+    }
+
+    pub fn set_options_rest_api_endpoint<T: Into<String>>(
+        mut self, options_rest_api_endpoint: T,
+    ) -> Self {
+        self.options_rest_api_endpoint = options_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn set_options_ws_endpoint<T: Into<String>>(mut self, options_ws_endpoint: T) -> Self {
+        self.options_ws_endpoint = options_ws_endpoint.into();
+        self
+    }
+
     pub fn set_recv_window(mut self, recv_window: u64) -> Self {
         self.recv_window = recv_window;
         self
     }
+
+    pub fn set_time_sync_interval<D: Into<Option<Duration>>>(
+        mut self, time_sync_interval: D,
+    ) -> Self {
+        self.time_sync_interval = time_sync_interval.into();
+        self
+    }
+
+    pub fn set_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    pub fn set_futures_rate_limiter(mut self, futures_rate_limiter: Arc<RateLimiter>) -> Self {
+        self.futures_rate_limiter = futures_rate_limiter;
+        self
+    }
+
+    pub fn set_coin_futures_rate_limiter(
+        mut self, coin_futures_rate_limiter: Arc<RateLimiter>,
+    ) -> Self {
+        self.coin_futures_rate_limiter = coin_futures_rate_limiter;
+        self
+    }
+
+    pub fn set_options_rate_limiter(mut self, options_rate_limiter: Arc<RateLimiter>) -> Self {
+        self.options_rate_limiter = options_rate_limiter;
+        self
+    }
+
+    pub fn set_signer<S: Signer + 'static>(mut self, signer: S) -> Self {
+        self.signer = Some(Arc::new(signer));
+        self
+    }
 }
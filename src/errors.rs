@@ -1,10 +1,11 @@
 use serde::Deserialize;
 use thiserror::Error;
 use std::fmt;
+use std::time::Duration;
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct BinanceContentError {
-    pub code: i16,
+    pub code: i32,
     pub msg: String,
 }
 
@@ -16,10 +17,253 @@ impl fmt::Display for BinanceContentError {
 
 impl std::error::Error for BinanceContentError {}
 
+impl BinanceContentError {
+    pub fn kind(&self) -> BinanceErrorCode {
+        BinanceErrorCode::from(self.code)
+    }
+}
+
+/// Binance error codes this crate knows by name. Anything else is kept as
+/// [`BinanceErrorCode::Other`]. Spot and futures share most codes; the few
+/// they use differently are named after both meanings.
+///
+/// See <https://developers.binance.com/docs/binance-spot-api-docs/errors>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinanceErrorCode {
+    /// An unknown error occurred while processing the request.
+    Unknown,
+    /// Internal error; unable to process the request.
+    Disconnected,
+    /// Not authorized to execute this request.
+    Unauthorized,
+    /// Too many requests; the IP is rate limited or banned.
+    TooManyRequests,
+    /// Unexpected response from the message bus; execution status unknown.
+    UnexpectedResponse,
+    /// Timeout waiting for the backend; execution status unknown.
+    Timeout,
+    /// The server is overloaded; retry later.
+    ServerBusy,
+    /// A filter such as LOT_SIZE or MIN_NOTIONAL rejected the request.
+    InvalidQuantity,
+    /// Unsupported order combination.
+    UnknownOrderComposition,
+    /// Too many new orders.
+    TooManyOrders,
+    /// This service is no longer available.
+    ServiceShuttingDown,
+    /// This operation is not supported.
+    UnsupportedOperation,
+    /// Timestamp outside recvWindow or ahead of the server clock.
+    InvalidTimestamp,
+    /// Signature for this request is not valid.
+    InvalidSignature,
+    /// Illegal characters found in a parameter.
+    IllegalChars,
+    /// Too many parameters sent.
+    TooManyParameters,
+    /// A mandatory parameter was not sent, empty or malformed.
+    MandatoryParamEmptyOrMalformed,
+    /// An unknown parameter was sent.
+    UnknownParam,
+    /// Not all sent parameters were read.
+    UnreadParameters,
+    /// A parameter was empty.
+    ParamEmpty,
+    /// A parameter was sent when not required.
+    ParamNotRequired,
+    /// Precision is over the maximum defined for this asset.
+    BadPrecision,
+    /// No orders on book for symbol.
+    NoDepth,
+    /// TimeInForce parameter sent when not required.
+    TifNotRequired,
+    /// Invalid timeInForce.
+    InvalidTif,
+    /// Invalid orderType.
+    InvalidOrderType,
+    /// Invalid side.
+    InvalidSide,
+    /// New client order ID was empty.
+    EmptyNewClientOrderId,
+    /// Original client order ID was empty.
+    EmptyOrigClientOrderId,
+    /// Invalid interval.
+    BadInterval,
+    /// Invalid symbol.
+    BadSymbol,
+    /// This listenKey does not exist.
+    InvalidListenKey,
+    /// Lookup interval is too big.
+    MoreThanXxHours,
+    /// Combination of optional parameters invalid.
+    OptionalParamsBadCombo,
+    /// Invalid data sent for a parameter.
+    InvalidParameter,
+    /// NEW_ORDER_REJECTED, e.g. insufficient balance.
+    NewOrderRejected,
+    /// CANCEL_REJECTED, e.g. unknown order.
+    CancelRejected,
+    /// Order does not exist.
+    NoSuchOrder,
+    /// API-key format invalid.
+    BadApiKeyFormat,
+    /// Invalid API-key, IP, or permissions for action.
+    RejectedApiKey,
+    /// Futures: margin is insufficient.
+    MarginInsufficient,
+    /// -2021, which means something else per API. Spot: a cancel-replace
+    /// partially failed, i.e. the cancel or the new order did. USD-M futures:
+    /// the order would immediately trigger.
+    CancelReplaceOrTriggerRejected,
+    /// -2022, which means something else per API. Spot: a cancel-replace
+    /// failed, both the cancel and the new order. USD-M futures: a ReduceOnly
+    /// order is rejected.
+    CancelReplaceOrReduceOnlyRejected,
+    Other(i32),
+}
+
+impl From<i32> for BinanceErrorCode {
+    fn from(code: i32) -> Self {
+        use BinanceErrorCode::*;
+        match code {
+            -1000 => Unknown,
+            -1001 => Disconnected,
+            -1002 => Unauthorized,
+            -1003 => TooManyRequests,
+            -1006 => UnexpectedResponse,
+            -1007 => Timeout,
+            -1008 => ServerBusy,
+            -1013 => InvalidQuantity,
+            -1014 => UnknownOrderComposition,
+            -1015 => TooManyOrders,
+            -1016 => ServiceShuttingDown,
+            -1020 => UnsupportedOperation,
+            -1021 => InvalidTimestamp,
+            -1022 => InvalidSignature,
+            -1100 => IllegalChars,
+            -1101 => TooManyParameters,
+            -1102 => MandatoryParamEmptyOrMalformed,
+            -1103 => UnknownParam,
+            -1104 => UnreadParameters,
+            -1105 => ParamEmpty,
+            -1106 => ParamNotRequired,
+            -1111 => BadPrecision,
+            -1112 => NoDepth,
+            -1114 => TifNotRequired,
+            -1115 => InvalidTif,
+            -1116 => InvalidOrderType,
+            -1117 => InvalidSide,
+            -1118 => EmptyNewClientOrderId,
+            -1119 => EmptyOrigClientOrderId,
+            -1120 => BadInterval,
+            -1121 => BadSymbol,
+            -1125 => InvalidListenKey,
+            -1127 => MoreThanXxHours,
+            -1128 => OptionalParamsBadCombo,
+            -1130 => InvalidParameter,
+            -2010 => NewOrderRejected,
+            -2011 => CancelRejected,
+            -2013 => NoSuchOrder,
+            -2014 => BadApiKeyFormat,
+            -2015 => RejectedApiKey,
+            -2019 => MarginInsufficient,
+            -2021 => CancelReplaceOrTriggerRejected,
+            -2022 => CancelReplaceOrReduceOnlyRejected,
+            other => Other(other),
+        }
+    }
+}
+
+impl From<BinanceErrorCode> for i32 {
+    fn from(code: BinanceErrorCode) -> Self {
+        use BinanceErrorCode::*;
+        match code {
+            Unknown => -1000,
+            Disconnected => -1001,
+            Unauthorized => -1002,
+            TooManyRequests => -1003,
+            UnexpectedResponse => -1006,
+            Timeout => -1007,
+            ServerBusy => -1008,
+            InvalidQuantity => -1013,
+            UnknownOrderComposition => -1014,
+            TooManyOrders => -1015,
+            ServiceShuttingDown => -1016,
+            UnsupportedOperation => -1020,
+            InvalidTimestamp => -1021,
+            InvalidSignature => -1022,
+            IllegalChars => -1100,
+            TooManyParameters => -1101,
+            MandatoryParamEmptyOrMalformed => -1102,
+            UnknownParam => -1103,
+            UnreadParameters => -1104,
+            ParamEmpty => -1105,
+            ParamNotRequired => -1106,
+            BadPrecision => -1111,
+            NoDepth => -1112,
+            TifNotRequired => -1114,
+            InvalidTif => -1115,
+            InvalidOrderType => -1116,
+            InvalidSide => -1117,
+            EmptyNewClientOrderId => -1118,
+            EmptyOrigClientOrderId => -1119,
+            BadInterval => -1120,
+            BadSymbol => -1121,
+            InvalidListenKey => -1125,
+            MoreThanXxHours => -1127,
+            OptionalParamsBadCombo => -1128,
+            InvalidParameter => -1130,
+            NewOrderRejected => -2010,
+            CancelRejected => -2011,
+            NoSuchOrder => -2013,
+            BadApiKeyFormat => -2014,
+            RejectedApiKey => -2015,
+            MarginInsufficient => -2019,
+            CancelReplaceOrTriggerRejected => -2021,
+            CancelReplaceOrReduceOnlyRejected => -2022,
+            Other(other) => other,
+        }
+    }
+}
+
+/// A response with a non-success HTTP status.
+#[derive(Debug, Clone)]
+pub struct HttpError {
+    pub status: reqwest::StatusCode,
+    /// The `{"code": .., "msg": ..}` body, when Binance sent one.
+    pub content: Option<BinanceContentError>,
+    /// The raw response body.
+    pub body: String,
+    /// The `Retry-After` header, sent with 429 and 418.
+    pub retry_after: Option<Duration>,
+}
+
+impl HttpError {
+    pub fn code(&self) -> Option<BinanceErrorCode> {
+        self.content.as_ref().map(BinanceContentError::kind)
+    }
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.content {
+            Some(content) => write!(f, "{}, {}", self.status, content)?,
+            None => write!(f, "{}, body: {}", self.status, self.body)?,
+        }
+        if let Some(retry_after) = self.retry_after {
+            write!(f, ", retry after {:?}", retry_after)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for HttpError {}
+
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error("Binance API Error: {0}")]
-    BinanceError(#[from] BinanceContentError),
+    #[error("HTTP Error: {0}")]
+    Http(#[from] HttpError),
 
     #[error("Invalid Kline Vec: {name} at index {index} is missing")]
     KlineValueMissingError { index: usize, name: &'static str },
@@ -48,8 +292,44 @@ pub enum Error {
     #[error("System Time Error: {0}")]
     Timestamp(#[from] std::time::SystemTimeError),
 
+    #[error("Invalid Order: {0}")]
+    InvalidOrder(String),
+
+    #[error("Filter Error: {0}")]
+    Filter(#[from] crate::symbol_rules::FilterError),
+
+    #[error("Rate Limited: {reason}, retry after {retry_after:?}")]
+    RateLimited {
+        reason: String,
+        retry_after: Duration,
+        /// Set while a 429/418 from Binance is in effect, rather than a local limit being reached.
+        banned: bool,
+    },
+
+    /// A request that costs more than a whole window allows; waiting would never make room for it.
+    #[error("Cost Above Limit: {cost} exceeds the {kind:?} limit of {limit} per {interval:?}")]
+    CostAboveLimit {
+        kind: crate::rate_limit::RateLimitKind,
+        cost: u64,
+        limit: u64,
+        interval: Duration,
+    },
+
+    #[error("Signing Key Error: {0}")]
+    SigningKey(String),
+
     #[error("Custom Error: {0}")]
     Custom(String),
 }
 
+impl Error {
+    /// The Binance error code, for errors that carry one.
+    pub fn binance_code(&self) -> Option<BinanceErrorCode> {
+        match self {
+            Error::Http(http) => http.code(),
+            _ => None,
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, Error>;
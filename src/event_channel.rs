@@ -0,0 +1,256 @@
+//! Websocket events as a [`Stream`] instead of a callback.
+//!
+//! [`WebSockets::channel`] and [`FuturesWebSockets::channel`] build a socket
+//! whose events go to a bounded queue, read through an [`EventReceiver`].
+//! After connecting, `spawn` runs the event loop on a tokio task and returns
+//! a [`SpawnedSocket`] through which streams can still be subscribed and
+//! unsubscribed. The receiver yields `Ok(event)` for each event, a
+//! [`StreamEvent`] carrying the stream name and receive time. If the
+//! connection fails for good, the error comes as the last item before the
+//! stream ends. Dropping the receiver stops the task.
+//!
+//! When the consumer falls behind and the queue is full, [`Backpressure`]
+//! decides what happens to the next event.
+//!
+//!```no_run
+//! use binance_rs_plus::event_channel::{Backpressure, ChannelConfig};
+//! use binance_rs_plus::websockets::{WebSockets, WebsocketEvent};
+//! use futures_util::StreamExt;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     let (mut web_socket, mut events) = WebSockets::channel(
+//!         ChannelConfig::default()
+//!             .set_capacity(256)
+//!             .set_backpressure(Backpressure::DropOldest),
+//!     );
+//!     web_socket.connect("btcusdt@aggTrade").await?;
+//!     let web_socket = web_socket.spawn();
+//!     web_socket.subscribe(&["ethusdt@aggTrade"]).await?;
+//!
+//!     while let Some(event) = events.next().await {
+//!         if let WebsocketEvent::AggrTrades(trade) = event?.event {
+//!             println!("{} @ {}", trade.qty, trade.price);
+//!         }
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`WebSockets::channel`]: crate::websockets::WebSockets::channel
+//! [`FuturesWebSockets::channel`]: crate::futures::websockets::FuturesWebSockets::channel
+//! [`SpawnedSocket`]: crate::async_websocket_client::SpawnedSocket
+//! [`StreamEvent`]: crate::async_websocket_client::StreamEvent
+
+use crate::errors::{Error, Result};
+use futures_util::Stream;
+use futures_util::task::AtomicWaker;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use tokio::sync::Notify;
+
+/// What happens to an event that arrives while the queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Backpressure {
+    /// Wait for room. Reading from the socket pauses meanwhile, so a consumer
+    /// that stalls for long enough makes the server drop the connection.
+    #[default]
+    Block,
+    /// Discard the oldest queued event.
+    DropOldest,
+    /// Discard the arriving event.
+    DropNewest,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelConfig {
+    pub capacity: usize,
+    pub backpressure: Backpressure,
+}
+
+impl Default for ChannelConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 1024,
+            backpressure: Backpressure::default(),
+        }
+    }
+}
+
+impl ChannelConfig {
+    /// Queue size, at least 1.
+    pub fn set_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    pub fn set_backpressure(mut self, backpressure: Backpressure) -> Self {
+        self.backpressure = backpressure;
+        self
+    }
+}
+
+struct Shared<T> {
+    queue: Mutex<VecDeque<T>>,
+    capacity: usize,
+    backpressure: Backpressure,
+    senders: AtomicUsize,
+    senders_closed: AtomicBool,
+    receiver_closed: AtomicBool,
+    dropped: AtomicU64,
+    receiver_waker: AtomicWaker,
+    space: Notify,
+}
+
+/// Creates a channel; see the [module documentation](self).
+pub fn event_channel<T>(config: ChannelConfig) -> (EventSender<T>, EventReceiver<T>) {
+    let capacity = config.capacity.max(1);
+    let shared = Arc::new(Shared {
+        queue: Mutex::new(VecDeque::with_capacity(capacity)),
+        capacity,
+        backpressure: config.backpressure,
+        senders: AtomicUsize::new(1),
+        senders_closed: AtomicBool::new(false),
+        receiver_closed: AtomicBool::new(false),
+        dropped: AtomicU64::new(0),
+        receiver_waker: AtomicWaker::new(),
+        space: Notify::new(),
+    });
+    (
+        EventSender {
+            shared: shared.clone(),
+        },
+        EventReceiver { shared },
+    )
+}
+
+pub struct EventSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> EventSender<T> {
+    /// Queues `item`, applying the backpressure policy when the queue is
+    /// full. Fails once the receiver has been dropped.
+    pub async fn send(&self, item: T) -> Result<()> {
+        let mut item = Some(item);
+        while let Some(next) = item.take() {
+            if self.is_closed() {
+                return Err(Error::Custom("Event receiver dropped".to_string()));
+            }
+            // Created before trying so that room made in between is not missed.
+            let space = self.shared.space.notified();
+            item = self.try_push(next);
+            if item.is_some() {
+                space.await;
+            }
+        }
+        self.shared.receiver_waker.wake();
+        Ok(())
+    }
+
+    // Hands `item` back if the queue is full and the policy is to wait.
+    fn try_push(&self, item: T) -> Option<T> {
+        let mut queue = self.shared.queue.lock().unwrap();
+        if queue.len() >= self.shared.capacity {
+            if self.shared.backpressure == Backpressure::Block {
+                return Some(item);
+            }
+            self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+            if self.shared.backpressure == Backpressure::DropNewest {
+                return None;
+            }
+            queue.pop_front();
+        }
+        queue.push_back(item);
+        None
+    }
+
+    /// Whether the receiver has been dropped.
+    pub fn is_closed(&self) -> bool {
+        self.shared.receiver_closed.load(Ordering::Acquire)
+    }
+
+    /// Ends the receiver's stream once the queued events are read, even if
+    /// clones of this sender are still alive.
+    pub fn close(&self) {
+        self.shared.senders_closed.store(true, Ordering::Release);
+        self.shared.receiver_waker.wake();
+    }
+}
+
+impl<T> Clone for EventSender<T> {
+    fn clone(&self) -> Self {
+        self.shared.senders.fetch_add(1, Ordering::Relaxed);
+        EventSender {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for EventSender<T> {
+    fn drop(&mut self) {
+        if self.shared.senders.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.shared.receiver_waker.wake();
+        }
+    }
+}
+
+/// Receiving half of the channel. Ends once every sender is gone and the
+/// queue is drained.
+pub struct EventReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> EventReceiver<T> {
+    pub async fn recv(&mut self) -> Option<T> {
+        std::future::poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.shared.receiver_waker.register(cx.waker());
+        if let Some(item) = self.shared.queue.lock().unwrap().pop_front() {
+            self.shared.space.notify_one();
+            return Poll::Ready(Some(item));
+        }
+        if self.shared.senders.load(Ordering::Acquire) == 0
+            || self.shared.senders_closed.load(Ordering::Acquire)
+        {
+            return Poll::Ready(None);
+        }
+        Poll::Pending
+    }
+
+    /// Number of events discarded so far because the queue was full.
+    pub fn dropped(&self) -> u64 {
+        self.shared.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Number of events waiting in the queue.
+    pub fn len(&self) -> usize {
+        self.shared.queue.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Stream for EventReceiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.get_mut().poll_recv(cx)
+    }
+}
+
+impl<T> Drop for EventReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.receiver_closed.store(true, Ordering::Release);
+        // Wakes a sender waiting for room, or leaves a permit for one about to.
+        self.shared.space.notify_waiters();
+        self.shared.space.notify_one();
+    }
+}
@@ -1,12 +1,16 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Display;
 use crate::util::build_signed_request;
-use crate::errors::{Result, Error}; // Added Error
+use crate::errors::{BinanceContentError, Result, Error}; // Added Error
 use crate::client::Client;
+use crate::pagination::{self, DAY, PageLimits};
+use futures_util::Stream;
+use crate::symbol_rules::SymbolRules;
 use crate::api::{API, Futures};
-use crate::model::Empty;
+use crate::model::{Empty, Number};
 use crate::account::OrderSide; // Re-using OrderSide from spot account, assuming it's compatible
 use crate::futures::model::{Order as FuturesOrder, TradeHistory, Income}; // Aliased Order to avoid conflict
+use serde::Deserialize;
 
 use super::model::{
     ChangeLeverageResponse, Transaction, CanceledOrder, PositionRisk, AccountBalance,
@@ -17,6 +21,8 @@ use super::model::{
 pub struct FuturesAccount {
     pub client: Client,
     pub recv_window: u64,
+    /// Filters checked before orders for these symbols are sent, keyed by symbol.
+    pub symbol_rules: HashMap<String, SymbolRules>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -118,20 +124,20 @@ impl Display for TimeInForce {
 }
 
 // Internal struct to build order requests
-struct OrderRequestBuilder {
+pub(crate) struct OrderRequestBuilder {
     pub symbol: String,
     pub side: OrderSide,
     pub position_side: Option<PositionSide>,
     pub order_type: OrderType,
     pub time_in_force: Option<TimeInForce>,
-    pub quantity: Option<f64>, // Changed from qty to quantity for clarity
+    pub quantity: Option<Number>, // Changed from qty to quantity for clarity
     pub reduce_only: Option<bool>,
-    pub price: Option<f64>,
+    pub price: Option<Number>,
     pub new_client_order_id: Option<String>,
-    pub stop_price: Option<f64>,
+    pub stop_price: Option<Number>,
     pub close_position: Option<bool>, // if true, all existing long/short positions will be closed
-    pub activation_price: Option<f64>, // Used with TRAILING_STOP_MARKET orders
-    pub callback_rate: Option<f64>,   // Used with TRAILING_STOP_MARKET orders
+    pub activation_price: Option<Number>, // Used with TRAILING_STOP_MARKET orders
+    pub callback_rate: Option<Number>, // Used with TRAILING_STOP_MARKET orders
     pub working_type: Option<WorkingType>,
     pub price_protect: Option<bool>, // For STOP_MARKET and TAKE_PROFIT_MARKET orders
 }
@@ -144,18 +150,76 @@ pub struct CustomOrderRequest {
     pub position_side: Option<PositionSide>,
     pub order_type: OrderType,
     pub time_in_force: Option<TimeInForce>,
-    pub quantity: Option<f64>,
+    pub quantity: Option<Number>,
     pub reduce_only: Option<bool>,
-    pub price: Option<f64>,
+    pub price: Option<Number>,
     pub new_client_order_id: Option<String>,
-    pub stop_price: Option<f64>,
+    pub stop_price: Option<Number>,
     pub close_position: Option<bool>,
-    pub activation_price: Option<f64>,
-    pub callback_rate: Option<f64>,
+    pub activation_price: Option<Number>,
+    pub callback_rate: Option<Number>,
     pub working_type: Option<WorkingType>,
     pub price_protect: Option<bool>,
 }
 
+impl From<CustomOrderRequest> for OrderRequestBuilder {
+    fn from(order_request: CustomOrderRequest) -> Self {
+        OrderRequestBuilder {
+            symbol: order_request.symbol,
+            side: order_request.side,
+            position_side: order_request.position_side,
+            order_type: order_request.order_type,
+            time_in_force: order_request.time_in_force,
+            quantity: order_request.quantity,
+            reduce_only: order_request.reduce_only,
+            price: order_request.price,
+            new_client_order_id: order_request.new_client_order_id,
+            stop_price: order_request.stop_price,
+            close_position: order_request.close_position,
+            activation_price: order_request.activation_price,
+            callback_rate: order_request.callback_rate,
+            working_type: order_request.working_type,
+            price_protect: order_request.price_protect,
+        }
+    }
+}
+
+// Modify the price and quantity of an open LIMIT order, identified by
+// either order_id or orig_client_order_id
+#[derive(Debug, Clone)]
+pub struct ModifyOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    pub quantity: Number,
+    pub price: Number,
+}
+
+/// Outcome of one order in a batch request. Binance answers every element
+/// separately, so one rejected order does not fail the rest of the batch.
+pub type BatchOrderResult<T> = std::result::Result<T, BinanceContentError>;
+
+// Maximum number of orders Binance accepts in one /fapi/v1/batchOrders call
+const MAX_BATCH_ORDERS: usize = 5;
+
+// Each element of a batch response is either the order or an error object
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BatchResponse<T> {
+    Err(BinanceContentError),
+    Ok(T),
+}
+
+impl<T> From<BatchResponse<T>> for BatchOrderResult<T> {
+    fn from(response: BatchResponse<T>) -> Self {
+        match response {
+            BatchResponse::Ok(value) => Ok(value),
+            BatchResponse::Err(err) => Err(err),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct IncomeRequest {
     pub symbol: Option<String>,
@@ -196,54 +260,21 @@ impl Display for IncomeType {
 }
 
 impl FuturesAccount {
-    // Helper to build BTreeMap for an order
-    fn build_order_params(&self, order_builder: OrderRequestBuilder) -> BTreeMap<String, String> {
-        let mut parameters = BTreeMap::new();
-        parameters.insert("symbol".into(), order_builder.symbol);
-        parameters.insert("side".into(), order_builder.side.to_string());
-        parameters.insert("type".into(), order_builder.order_type.to_string());
+    /// Check orders for `rules.symbol` against its exchange filters before
+    /// they are sent. Violations fail with `Error::Filter`.
+    pub fn set_symbol_rules(&mut self, rules: SymbolRules) {
+        self.symbol_rules.insert(rules.symbol.clone(), rules);
+    }
 
-        if let Some(ps) = order_builder.position_side {
-            parameters.insert("positionSide".into(), ps.to_string());
-        }
-        if let Some(tif) = order_builder.time_in_force {
-            parameters.insert("timeInForce".into(), tif.to_string());
-        }
-        if let Some(q) = order_builder.quantity {
-            parameters.insert("quantity".into(), q.to_string());
-        }
-        if let Some(ro) = order_builder.reduce_only {
-            parameters.insert("reduceOnly".into(), ro.to_string());
-        }
-        if let Some(p) = order_builder.price {
-            parameters.insert("price".into(), p.to_string());
-        }
-        if let Some(ncoi) = order_builder.new_client_order_id {
-            parameters.insert("newClientOrderId".into(), ncoi);
-        }
-        if let Some(sp) = order_builder.stop_price {
-            parameters.insert("stopPrice".into(), sp.to_string());
-        }
-        if let Some(cp) = order_builder.close_position {
-            parameters.insert("closePosition".into(), cp.to_string());
-        }
-        if let Some(ap) = order_builder.activation_price {
-            parameters.insert("activationPrice".into(), ap.to_string());
-        }
-        if let Some(cr) = order_builder.callback_rate {
-            parameters.insert("callbackRate".into(), cr.to_string());
-        }
-        if let Some(wt) = order_builder.working_type {
-            parameters.insert("workingType".into(), wt.to_string());
-        }
-        if let Some(pp) = order_builder.price_protect {
-            parameters.insert("priceProtect".into(), pp.to_string());
-        }
-        parameters
+    // Helper to build BTreeMap for an order
+    fn build_order_params(
+        &self, order_builder: OrderRequestBuilder,
+    ) -> Result<BTreeMap<String, String>> {
+        build_order_params(&self.symbol_rules, order_builder)
     }
 
     pub async fn limit_buy(
-        &self, symbol: impl Into<String>, qty: impl Into<f64>, price: f64,
+        &self, symbol: impl Into<String>, qty: impl Into<Number>, price: Number,
         time_in_force: TimeInForce,
     ) -> Result<Transaction> {
         let builder = OrderRequestBuilder {
@@ -263,7 +294,7 @@ impl FuturesAccount {
             working_type: None,
             price_protect: None,
         };
-        let order_params = self.build_order_params(builder);
+        let order_params = self.build_order_params(builder)?;
         let request = build_signed_request(order_params, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
@@ -271,7 +302,7 @@ impl FuturesAccount {
     }
 
     pub async fn limit_sell(
-        &self, symbol: impl Into<String>, qty: impl Into<f64>, price: f64,
+        &self, symbol: impl Into<String>, qty: impl Into<Number>, price: Number,
         time_in_force: TimeInForce,
     ) -> Result<Transaction> {
         let builder = OrderRequestBuilder {
@@ -291,7 +322,7 @@ impl FuturesAccount {
             working_type: None,
             price_protect: None,
         };
-        let order_params = self.build_order_params(builder);
+        let order_params = self.build_order_params(builder)?;
         let request = build_signed_request(order_params, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
@@ -301,7 +332,7 @@ impl FuturesAccount {
     pub async fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let builder = OrderRequestBuilder {
             symbol: symbol.into(),
@@ -320,7 +351,7 @@ impl FuturesAccount {
             working_type: None,
             price_protect: None,
         };
-        let order_params = self.build_order_params(builder);
+        let order_params = self.build_order_params(builder)?;
         let request = build_signed_request(order_params, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
@@ -330,7 +361,7 @@ impl FuturesAccount {
     pub async fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let builder = OrderRequestBuilder {
             symbol: symbol.into(),
@@ -349,7 +380,7 @@ impl FuturesAccount {
             working_type: None,
             price_protect: None,
         };
-        let order_params = self.build_order_params(builder);
+        let order_params = self.build_order_params(builder)?;
         let request = build_signed_request(order_params, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
@@ -389,7 +420,7 @@ impl FuturesAccount {
     pub async fn stop_market_close_buy<S, F>(&self, symbol: S, stop_price: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let builder = OrderRequestBuilder {
             symbol: symbol.into(),
@@ -408,7 +439,7 @@ impl FuturesAccount {
             working_type: None,
             price_protect: None,
         };
-        let order_params = self.build_order_params(builder);
+        let order_params = self.build_order_params(builder)?;
         let request = build_signed_request(order_params, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
@@ -420,7 +451,7 @@ impl FuturesAccount {
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let builder = OrderRequestBuilder {
             symbol: symbol.into(),
@@ -439,7 +470,7 @@ impl FuturesAccount {
             working_type: None,
             price_protect: None,
         };
-        let order_params = self.build_order_params(builder);
+        let order_params = self.build_order_params(builder)?;
         let request = build_signed_request(order_params, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
@@ -447,36 +478,130 @@ impl FuturesAccount {
     }
 
     pub async fn custom_order(&self, order_request: CustomOrderRequest) -> Result<Transaction> {
-        let builder = OrderRequestBuilder {
-            symbol: order_request.symbol,
-            side: order_request.side,
-            position_side: order_request.position_side,
-            order_type: order_request.order_type,
-            time_in_force: order_request.time_in_force,
-            quantity: order_request.quantity,
-            reduce_only: order_request.reduce_only,
-            price: order_request.price,
-            new_client_order_id: order_request.new_client_order_id,
-            stop_price: order_request.stop_price,
-            close_position: order_request.close_position,
-            activation_price: order_request.activation_price,
-            callback_rate: order_request.callback_rate,
-            working_type: order_request.working_type,
-            price_protect: order_request.price_protect,
-        };
-        let order_params = self.build_order_params(builder);
+        let order_params = self.build_order_params(order_request.into())?;
         let request = build_signed_request(order_params, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
             .await
     }
 
+    /// Place up to 5 orders in a single request.
+    ///
+    /// The returned vector holds one result per order, in request order.
     pub async fn custom_batch_orders(
-        &self, _order_requests: Vec<CustomOrderRequest>,
-    ) -> Result<Vec<Transaction>> {
-        Err(Error::Custom(
-            "Batch order functionality not yet fully implemented.".to_string(),
-        ))
+        &self, order_requests: Vec<CustomOrderRequest>,
+    ) -> Result<Vec<BatchOrderResult<Transaction>>> {
+        check_batch_size(order_requests.len())?;
+        let orders: Vec<BTreeMap<String, String>> = order_requests
+            .into_iter()
+            .map(|order_request| self.build_order_params(order_request.into()))
+            .collect::<Result<_>>()?;
+
+        let mut parameters = BTreeMap::new();
+        parameters.insert("batchOrders".into(), encode_json_param(&orders)?);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let responses: Vec<BatchResponse<Transaction>> = self
+            .client
+            .post_signed(API::Futures(Futures::BatchOrders), request)
+            .await?;
+        Ok(responses.into_iter().map(Into::into).collect())
+    }
+
+    /// Cancel up to 10 orders of one symbol by order id.
+    pub async fn cancel_batch_orders<S>(
+        &self, symbol: S, order_ids: Vec<u64>,
+    ) -> Result<Vec<BatchOrderResult<CanceledOrder>>>
+    where
+        S: Into<String>,
+    {
+        if order_ids.is_empty() || order_ids.len() > 10 {
+            return Err(Error::InvalidOrder(format!(
+                "batch cancel takes 1 to 10 orders, got {}",
+                order_ids.len()
+            )));
+        }
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderIdList".into(), encode_json_param(&order_ids)?);
+
+        self.batch_cancel(parameters).await
+    }
+
+    /// Cancel up to 10 orders of one symbol by client order id.
+    pub async fn cancel_batch_orders_with_client_ids<S>(
+        &self, symbol: S, orig_client_order_ids: Vec<String>,
+    ) -> Result<Vec<BatchOrderResult<CanceledOrder>>>
+    where
+        S: Into<String>,
+    {
+        if orig_client_order_ids.is_empty() || orig_client_order_ids.len() > 10 {
+            return Err(Error::InvalidOrder(format!(
+                "batch cancel takes 1 to 10 orders, got {}",
+                orig_client_order_ids.len()
+            )));
+        }
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert(
+            "origClientOrderIdList".into(),
+            encode_json_param(&orig_client_order_ids)?,
+        );
+
+        self.batch_cancel(parameters).await
+    }
+
+    async fn batch_cancel(
+        &self, parameters: BTreeMap<String, String>,
+    ) -> Result<Vec<BatchOrderResult<CanceledOrder>>> {
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let responses: Vec<BatchResponse<CanceledOrder>> = self
+            .client
+            .delete_signed(API::Futures(Futures::BatchOrders), Some(request))
+            .await?;
+        Ok(responses.into_iter().map(Into::into).collect())
+    }
+
+    /// Modify up to 5 open LIMIT orders in a single request.
+    pub async fn modify_batch_orders(
+        &self, modify_requests: Vec<ModifyOrderRequest>,
+    ) -> Result<Vec<BatchOrderResult<Transaction>>> {
+        check_batch_size(modify_requests.len())?;
+        let mut orders = Vec::with_capacity(modify_requests.len());
+        for modify in modify_requests {
+            if let Some(rules) = self.symbol_rules.get(&modify.symbol) {
+                rules.validate(Some(modify.price), Some(modify.quantity), false)?;
+            }
+            let mut order: BTreeMap<String, String> = BTreeMap::new();
+            order.insert("symbol".into(), modify.symbol);
+            order.insert("side".into(), modify.side.to_string());
+            match (modify.order_id, modify.orig_client_order_id) {
+                (Some(order_id), _) => {
+                    order.insert("orderId".into(), order_id.to_string());
+                }
+                (None, Some(client_id)) => {
+                    order.insert("origClientOrderId".into(), client_id);
+                }
+                (None, None) => {
+                    return Err(Error::InvalidOrder(
+                        "order_id or orig_client_order_id is required to modify an order".into(),
+                    ));
+                }
+            }
+            order.insert("quantity".into(), modify.quantity.to_string());
+            order.insert("price".into(), modify.price.to_string());
+            orders.push(order);
+        }
+
+        let mut parameters = BTreeMap::new();
+        parameters.insert("batchOrders".into(), encode_json_param(&orders)?);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let responses: Vec<BatchResponse<Transaction>> = self
+            .client
+            .put_signed(API::Futures(Futures::BatchOrders), request)
+            .await?;
+        Ok(responses.into_iter().map(Into::into).collect())
     }
 
     pub async fn get_all_orders<S, F, N>(
@@ -537,6 +662,74 @@ impl FuturesAccount {
             .await
     }
 
+    /// Stream every order from `start_time` to `end_time` (default now),
+    /// seven day window and 1000 orders per request.
+    pub fn iter_all_orders<S, E>(
+        &self, symbol: S, start_time: u64, end_time: E,
+    ) -> impl Stream<Item = Result<FuturesOrder>> + use<S, E>
+    where
+        S: Into<String>,
+        E: Into<Option<u64>>,
+    {
+        let account = self.clone();
+        let symbol = symbol.into();
+        let end_time = pagination::end_or_now(end_time.into());
+        pagination::by_id(
+            start_time,
+            end_time,
+            PageLimits::new(7 * DAY, 1000),
+            move |page| {
+                let account = account.clone();
+                let symbol = symbol.clone();
+                async move {
+                    account
+                        .get_all_orders(
+                            symbol,
+                            page.from_id,
+                            page.start_time,
+                            page.end_time,
+                            page.limit,
+                        )
+                        .await
+                }
+            },
+        )
+    }
+
+    /// Stream every trade from `start_time` to `end_time` (default now),
+    /// seven day window and 1000 trades per request.
+    pub fn iter_user_trades<S, E>(
+        &self, symbol: S, start_time: u64, end_time: E,
+    ) -> impl Stream<Item = Result<TradeHistory>> + use<S, E>
+    where
+        S: Into<String>,
+        E: Into<Option<u64>>,
+    {
+        let account = self.clone();
+        let symbol = symbol.into();
+        let end_time = pagination::end_or_now(end_time.into());
+        pagination::by_id(
+            start_time,
+            end_time,
+            PageLimits::new(7 * DAY, 1000),
+            move |page| {
+                let account = account.clone();
+                let symbol = symbol.clone();
+                async move {
+                    account
+                        .get_user_trades(
+                            symbol,
+                            page.from_id,
+                            page.start_time,
+                            page.end_time,
+                            page.limit,
+                        )
+                        .await
+                }
+            },
+        )
+    }
+
     pub async fn position_information<S>(&self, symbol: S) -> Result<Vec<PositionRisk>>
     where
         S: Into<String>,
@@ -600,7 +793,7 @@ impl FuturesAccount {
     }
 
     pub async fn change_position_margin<S>(
-        &self, symbol: S, amount: f64, margin_type: u8,
+        &self, symbol: S, amount: Number, margin_type: u8,
     ) -> Result<()>
     where
         S: Into<String>,
@@ -673,4 +866,113 @@ impl FuturesAccount {
             .get_signed(API::Futures(Futures::Income), Some(request))
             .await
     }
+
+    /// Stream every income matching `income_request`. The range defaults to
+    /// the last seven days, like `get_income`; `limit` sets the page size.
+    pub fn iter_income(
+        &self, income_request: IncomeRequest,
+    ) -> impl Stream<Item = Result<Income>> + use<> {
+        let account = self.clone();
+        let end_time = pagination::end_or_now(income_request.end_time);
+        let start_time = income_request
+            .start_time
+            .unwrap_or_else(|| end_time.saturating_sub(7 * DAY));
+        let limit = income_request
+            .limit
+            .map_or(1000, |limit| limit.clamp(1, 1000) as u16);
+        pagination::by_time(
+            start_time,
+            end_time,
+            PageLimits::new(7 * DAY, limit),
+            move |page| {
+                let account = account.clone();
+                let request = IncomeRequest {
+                    start_time: page.start_time,
+                    end_time: page.end_time,
+                    limit: Some(u32::from(page.limit)),
+                    ..income_request.clone()
+                };
+                async move { account.get_income(request).await }
+            },
+        )
+    }
+}
+
+// Order parameters, checked against `symbol_rules` first. Shared with the
+// COIN-M account, which takes the same order fields.
+pub(crate) fn build_order_params(
+    symbol_rules: &HashMap<String, SymbolRules>, order_builder: OrderRequestBuilder,
+) -> Result<BTreeMap<String, String>> {
+    if let Some(rules) = symbol_rules.get(&order_builder.symbol) {
+        let is_market = matches!(
+            order_builder.order_type,
+            OrderType::Market
+                | OrderType::StopMarket
+                | OrderType::TakeProfitMarket
+                | OrderType::TrailingStopMarket
+        );
+        rules.validate(order_builder.price, order_builder.quantity, is_market)?;
+        if let Some(stop_price) = order_builder.stop_price {
+            rules.check_price(stop_price)?;
+        }
+    }
+
+    let mut parameters = BTreeMap::new();
+    parameters.insert("symbol".into(), order_builder.symbol);
+    parameters.insert("side".into(), order_builder.side.to_string());
+    parameters.insert("type".into(), order_builder.order_type.to_string());
+
+    if let Some(ps) = order_builder.position_side {
+        parameters.insert("positionSide".into(), ps.to_string());
+    }
+    if let Some(tif) = order_builder.time_in_force {
+        parameters.insert("timeInForce".into(), tif.to_string());
+    }
+    if let Some(q) = order_builder.quantity {
+        parameters.insert("quantity".into(), q.to_string());
+    }
+    if let Some(ro) = order_builder.reduce_only {
+        parameters.insert("reduceOnly".into(), ro.to_string());
+    }
+    if let Some(p) = order_builder.price {
+        parameters.insert("price".into(), p.to_string());
+    }
+    if let Some(ncoi) = order_builder.new_client_order_id {
+        parameters.insert("newClientOrderId".into(), ncoi);
+    }
+    if let Some(sp) = order_builder.stop_price {
+        parameters.insert("stopPrice".into(), sp.to_string());
+    }
+    if let Some(cp) = order_builder.close_position {
+        parameters.insert("closePosition".into(), cp.to_string());
+    }
+    if let Some(ap) = order_builder.activation_price {
+        parameters.insert("activationPrice".into(), ap.to_string());
+    }
+    if let Some(cr) = order_builder.callback_rate {
+        parameters.insert("callbackRate".into(), cr.to_string());
+    }
+    if let Some(wt) = order_builder.working_type {
+        parameters.insert("workingType".into(), wt.to_string());
+    }
+    if let Some(pp) = order_builder.price_protect {
+        parameters.insert("priceProtect".into(), pp.to_string());
+    }
+    Ok(parameters)
+}
+
+fn check_batch_size(len: usize) -> Result<()> {
+    if len == 0 || len > MAX_BATCH_ORDERS {
+        return Err(Error::InvalidOrder(format!(
+            "batch requests take 1 to {} orders, got {}",
+            MAX_BATCH_ORDERS, len
+        )));
+    }
+    Ok(())
+}
+
+// List parameters are sent as URL-encoded JSON
+fn encode_json_param<T: serde::Serialize + ?Sized>(value: &T) -> Result<String> {
+    let json = serde_json::to_string(value)?;
+    Ok(url::form_urlencoded::byte_serialize(json.as_bytes()).collect())
 }
@@ -28,11 +28,17 @@ impl FuturesGeneral {
 
     // Obtain exchange information
     // - Current exchange trading rules and symbol information
+    // - Also applies the reported rate limits to the client's rate limiter
     pub async fn exchange_info(&self) -> Result<ExchangeInformation> {
         // async added
-        self.client
+        let info: ExchangeInformation = self
+            .client
             .get(API::Futures(Futures::ExchangeInfo), None)
-            .await // .await added
+            .await?; // .await added
+        self.client
+            .rate_limiter()
+            .set_exchange_limits(&info.rate_limits);
+        Ok(info)
     }
 
     // Get Symbol information
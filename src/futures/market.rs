@@ -2,32 +2,41 @@
 ## Implemented functionality
 - [x] `Order Book`
 - [x] `Recent Trades List`
-- [ ] `Old Trades Lookup (MARKET_DATA)`
+- [x] `Old Trades Lookup (MARKET_DATA)`
 - [x] `Compressed/Aggregate Trades List`
 - [x] `Kline/Candlestick Data`
+- [x] `Continuous Contract Kline/Candlestick Data`
+- [x] `Index Price Kline/Candlestick Data`
+- [x] `Mark Price Kline/Candlestick Data`
 - [x] `Mark Price`
-- [ ] `Get Funding Rate History (MARKET_DATA)`
+- [x] `Get Funding Rate History`
 - [x] `24hr Ticker Price Change Statistics`
 - [x] `Symbol Price Ticker`
 - [x] `Symbol Order Book Ticker`
 - [x] `Get all Liquidation Orders`
 - [x] `Open Interest`
 - [ ] `Notional and Leverage Brackets (MARKET_DATA)`
-- [ ] `Open Interest Statistics (MARKET_DATA)`
-- [ ] `Top Trader Long/Short Ratio (Accounts) (MARKET_DATA)`
-- [ ] `Top Trader Long/Short Ratio (Positions) (MARKET_DATA)`
-- [ ] `Long/Short Ratio (MARKET_DATA)`
-- [ ] `Taker Buy/Sell Volume (MARKET_DATA)`
+- [x] `Open Interest Statistics`
+- [x] `Top Trader Long/Short Ratio (Accounts)`
+- [x] `Top Trader Long/Short Ratio (Positions)`
+- [x] `Long/Short Ratio`
+- [x] `Taker Buy/Sell Volume`
+- [x] `Basis`
+- [x] `Composite Index Symbol Information`
+- [x] `Historical BLVT NAV Kline/Candlestick`
 */
 
 use crate::util::{build_request, build_signed_request};
+use crate::futures::account::ContractType;
 use crate::futures::model::{
-    AggTrades, BookTickers, KlineSummaries, KlineSummary, LiquidationOrders, MarkPrices,
-    OpenInterest, OpenInterestHist, OrderBook, PriceStats, SymbolPrice, Tickers, Trades,
+    AggTrades, Basis, BookTickers, FundingRate, IndexInfo, KlineSummaries, KlineSummary,
+    LiquidationOrders, LongShortRatio, MarkPrice, MarkPrices, OpenInterest, OpenInterestHist,
+    OrderBook, PriceStats, SymbolPrice, TakerBuySellVolume, Tickers, Trades,
 };
 use crate::client::Client;
 use crate::errors::Result; // Error will be brought in by Result if needed via crate::errors::Error
 use std::collections::BTreeMap;
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 use crate::api::API;
 use crate::api::Futures;
@@ -196,12 +205,104 @@ impl FuturesMarket {
             parameters.insert("endTime".into(), format!("{}", et));
         }
 
-        let request = build_request(parameters);
+        self.get_kline_summaries(Futures::Klines, parameters).await
+    }
+
+    // Klines of a pair for one contract type
+    pub async fn get_continuous_klines<S1, S2, S3, S4, S5>(
+        &self, pair: S1, contract_type: ContractType, interval: S2, limit: S3, start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("pair".into(), pair.into());
+        parameters.insert("contractType".into(), contract_type.into());
+        parameters.insert("interval".into(), interval.into());
+        insert_range(&mut parameters, limit, start_time, end_time);
+
+        self.get_kline_summaries(Futures::ContinuousKlines, parameters)
+            .await
+    }
+
+    /// Klines of the index price of a pair. Only the open, high, low and
+    /// close prices are set; the volumes are "0".
+    pub async fn get_index_price_klines<S1, S2, S3, S4, S5>(
+        &self, pair: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("pair".into(), pair.into());
+        parameters.insert("interval".into(), interval.into());
+        insert_range(&mut parameters, limit, start_time, end_time);
+
+        self.get_kline_summaries(Futures::IndexPriceKlines, parameters)
+            .await
+    }
+
+    /// Klines of the mark price of a symbol. Only the open, high, low and
+    /// close prices are set; the volumes are "0".
+    pub async fn get_mark_price_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+        insert_range(&mut parameters, limit, start_time, end_time);
+
+        self.get_kline_summaries(Futures::MarkPriceKlines, parameters)
+            .await
+    }
+
+    /// Klines of the net asset value of a leveraged token such as
+    /// "BTCDOWN". `volume` holds the real leverage at close,
+    /// `number_of_trades` the number of NAV updates, and
+    /// `taker_buy_base_asset_volume` the real leverage at open.
+    pub async fn get_lvt_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+        insert_range(&mut parameters, limit, start_time, end_time);
+
+        self.get_kline_summaries(Futures::LvtKlines, parameters)
+            .await
+    }
 
+    async fn get_kline_summaries(
+        &self, endpoint: Futures, parameters: BTreeMap<String, String>,
+    ) -> Result<KlineSummaries> {
+        let request = build_request(parameters);
         let data: Vec<Vec<Value>> = self
             .client
-            .get(API::Futures(Futures::Klines), Some(request))
-            .await?; // .await? added
+            .get(API::Futures(endpoint), Some(request))
+            .await?;
 
         let klines = KlineSummaries::AllKlineSummaries(
             data.iter()
@@ -290,6 +391,43 @@ impl FuturesMarket {
             .await // .await added
     }
 
+    // Mark price, index price and funding rate of ONE symbol
+    pub async fn get_mark_price<S>(&self, symbol: S) -> Result<MarkPrice>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+        self.client
+            .get(API::Futures(Futures::PremiumIndex), Some(request))
+            .await
+    }
+
+    /// Funding rate history of one symbol, or of all symbols with `None`.
+    /// Without a time range the most recent rates are returned, oldest
+    /// first (default 100, max 1000).
+    pub async fn get_funding_rate_history<S1, S2, S3, S4>(
+        &self, symbol: S1, start_time: S2, end_time: S3, limit: S4,
+    ) -> Result<Vec<FundingRate>>
+    where
+        S1: Into<Option<String>>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol.into() {
+            parameters.insert("symbol".into(), symbol);
+        }
+        insert_range(&mut parameters, limit, start_time, end_time);
+
+        let request = build_request(parameters);
+        self.client
+            .get(API::Futures(Futures::FundingRate), Some(request))
+            .await
+    }
+
     pub async fn get_all_liquidation_orders(&self) -> Result<LiquidationOrders> {
         // async added
         self.client
@@ -297,6 +435,28 @@ impl FuturesMarket {
             .await // .await added
     }
 
+    // Liquidation orders of ONE symbol, or of all symbols with `None`
+    pub async fn get_liquidation_orders<S1, S2, S3, S4>(
+        &self, symbol: S1, start_time: S2, end_time: S3, limit: S4,
+    ) -> Result<LiquidationOrders>
+    where
+        S1: Into<Option<String>>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol.into() {
+            parameters.insert("symbol".into(), symbol);
+        }
+        insert_range(&mut parameters, limit, start_time, end_time);
+
+        let request = build_request(parameters);
+        self.client
+            .get(API::Futures(Futures::AllForceOrders), Some(request))
+            .await
+    }
+
     pub async fn open_interest<S>(&self, symbol: S) -> Result<OpenInterest>
     // async added
     where
@@ -345,4 +505,164 @@ impl FuturesMarket {
             .get(API::Futures(Futures::OpenInterestHist), Some(request))
             .await // .await added
     }
+
+    /// Long/short account ratio of the top 20% traders by margin balance.
+    /// `period` is one of "5m", "15m", "30m", "1h", "2h", "4h", "6h", "12h"
+    /// or "1d"; only the last 30 days are available.
+    pub async fn top_long_short_account_ratio<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::TopLongShortAccountRatio,
+            symbol_and_period(symbol, period, limit, start_time, end_time),
+        )
+        .await
+    }
+
+    /// Long/short position ratio of the top 20% traders by margin balance.
+    pub async fn top_long_short_position_ratio<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::TopLongShortPositionRatio,
+            symbol_and_period(symbol, period, limit, start_time, end_time),
+        )
+        .await
+    }
+
+    /// Long/short ratio of all accounts with a position in the symbol.
+    pub async fn global_long_short_account_ratio<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::GlobalLongShortAccountRatio,
+            symbol_and_period(symbol, period, limit, start_time, end_time),
+        )
+        .await
+    }
+
+    /// Taker buy and sell volume per period.
+    pub async fn taker_buy_sell_volume<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<TakerBuySellVolume>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::TakerlongshortRatio,
+            symbol_and_period(symbol, period, limit, start_time, end_time),
+        )
+        .await
+    }
+
+    /// Basis of a pair for one contract type: the futures price against
+    /// the index price.
+    pub async fn basis<S1, S2, S3, S4, S5>(
+        &self, pair: S1, contract_type: ContractType, period: S2, limit: S3, start_time: S4,
+        end_time: S5,
+    ) -> Result<Vec<Basis>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("pair".into(), pair.into());
+        parameters.insert("contractType".into(), contract_type.into());
+        parameters.insert("period".into(), period.into());
+        insert_range(&mut parameters, limit, start_time, end_time);
+
+        self.get_statistics(Futures::Basis, parameters).await
+    }
+
+    async fn get_statistics<T: DeserializeOwned>(
+        &self, endpoint: Futures, parameters: BTreeMap<String, String>,
+    ) -> Result<Vec<T>> {
+        let request = build_request(parameters);
+        self.client.get(API::Futures(endpoint), Some(request)).await
+    }
+
+    // Components of ONE composite index, or of all of them with `None`
+    pub async fn get_index_info<S>(&self, symbol: S) -> Result<Vec<IndexInfo>>
+    where
+        S: Into<Option<String>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol.into() {
+            parameters.insert("symbol".into(), symbol);
+        }
+        let request = build_request(parameters);
+        // A single index comes back as an object rather than a list.
+        let value: Value = self
+            .client
+            .get(API::Futures(Futures::IndexInfo), Some(request))
+            .await?;
+        let infos = match value {
+            Value::Array(_) => serde_json::from_value(value)?,
+            value => vec![serde_json::from_value(value)?],
+        };
+        Ok(infos)
+    }
+}
+
+fn symbol_and_period<S1, S2, S3, S4, S5>(
+    symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+) -> BTreeMap<String, String>
+where
+    S1: Into<String>,
+    S2: Into<String>,
+    S3: Into<Option<u16>>,
+    S4: Into<Option<u64>>,
+    S5: Into<Option<u64>>,
+{
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("symbol".into(), symbol.into());
+    parameters.insert("period".into(), period.into());
+    insert_range(&mut parameters, limit, start_time, end_time);
+    parameters
+}
+
+fn insert_range<S1, S2, S3>(
+    parameters: &mut BTreeMap<String, String>, limit: S1, start_time: S2, end_time: S3,
+) where
+    S1: Into<Option<u16>>,
+    S2: Into<Option<u64>>,
+    S3: Into<Option<u64>>,
+{
+    if let Some(lt) = limit.into() {
+        parameters.insert("limit".into(), format!("{}", lt));
+    }
+    if let Some(st) = start_time.into() {
+        parameters.insert("startTime".into(), format!("{}", st));
+    }
+    if let Some(et) = end_time.into() {
+        parameters.insert("endTime".into(), format!("{}", et));
+    }
 }
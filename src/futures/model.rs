@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use crate::model::{string_or_float, string_or_float_opt, string_or_bool};
+use crate::model::{string_or_float, string_or_float_opt, string_or_bool, Number, NumberString};
 
 pub use crate::model::{
     Asks, Bids, BookTickers, Filters, KlineSummaries, KlineSummary, RateLimit, ServerTime,
@@ -53,23 +53,23 @@ pub struct OrderBook {
 #[serde(rename_all = "camelCase")]
 pub struct PriceStats {
     pub symbol: String,
-    pub price_change: String,
-    pub price_change_percent: String,
-    pub weighted_avg_price: String,
+    pub price_change: NumberString,
+    pub price_change_percent: NumberString,
+    pub weighted_avg_price: NumberString,
     #[serde(with = "string_or_float")]
-    pub last_price: f64,
+    pub last_price: Number,
     #[serde(with = "string_or_float")]
-    pub open_price: f64,
+    pub open_price: Number,
     #[serde(with = "string_or_float")]
-    pub high_price: f64,
+    pub high_price: Number,
     #[serde(with = "string_or_float")]
-    pub low_price: f64,
+    pub low_price: Number,
     #[serde(with = "string_or_float")]
-    pub volume: f64,
+    pub volume: Number,
     #[serde(with = "string_or_float")]
-    pub quote_volume: f64,
+    pub quote_volume: Number,
     #[serde(with = "string_or_float")]
-    pub last_qty: f64,
+    pub last_qty: Number,
     pub open_time: u64,
     pub close_time: u64,
     pub first_id: u64,
@@ -82,19 +82,19 @@ pub struct PriceStats {
 pub struct TradeHistory {
     pub buyer: bool,
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Number,
     pub commission_asset: String,
     pub id: u64,
     pub maker: bool,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     #[serde(with = "string_or_float")]
-    pub quote_qty: f64,
+    pub quote_qty: Number,
     #[serde(with = "string_or_float")]
-    pub realized_pnl: f64,
+    pub realized_pnl: Number,
     pub side: String,
     pub position_side: String,
     pub symbol: String,
@@ -113,11 +113,11 @@ pub struct Trade {
     pub id: u64,
     pub is_buyer_maker: bool,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     #[serde(with = "string_or_float")]
-    pub quote_qty: f64,
+    pub quote_qty: Number,
     pub time: u64,
 }
 
@@ -141,9 +141,9 @@ pub struct AggTrade {
     #[serde(rename = "m")]
     pub maker: bool,
     #[serde(rename = "p", with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(rename = "q", with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -157,11 +157,18 @@ pub enum MarkPrices {
 pub struct MarkPrice {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub mark_price: f64,
+    pub mark_price: Number,
     #[serde(with = "string_or_float")]
-    pub last_funding_rate: f64,
+    pub last_funding_rate: Number,
     pub next_funding_time: u64,
     pub time: u64,
+    #[serde(default, with = "string_or_float_opt")]
+    pub index_price: Option<Number>,
+    /// Only meaningful in the last hour before delivery or settlement.
+    #[serde(default, with = "string_or_float_opt")]
+    pub estimated_settle_price: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub interest_rate: Option<Number>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -174,13 +181,13 @@ pub enum LiquidationOrders {
 #[serde(rename_all = "camelCase")]
 pub struct LiquidationOrder {
     #[serde(with = "string_or_float")]
-    pub average_price: f64,
+    pub average_price: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     pub side: String,
     pub status: String,
     pub symbol: String,
@@ -193,7 +200,7 @@ pub struct LiquidationOrder {
 #[serde(rename_all = "camelCase")]
 pub struct OpenInterest {
     #[serde(with = "string_or_float")]
-    pub open_interest: f64,
+    pub open_interest: Number,
     pub symbol: String,
 }
 
@@ -201,34 +208,115 @@ pub struct OpenInterest {
 #[serde(rename_all = "camelCase")]
 pub struct OpenInterestHist {
     pub symbol: String,
-    pub sum_open_interest: String,
-    pub sum_open_interest_value: String,
+    pub sum_open_interest: NumberString,
+    pub sum_open_interest_value: NumberString,
     pub timestamp: u64,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub funding_rate: Number,
+    pub funding_time: u64,
+    /// Missing for older records.
+    #[serde(default, with = "string_or_float_opt")]
+    pub mark_price: Option<Number>,
+}
+
+/// Long/short ratio of top traders' accounts or positions, or of all
+/// accounts, over one period.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortRatio {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub long_short_ratio: Number,
+    /// Share of long accounts or positions, from 0 to 1.
+    #[serde(with = "string_or_float")]
+    pub long_account: Number,
+    #[serde(with = "string_or_float")]
+    pub short_account: Number,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TakerBuySellVolume {
+    #[serde(with = "string_or_float")]
+    pub buy_sell_ratio: Number,
+    #[serde(with = "string_or_float")]
+    pub buy_vol: Number,
+    #[serde(with = "string_or_float")]
+    pub sell_vol: Number,
+    pub timestamp: u64,
+}
+
+/// Futures price against index price of a pair over one period.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Basis {
+    pub pair: String,
+    pub contract_type: String,
+    #[serde(with = "string_or_float")]
+    pub futures_price: Number,
+    #[serde(with = "string_or_float")]
+    pub index_price: Number,
+    #[serde(with = "string_or_float")]
+    pub basis: Number,
+    #[serde(with = "string_or_float")]
+    pub basis_rate: Number,
+    /// Not given for perpetual contracts.
+    #[serde(default, with = "string_or_float_opt")]
+    pub annualized_basis_rate: Option<Number>,
+    pub timestamp: u64,
+}
+
+/// Components of a composite index symbol such as "DEFIUSDT".
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexInfo {
+    pub symbol: String,
+    pub time: u64,
+    pub component: String,
+    pub base_asset_list: Vec<IndexComponent>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexComponent {
+    pub base_asset: String,
+    pub quote_asset: String,
+    #[serde(with = "string_or_float")]
+    pub weight_in_quantity: Number,
+    #[serde(with = "string_or_float")]
+    pub weight_in_percentage: Number,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Order {
     pub client_order_id: String,
     #[serde(with = "string_or_float", default = "default_stop_price")]
-    pub cum_qty: f64,
+    pub cum_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     pub side: String,
     pub reduce_only: bool,
     pub position_side: String,
     pub status: String,
     #[serde(with = "string_or_float", default = "default_stop_price")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: String,
@@ -236,9 +324,11 @@ pub struct Order {
     pub order_type: String,
     pub orig_type: String,
     #[serde(with = "string_or_float", default = "default_activation_price")]
-    pub activation_price: f64,
+    pub activation_price: Number,
     #[serde(with = "string_or_float", default = "default_price_rate")]
-    pub price_rate: f64,
+    pub price_rate: Number,
+    #[serde(default)]
+    pub time: u64,
     pub update_time: u64,
     pub working_type: String,
     pub price_protect: bool,
@@ -249,22 +339,22 @@ pub struct Order {
 pub struct Transaction {
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub cum_qty: f64,
+    pub cum_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     pub reduce_only: bool,
     pub side: String,
     pub position_side: String,
     pub status: String,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: String,
@@ -273,10 +363,10 @@ pub struct Transaction {
     pub orig_type: String,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub activate_price: Option<f64>,
+    pub activate_price: Option<Number>,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub price_rate: Option<f64>,
+    pub price_rate: Option<Number>,
     pub update_time: u64,
     pub working_type: String,
     price_protect: bool,
@@ -287,23 +377,23 @@ pub struct Transaction {
 pub struct CanceledOrder {
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub cum_qty: f64,
+    pub cum_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     pub orig_type: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     pub reduce_only: bool,
     pub side: String,
     pub position_side: String,
     pub status: String,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: String,
@@ -311,10 +401,10 @@ pub struct CanceledOrder {
     pub type_name: String,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub activate_price: Option<f64>,
+    pub activate_price: Option<Number>,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub price_rate: Option<f64>,
+    pub price_rate: Option<Number>,
     pub update_time: u64,
     pub working_type: String,
     price_protect: bool,
@@ -324,29 +414,29 @@ pub struct CanceledOrder {
 #[serde(rename_all = "camelCase")]
 pub struct PositionRisk {
     #[serde(with = "string_or_float")]
-    pub entry_price: f64,
+    pub entry_price: Number,
     pub margin_type: String,
     #[serde(with = "string_or_bool")]
     pub is_auto_add_margin: bool,
     #[serde(with = "string_or_float")]
-    pub isolated_margin: f64,
+    pub isolated_margin: Number,
     pub leverage: String,
     #[serde(with = "string_or_float")]
-    pub liquidation_price: f64,
+    pub liquidation_price: Number,
     #[serde(with = "string_or_float")]
-    pub mark_price: f64,
+    pub mark_price: Number,
     #[serde(with = "string_or_float")]
-    pub max_notional_value: f64,
+    pub max_notional_value: Number,
     #[serde(with = "string_or_float", rename = "positionAmt")]
-    pub position_amount: f64,
+    pub position_amount: Number,
     pub symbol: String,
     #[serde(with = "string_or_float", rename = "unRealizedProfit")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Number,
     pub position_side: String,
     #[serde(with = "string_or_float")]
-    pub notional: f64,
+    pub notional: Number,
     #[serde(with = "string_or_float")]
-    pub isolated_wallet: f64,
+    pub isolated_wallet: Number,
     pub update_time: u64,
 }
 
@@ -355,27 +445,27 @@ pub struct PositionRisk {
 pub struct FuturesAsset {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub wallet_balance: f64,
+    pub wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Number,
     #[serde(with = "string_or_float")]
-    pub margin_balance: f64,
+    pub margin_balance: Number,
     #[serde(with = "string_or_float")]
-    pub maint_margin: f64,
+    pub maint_margin: Number,
     #[serde(with = "string_or_float")]
-    pub initial_margin: f64,
+    pub initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub position_initial_margin: f64,
+    pub position_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub open_order_initial_margin: f64,
+    pub open_order_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub max_withdraw_amount: f64,
+    pub max_withdraw_amount: Number,
     #[serde(with = "string_or_float")]
-    pub cross_wallet_balance: f64,
+    pub cross_wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub cross_un_pnl: f64,
+    pub cross_un_pnl: Number,
     #[serde(with = "string_or_float")]
-    pub available_balance: f64,
+    pub available_balance: Number,
     #[serde(with = "string_or_bool")]
     pub margin_available: bool,
     pub update_time: u64,
@@ -386,41 +476,41 @@ pub struct FuturesAsset {
 pub struct FuturesPosition {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub initial_margin: f64,
+    pub initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub maint_margin: f64,
+    pub maint_margin: Number,
     #[serde(with = "string_or_float")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Number,
     #[serde(with = "string_or_float")]
-    pub position_initial_margin: f64,
+    pub position_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub open_order_initial_margin: f64,
+    pub open_order_initial_margin: Number,
     pub leverage: String,
     #[serde(with = "string_or_bool")]
     pub isolated: bool,
     #[serde(with = "string_or_float")]
-    pub entry_price: f64,
+    pub entry_price: Number,
     #[serde(with = "string_or_float")]
-    pub max_notional: f64,
+    pub max_notional: Number,
     pub position_side: String,
     #[serde(with = "string_or_float", rename = "positionAmt")]
-    pub position_amount: f64,
+    pub position_amount: Number,
     #[serde(with = "string_or_float")]
-    pub notional: f64,
+    pub notional: Number,
     #[serde(with = "string_or_float")]
-    pub isolated_wallet: f64,
+    pub isolated_wallet: Number,
     pub update_time: u64,
     #[serde(with = "string_or_float")]
-    pub bid_notional: f64,
+    pub bid_notional: Number,
     #[serde(with = "string_or_float")]
-    pub ask_notional: f64,
+    pub ask_notional: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountInformation {
     #[serde(with = "string_or_float")]
-    pub fee_tier: f64,
+    pub fee_tier: Number,
     #[serde(with = "string_or_bool")]
     pub can_trade: bool,
     #[serde(with = "string_or_bool")]
@@ -428,29 +518,29 @@ pub struct AccountInformation {
     #[serde(with = "string_or_bool")]
     pub can_withdraw: bool,
     #[serde(with = "string_or_float")]
-    pub update_time: f64,
+    pub update_time: Number,
     #[serde(with = "string_or_float")]
-    pub total_initial_margin: f64,
+    pub total_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_maint_margin: f64,
+    pub total_maint_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_wallet_balance: f64,
+    pub total_wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub total_unrealized_profit: f64,
+    pub total_unrealized_profit: Number,
     #[serde(with = "string_or_float")]
-    pub total_margin_balance: f64,
+    pub total_margin_balance: Number,
     #[serde(with = "string_or_float")]
-    pub total_position_initial_margin: f64,
+    pub total_position_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_open_order_initial_margin: f64,
+    pub total_open_order_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_cross_wallet_balance: f64,
+    pub total_cross_wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub total_cross_un_pnl: f64,
+    pub total_cross_un_pnl: Number,
     #[serde(with = "string_or_float")]
-    pub available_balance: f64,
+    pub available_balance: Number,
     #[serde(with = "string_or_float")]
-    pub max_withdraw_amount: f64,
+    pub max_withdraw_amount: Number,
     pub assets: Vec<FuturesAsset>,
     pub positions: Vec<FuturesPosition>,
 }
@@ -461,15 +551,15 @@ pub struct AccountBalance {
     pub account_alias: String,
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub balance: f64,
+    pub balance: Number,
     #[serde(with = "string_or_float")]
-    pub cross_wallet_balance: f64,
+    pub cross_wallet_balance: Number,
     #[serde(with = "string_or_float", rename = "crossUnPnl")]
-    pub cross_unrealized_pnl: f64,
+    pub cross_unrealized_pnl: Number,
     #[serde(with = "string_or_float")]
-    pub available_balance: f64,
+    pub available_balance: Number,
     #[serde(with = "string_or_float")]
-    pub max_withdraw_amount: f64,
+    pub max_withdraw_amount: Number,
     pub margin_available: bool,
     pub update_time: u64,
 }
@@ -479,18 +569,18 @@ pub struct AccountBalance {
 pub struct ChangeLeverageResponse {
     pub leverage: u8,
     #[serde(with = "string_or_float")]
-    pub max_notional_value: f64,
+    pub max_notional_value: Number,
     pub symbol: String,
 }
 
-fn default_stop_price() -> f64 {
-    0.0
+fn default_stop_price() -> Number {
+    Number::default()
 }
-fn default_activation_price() -> f64 {
-    0.0
+fn default_activation_price() -> Number {
+    Number::default()
 }
-fn default_price_rate() -> f64 {
-    0.0
+fn default_price_rate() -> Number {
+    Number::default()
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -512,16 +602,16 @@ pub struct OrderUpdate {
     pub time_in_force: String,
 
     #[serde(rename = "q")]
-    pub qty: String,
+    pub qty: NumberString,
 
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: NumberString,
 
     #[serde(rename = "ap")]
-    pub average_price: String,
+    pub average_price: NumberString,
 
     #[serde(rename = "sp")]
-    pub stop_price: String,
+    pub stop_price: NumberString,
 
     #[serde(rename = "x")]
     pub execution_type: String,
@@ -533,13 +623,13 @@ pub struct OrderUpdate {
     pub order_id: u64,
 
     #[serde(rename = "l")]
-    pub qty_last_filled_trade: String,
+    pub qty_last_filled_trade: NumberString,
 
     #[serde(rename = "z")]
-    pub accumulated_qty_filled_trades: String,
+    pub accumulated_qty_filled_trades: NumberString,
 
     #[serde(rename = "L")]
-    pub price_last_filled_trade: String,
+    pub price_last_filled_trade: NumberString,
 
     #[serde(skip, rename = "N")]
     pub asset_commisioned: Option<String>,
@@ -554,10 +644,10 @@ pub struct OrderUpdate {
     pub trade_id: i64,
 
     #[serde(rename = "b")]
-    pub bids_notional: String,
+    pub bids_notional: NumberString,
 
     #[serde(rename = "a")]
-    pub ask_notional: String,
+    pub ask_notional: NumberString,
 
     #[serde(rename = "m")]
     pub is_buyer_maker: bool,
@@ -593,7 +683,7 @@ pub struct OrderUpdate {
     pub ss_ignore: i32,
 
     #[serde(rename = "rp")]
-    pub realized_profit: String,
+    pub realized_profit: NumberString,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -612,13 +702,248 @@ pub struct OrderTradeEvent {
     pub order: OrderUpdate,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MarginCallEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    /// Cross wallet balance, only pushed with crossed positions.
+    #[serde(rename = "cw")]
+    pub cross_wallet_balance: Option<NumberString>,
+
+    #[serde(rename = "p")]
+    pub positions: Vec<MarginCallPosition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MarginCallPosition {
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "ps")]
+    pub position_side: String,
+
+    #[serde(rename = "pa")]
+    pub position_amount: NumberString,
+
+    #[serde(rename = "mt")]
+    pub margin_type: String,
+
+    /// Isolated wallet, only pushed with isolated positions.
+    #[serde(rename = "iw")]
+    pub isolated_wallet: Option<NumberString>,
+
+    #[serde(rename = "mp")]
+    pub mark_price: NumberString,
+
+    #[serde(rename = "up")]
+    pub unrealized_pnl: NumberString,
+
+    #[serde(rename = "mm")]
+    pub maintenance_margin_required: NumberString,
+}
+
+/// Pushed on a leverage change (`leverage_update`) or a multi-assets mode
+/// change (`multi_assets_update`).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountConfigUpdateEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "ac")]
+    pub leverage_update: Option<LeverageUpdate>,
+
+    #[serde(rename = "ai")]
+    pub multi_assets_update: Option<MultiAssetsUpdate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LeverageUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "l")]
+    pub leverage: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MultiAssetsUpdate {
+    #[serde(rename = "j")]
+    pub multi_assets_margin: bool,
+}
+
+/// Condensed fill of an order, pushed ahead of the matching
+/// `ORDER_TRADE_UPDATE`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TradeLiteEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "q")]
+    pub qty: NumberString,
+
+    #[serde(rename = "p")]
+    pub price: NumberString,
+
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+
+    #[serde(rename = "S")]
+    pub side: String,
+
+    #[serde(rename = "L")]
+    pub last_filled_price: NumberString,
+
+    #[serde(rename = "l")]
+    pub last_filled_qty: NumberString,
+
+    #[serde(rename = "t")]
+    pub trade_id: u64,
+
+    #[serde(rename = "i")]
+    pub order_id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GridUpdateEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "gu")]
+    pub grid: GridUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GridUpdate {
+    #[serde(rename = "si")]
+    pub strategy_id: u64,
+
+    #[serde(rename = "st")]
+    pub strategy_type: String,
+
+    #[serde(rename = "ss")]
+    pub strategy_status: String,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "r")]
+    pub realized_pnl: NumberString,
+
+    #[serde(rename = "up")]
+    pub unmatched_average_price: NumberString,
+
+    #[serde(rename = "uq")]
+    pub unmatched_qty: NumberString,
+
+    #[serde(rename = "uf")]
+    pub unmatched_fee: NumberString,
+
+    #[serde(rename = "mp")]
+    pub matched_pnl: NumberString,
+
+    #[serde(rename = "ut")]
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StrategyUpdateEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "su")]
+    pub strategy: StrategyUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StrategyUpdate {
+    #[serde(rename = "si")]
+    pub strategy_id: u64,
+
+    #[serde(rename = "st")]
+    pub strategy_type: String,
+
+    #[serde(rename = "ss")]
+    pub strategy_status: String,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "ut")]
+    pub update_time: u64,
+
+    /// Operation code, e.g. 8007 when the strategy is stopped.
+    #[serde(rename = "c")]
+    pub op_code: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConditionalOrderTriggerRejectEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "or")]
+    pub order: RejectedOrder,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RejectedOrder {
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "i")]
+    pub order_id: u64,
+
+    #[serde(rename = "r")]
+    pub reject_reason: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Income {
     pub symbol: String,
     pub income_type: String,
     #[serde(with = "string_or_float")]
-    pub income: f64,
+    pub income: Number,
     pub asset: String,
     pub info: String,
     pub time: u64,
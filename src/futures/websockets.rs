@@ -1,5 +1,6 @@
 use crate::config::Config;
 use crate::errors::Result;
+use crate::event_channel::{ChannelConfig, EventReceiver, EventSender, event_channel};
 use crate::futures::model as futures_model;
 use crate::model::{
     AccountUpdateEvent, AggrTradesEvent, BookTickerEvent, ContinuousKlineEvent, DayTickerEvent,
@@ -7,11 +8,17 @@ use crate::model::{
     MarkPriceEvent, MiniTickerEvent, OrderBook, TradeEvent, UserDataStreamExpiredEvent,
 };
 // Alias for futures specific OrderTradeEvent
-use crate::async_websocket_client::AsyncWebsocketClient;
+use crate::async_websocket_client::{
+    AsyncWebsocketClient, ConnectionEvent, ReconnectPolicy, SpawnedSocket, StreamEvent,
+};
 // New
 
+use serde::de::{self, Deserializer};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
+use crate::websockets::{UntypedPayload, event_type, join_streams, untyped_payload};
+use std::fmt::Display;
 use std::future::Future;
 // New
 use std::pin::Pin;
@@ -33,6 +40,10 @@ enum FuturesWebsocketAPI {
 pub enum FuturesMarket {
     USDM,
     COINM,
+    /// European options market streams. [`OptionsWebSockets`] parses them
+    /// into typed events.
+    ///
+    /// [`OptionsWebSockets`]: crate::options::websockets::OptionsWebSockets
     Vanilla,
 }
 
@@ -41,7 +52,7 @@ impl FuturesWebsocketAPI {
         let baseurl = match market {
             FuturesMarket::USDM => "wss://fstream.binance.com",
             FuturesMarket::COINM => "wss://dstream.binance.com",
-            FuturesMarket::Vanilla => "wss://vstream.binance.com",
+            FuturesMarket::Vanilla => "wss://nbstream.binance.com/eoptions",
         };
 
         match self {
@@ -78,11 +89,19 @@ pub enum FuturesWebsocketEvent {
     DepthOrderBook(DepthOrderBookEvent),
     BookTicker(BookTickerEvent),
     UserDataStreamExpiredEvent(UserDataStreamExpiredEvent),
+    MarginCall(futures_model::MarginCallEvent),
+    AccountConfigUpdate(futures_model::AccountConfigUpdateEvent),
+    TradeLite(futures_model::TradeLiteEvent),
+    GridUpdate(futures_model::GridUpdateEvent),
+    StrategyUpdate(futures_model::StrategyUpdateEvent),
+    ConditionalOrderTriggerReject(futures_model::ConditionalOrderTriggerRejectEvent),
+    /// An event without a typed variant, as received.
+    Unknown(Value),
+    Connection(ConnectionEvent),
 }
 
 // Events enum is what AsyncWebsocketClient will deserialize into (as E)
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(untagged)]
+#[derive(Serialize, Debug, Clone)]
 enum FuturesEvents {
     VecDayTicker(Vec<DayTickerEvent>), // Renamed to avoid conflict if used directly in match
     DayTickerEvent(DayTickerEvent),
@@ -90,7 +109,7 @@ enum FuturesEvents {
     MiniTickerEvent(MiniTickerEvent),
     VecMiniTickerEvent(Vec<MiniTickerEvent>),
     AccountUpdateEvent(AccountUpdateEvent),
-    OrderTradeEvent(futures_model::OrderTradeEvent), // Use aliased model
+    OrderTradeEvent(Box<futures_model::OrderTradeEvent>), // Use aliased model
     AggrTradesEvent(AggrTradesEvent),
     IndexPriceEvent(IndexPriceEvent),
     MarkPriceEvent(MarkPriceEvent),
@@ -103,95 +122,253 @@ enum FuturesEvents {
     OrderBook(OrderBook),
     DepthOrderBookEvent(DepthOrderBookEvent),
     UserDataStreamExpiredEvent(UserDataStreamExpiredEvent),
+    MarginCallEvent(futures_model::MarginCallEvent),
+    AccountConfigUpdateEvent(futures_model::AccountConfigUpdateEvent),
+    TradeLiteEvent(futures_model::TradeLiteEvent),
+    GridUpdateEvent(futures_model::GridUpdateEvent),
+    StrategyUpdateEvent(futures_model::StrategyUpdateEvent),
+    ConditionalOrderTriggerRejectEvent(futures_model::ConditionalOrderTriggerRejectEvent),
+    Unknown(Value),
+    #[serde(skip)]
+    Connection(ConnectionEvent),
+}
+
+impl FuturesEvents {
+    fn from_value(value: Value) -> serde_json::Result<Self> {
+        let event = match (value.is_array(), event_type(&value)) {
+            (true, Some("24hrTicker")) => {
+                FuturesEvents::VecDayTicker(serde_json::from_value(value)?)
+            }
+            (true, Some("24hrMiniTicker")) => {
+                FuturesEvents::VecMiniTickerEvent(serde_json::from_value(value)?)
+            }
+            (true, Some("markPriceUpdate")) => {
+                FuturesEvents::VecMarkPriceEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("24hrTicker")) => {
+                FuturesEvents::DayTickerEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("bookTicker")) => {
+                FuturesEvents::BookTickerEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("24hrMiniTicker")) => {
+                FuturesEvents::MiniTickerEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("ACCOUNT_UPDATE")) => {
+                FuturesEvents::AccountUpdateEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("ORDER_TRADE_UPDATE")) => {
+                FuturesEvents::OrderTradeEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("aggTrade")) => {
+                FuturesEvents::AggrTradesEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("indexPriceUpdate")) => {
+                FuturesEvents::IndexPriceEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("markPriceUpdate")) => {
+                FuturesEvents::MarkPriceEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("trade")) => FuturesEvents::TradeEvent(serde_json::from_value(value)?),
+            (false, Some("kline")) => FuturesEvents::KlineEvent(serde_json::from_value(value)?),
+            (false, Some("continuous_kline")) => {
+                FuturesEvents::ContinuousKlineEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("indexPrice_kline")) => {
+                FuturesEvents::IndexKlineEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("forceOrder")) => {
+                FuturesEvents::LiquidationEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("depthUpdate")) => {
+                FuturesEvents::DepthOrderBookEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("listenKeyExpired")) => {
+                FuturesEvents::UserDataStreamExpiredEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("MARGIN_CALL")) => {
+                FuturesEvents::MarginCallEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("ACCOUNT_CONFIG_UPDATE")) => {
+                FuturesEvents::AccountConfigUpdateEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("TRADE_LITE")) => {
+                FuturesEvents::TradeLiteEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("GRID_UPDATE")) => {
+                FuturesEvents::GridUpdateEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("STRATEGY_UPDATE")) => {
+                FuturesEvents::StrategyUpdateEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("CONDITIONAL_ORDER_TRIGGER_REJECT")) => {
+                FuturesEvents::ConditionalOrderTriggerRejectEvent(serde_json::from_value(value)?)
+            }
+            (false, None) => match untyped_payload(&value) {
+                Some(UntypedPayload::PartialDepth) => {
+                    FuturesEvents::OrderBook(serde_json::from_value(value)?)
+                }
+                Some(UntypedPayload::BookTicker) => {
+                    FuturesEvents::BookTickerEvent(serde_json::from_value(value)?)
+                }
+                None => FuturesEvents::Unknown(value),
+            },
+            _ => FuturesEvents::Unknown(value),
+        };
+        Ok(event)
+    }
+}
+
+impl<'de> Deserialize<'de> for FuturesEvents {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        FuturesEvents::from_value(Value::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+impl From<ConnectionEvent> for FuturesEvents {
+    fn from(event: ConnectionEvent) -> Self {
+        FuturesEvents::Connection(event)
+    }
+}
+
+impl From<FuturesEvents> for FuturesWebsocketEvent {
+    fn from(events_obj: FuturesEvents) -> Self {
+        match events_obj {
+            FuturesEvents::VecDayTicker(v) => FuturesWebsocketEvent::DayTickerAll(v),
+            FuturesEvents::DayTickerEvent(v) => FuturesWebsocketEvent::DayTicker(v),
+            FuturesEvents::BookTickerEvent(v) => FuturesWebsocketEvent::BookTicker(v),
+            FuturesEvents::MiniTickerEvent(v) => FuturesWebsocketEvent::MiniTicker(v),
+            FuturesEvents::VecMiniTickerEvent(v) => FuturesWebsocketEvent::MiniTickerAll(v),
+            FuturesEvents::AccountUpdateEvent(v) => FuturesWebsocketEvent::AccountUpdate(v),
+            FuturesEvents::OrderTradeEvent(v) => FuturesWebsocketEvent::OrderTrade(*v),
+            FuturesEvents::IndexPriceEvent(v) => FuturesWebsocketEvent::IndexPrice(v),
+            FuturesEvents::MarkPriceEvent(v) => FuturesWebsocketEvent::MarkPrice(v),
+            FuturesEvents::VecMarkPriceEvent(v) => FuturesWebsocketEvent::MarkPriceAll(v),
+            FuturesEvents::TradeEvent(v) => FuturesWebsocketEvent::Trade(v),
+            FuturesEvents::ContinuousKlineEvent(v) => FuturesWebsocketEvent::ContinuousKline(v),
+            FuturesEvents::IndexKlineEvent(v) => FuturesWebsocketEvent::IndexKline(v),
+            FuturesEvents::LiquidationEvent(v) => FuturesWebsocketEvent::Liquidation(v),
+            FuturesEvents::KlineEvent(v) => FuturesWebsocketEvent::Kline(v),
+            FuturesEvents::OrderBook(v) => FuturesWebsocketEvent::OrderBook(v),
+            FuturesEvents::DepthOrderBookEvent(v) => FuturesWebsocketEvent::DepthOrderBook(v),
+            FuturesEvents::AggrTradesEvent(v) => FuturesWebsocketEvent::AggrTrades(v),
+            FuturesEvents::UserDataStreamExpiredEvent(v) => {
+                FuturesWebsocketEvent::UserDataStreamExpiredEvent(v)
+            }
+            FuturesEvents::MarginCallEvent(v) => FuturesWebsocketEvent::MarginCall(v),
+            FuturesEvents::AccountConfigUpdateEvent(v) => {
+                FuturesWebsocketEvent::AccountConfigUpdate(v)
+            }
+            FuturesEvents::TradeLiteEvent(v) => FuturesWebsocketEvent::TradeLite(v),
+            FuturesEvents::GridUpdateEvent(v) => FuturesWebsocketEvent::GridUpdate(v),
+            FuturesEvents::StrategyUpdateEvent(v) => FuturesWebsocketEvent::StrategyUpdate(v),
+            FuturesEvents::ConditionalOrderTriggerRejectEvent(v) => {
+                FuturesWebsocketEvent::ConditionalOrderTriggerReject(v)
+            }
+            FuturesEvents::Unknown(v) => FuturesWebsocketEvent::Unknown(v),
+            FuturesEvents::Connection(v) => FuturesWebsocketEvent::Connection(v),
+        }
+    }
 }
 
 // Define the type for the adapter handler passed to AsyncWebsocketClient
 type AdapterHandler<'a> = Box<
-    dyn FnMut(FuturesEvents) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>
+    dyn FnMut(StreamEvent<FuturesEvents>) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>
         + Send
         + Sync
         + 'a,
 >;
 
 pub struct FuturesWebSockets<'a> {
+    // Set for sockets built with `channel`, to report the error that ends `spawn`.
+    events: Option<EventSender<Result<StreamEvent<FuturesWebsocketEvent>>>>,
     client: AsyncWebsocketClient<'a, FuturesEvents, AdapterHandler<'a>>,
 }
 
 impl<'a> FuturesWebSockets<'a> {
-    pub fn new<Callback>(user_handler: Callback) -> FuturesWebSockets<'a>
+    pub fn new<Callback>(mut user_handler: Callback) -> FuturesWebSockets<'a>
     where
         Callback: FnMut(FuturesWebsocketEvent) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>
             + Send
             + Sync
             + 'a,
+    {
+        Self::new_with_stream_events(move |event: StreamEvent<FuturesWebsocketEvent>| {
+            user_handler(event.event)
+        })
+    }
+
+    /// Like [`new`](Self::new), but the handler also gets the stream name and
+    /// the local receive time of each event, so that one combined-stream
+    /// connection can feed a consumer per stream.
+    pub fn new_with_stream_events<Callback>(user_handler: Callback) -> FuturesWebSockets<'a>
+    where
+        Callback: FnMut(
+                StreamEvent<FuturesWebsocketEvent>,
+            ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>
+            + Send
+            + Sync
+            + 'a,
     {
         let shared_user_handler = Arc::new(TokioMutex::new(user_handler));
 
-        let adapter_handler: AdapterHandler<'a> = Box::new(move |events_obj: FuturesEvents| {
-            let user_handler_clone = Arc::clone(&shared_user_handler);
-            Box::pin(async move {
-                let action = match events_obj {
-                    FuturesEvents::VecDayTicker(v) => FuturesWebsocketEvent::DayTickerAll(v),
-                    FuturesEvents::DayTickerEvent(v) => FuturesWebsocketEvent::DayTicker(v),
-                    FuturesEvents::BookTickerEvent(v) => FuturesWebsocketEvent::BookTicker(v),
-                    FuturesEvents::MiniTickerEvent(v) => FuturesWebsocketEvent::MiniTicker(v),
-                    FuturesEvents::VecMiniTickerEvent(v) => FuturesWebsocketEvent::MiniTickerAll(v),
-                    FuturesEvents::AccountUpdateEvent(v) => FuturesWebsocketEvent::AccountUpdate(v),
-                    FuturesEvents::OrderTradeEvent(v) => FuturesWebsocketEvent::OrderTrade(v),
-                    FuturesEvents::IndexPriceEvent(v) => FuturesWebsocketEvent::IndexPrice(v),
-                    FuturesEvents::MarkPriceEvent(v) => FuturesWebsocketEvent::MarkPrice(v),
-                    FuturesEvents::VecMarkPriceEvent(v) => FuturesWebsocketEvent::MarkPriceAll(v),
-                    FuturesEvents::TradeEvent(v) => FuturesWebsocketEvent::Trade(v),
-                    FuturesEvents::ContinuousKlineEvent(v) => {
-                        FuturesWebsocketEvent::ContinuousKline(v)
-                    }
-                    FuturesEvents::IndexKlineEvent(v) => FuturesWebsocketEvent::IndexKline(v),
-                    FuturesEvents::LiquidationEvent(v) => FuturesWebsocketEvent::Liquidation(v),
-                    FuturesEvents::KlineEvent(v) => FuturesWebsocketEvent::Kline(v),
-                    FuturesEvents::OrderBook(v) => FuturesWebsocketEvent::OrderBook(v),
-                    FuturesEvents::DepthOrderBookEvent(v) => {
-                        FuturesWebsocketEvent::DepthOrderBook(v)
-                    }
-                    FuturesEvents::AggrTradesEvent(v) => FuturesWebsocketEvent::AggrTrades(v),
-                    FuturesEvents::UserDataStreamExpiredEvent(v) => {
-                        FuturesWebsocketEvent::UserDataStreamExpiredEvent(v)
-                    }
-                };
-                let mut handler_guard = user_handler_clone.lock().await;
-                (handler_guard)(action).await
-            })
-        });
+        let adapter_handler: AdapterHandler<'a> =
+            Box::new(move |events_obj: StreamEvent<FuturesEvents>| {
+                let user_handler_clone = Arc::clone(&shared_user_handler);
+                Box::pin(async move {
+                    let action = events_obj.map(FuturesWebsocketEvent::from);
+                    let mut handler_guard = user_handler_clone.lock().await;
+                    (handler_guard)(action).await
+                })
+            });
 
         FuturesWebSockets {
+            events: None,
             client: AsyncWebsocketClient::new(adapter_handler),
         }
     }
 
-    pub async fn connect(&mut self, market: &FuturesMarket, subscription: &'a str) -> Result<()> {
+    /// Reconnect automatically when the connection drops. Lifecycle changes are
+    /// delivered to the handler as [`FuturesWebsocketEvent::Connection`].
+    pub fn set_reconnect_policy<P: Into<Option<ReconnectPolicy>>>(&mut self, policy: P) {
+        self.client.set_reconnect_policy(policy);
+    }
+
+    /// Connects to one stream: a [`Stream`](crate::streams::Stream), a raw
+    /// stream name or a listen key.
+    pub async fn connect<S: Display>(
+        &mut self, market: &FuturesMarket, subscription: S,
+    ) -> Result<()> {
         self.client
-            .connect(&FuturesWebsocketAPI::Default.params(market, subscription))
+            .connect(&FuturesWebsocketAPI::Default.params(market, &subscription.to_string()))
             .await
     }
 
-    pub async fn connect_with_config(
-        &mut self, market: &FuturesMarket, subscription: &'a str, config: &'a Config,
+    pub(crate) async fn connect_url(&mut self, wss_url: &str) -> Result<()> {
+        self.client.connect(wss_url).await
+    }
+
+    pub async fn connect_with_config<S: Display>(
+        &mut self, market: &FuturesMarket, subscription: S, config: &Config,
     ) -> Result<()> {
-        let wss_url =
-            if config.ws_endpoint.contains("wss://") || config.ws_endpoint.contains("ws://") {
-                // If config.ws_endpoint is already a full URL, use it directly
-                config.ws_endpoint.clone()
-            } else {
-                // Otherwise, assume it's a base path and use FuturesWebsocketAPI::Custom to format it
-                FuturesWebsocketAPI::Custom(config.ws_endpoint.clone()).params(market, subscription)
-            };
+        let subscription = subscription.to_string();
+        let wss_url = if config.ws_endpoint.contains("wss://")
+            || config.ws_endpoint.contains("ws://")
+        {
+            // If config.ws_endpoint is already a full URL, use it directly
+            config.ws_endpoint.clone()
+        } else {
+            // Otherwise, assume it's a base path and use FuturesWebsocketAPI::Custom to format it
+            FuturesWebsocketAPI::Custom(config.ws_endpoint.clone()).params(market, &subscription)
+        };
         self.client.connect(&wss_url).await
     }
 
-    pub async fn connect_multiple_streams(
-        &mut self, market: &FuturesMarket, endpoints: &[String],
+    pub async fn connect_multiple_streams<S: Display>(
+        &mut self, market: &FuturesMarket, endpoints: &[S],
     ) -> Result<()> {
         self.client
-            .connect(&FuturesWebsocketAPI::MultiStream.params(market, &endpoints.join("/")))
+            .connect(&FuturesWebsocketAPI::MultiStream.params(market, &join_streams(endpoints)))
             .await
     }
 
@@ -199,7 +376,70 @@ impl<'a> FuturesWebSockets<'a> {
         self.client.disconnect().await
     }
 
-    pub async fn event_loop(&mut self, running: Arc<AtomicBool>) -> Result<()> {
+    pub async fn event_loop(&self, running: Arc<AtomicBool>) -> Result<()> {
         self.client.event_loop(running).await
     }
+
+    /// Adds streams to the open connection. Returns once the server has
+    /// acknowledged them, which requires `event_loop` to be running
+    /// concurrently, e.g. `tokio::join!(ws.event_loop(running), ws.subscribe(&streams))`.
+    pub async fn subscribe<S: Display>(&self, streams: &[S]) -> Result<()> {
+        self.client.subscribe(streams).await
+    }
+
+    pub async fn unsubscribe<S: Display>(&self, streams: &[S]) -> Result<()> {
+        self.client.unsubscribe(streams).await
+    }
+
+    pub async fn list_subscriptions(&self) -> Result<Vec<String>> {
+        self.client.list_subscriptions().await
+    }
+}
+
+impl FuturesWebSockets<'static> {
+    /// Builds a socket that queues its events, with their stream name and
+    /// receive time, for the returned receiver instead of calling a handler.
+    /// See [`event_channel`](crate::event_channel).
+    pub fn channel(
+        config: ChannelConfig,
+    ) -> (
+        FuturesWebSockets<'static>,
+        EventReceiver<Result<StreamEvent<FuturesWebsocketEvent>>>,
+    ) {
+        let (sender, receiver) = event_channel(config);
+        let handler_sender = sender.clone();
+        let mut web_socket = FuturesWebSockets::new_with_stream_events(
+            move |event: StreamEvent<FuturesWebsocketEvent>| {
+                let sender = handler_sender.clone();
+                Box::pin(async move { sender.send(Ok(event)).await })
+            },
+        );
+        web_socket.events = Some(sender);
+        (web_socket, receiver)
+    }
+
+    /// Runs [`event_loop`](Self::event_loop) on a new task until the
+    /// connection fails for good or, for a socket built with
+    /// [`channel`](Self::channel), the receiver is dropped. The returned
+    /// handle keeps `subscribe`, `unsubscribe` and `list_subscriptions`
+    /// usable while the loop runs. A channel socket sends the error that
+    /// ended the loop to the receiver instead of returning it.
+    pub fn spawn(self) -> SpawnedSocket<FuturesWebSockets<'static>> {
+        SpawnedSocket::spawn(self, |socket| async move {
+            let result = socket.event_loop(Arc::new(AtomicBool::new(true))).await;
+            match (&socket.events, result) {
+                (Some(events), result) => {
+                    if let Err(e) = result {
+                        if !events.is_closed() {
+                            let _ = events.send(Err(e)).await;
+                        }
+                    }
+                    // The handle keeps the socket, and with it the sender, alive.
+                    events.close();
+                    Ok(())
+                }
+                (None, result) => result,
+            }
+        })
+    }
 }
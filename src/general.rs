@@ -27,9 +27,15 @@ impl General {
 
     // Obtain exchange information
     // - Current exchange trading rules and symbol information
+    // - Also applies the reported rate limits to the client's rate limiter
     pub async fn exchange_info(&self) -> Result<ExchangeInformation> {
         // async added
-        self.client.get(API::Spot(Spot::ExchangeInfo), None).await // .await added
+        let info: ExchangeInformation =
+            self.client.get(API::Spot(Spot::ExchangeInfo), None).await?; // .await added
+        self.client
+            .rate_limiter()
+            .set_exchange_limits(&info.rate_limits);
+        Ok(info)
     }
 
     // Get Symbol information
@@ -0,0 +1,618 @@
+//! Kline backfill into a local cache.
+//!
+//! A [`KlineDownloader`] fetches the candles of a symbol and interval over any
+//! range, [`KlineSource::max_limit`] candles per request, and keeps them in a
+//! [`KlineCache`] on disk. A later [`sync`](KlineDownloader::sync) only asks
+//! for what the cache lacks: the candles after the last stored `close_time`,
+//! and any hole left by an interrupted download. Candles still open are not
+//! stored.
+//!
+//! Some holes are the exchange's: no candle exists for a maintenance window
+//! or before the listing. When a download covered a hole and returned nothing
+//! for it, the hole is recorded as [`GapKind::Exchange`] and not asked for
+//! again. Holes nobody has downloaded yet are [`GapKind::Missing`].
+//!
+//! The cache is one CSV file per market, symbol and interval,
+//! `<dir>/<market>/<SYMBOL>/<interval>.csv`, with the columns of
+//! [`KlineSummary`] and a header line. Recorded exchange gaps are kept next to
+//! it in `<interval>.gaps.csv`. Monthly (`1M`) candles open on the first of
+//! each calendar month (UTC) and are stepped through month by month.
+//!
+//!```no_run
+//! use binance_rs_plus::api::Binance;
+//! use binance_rs_plus::kline_cache::{KlineCache, KlineDownloader};
+//! use binance_rs_plus::market::Market;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     let market: Market = Binance::new(None, None);
+//!     let downloader = KlineDownloader::new(market, KlineCache::new("klines"));
+//!
+//!     let report = downloader.sync("BTCUSDT", "1h", 1_672_531_200_000, None).await?;
+//!     println!("downloaded {}, gaps {:?}", report.downloaded, report.gaps);
+//!
+//!     let klines = downloader.cache().load("spot", "BTCUSDT", "1h").await?;
+//!     println!("{} candles cached", klines.len());
+//!     Ok(())
+//! }
+//! ```
+
+use crate::errors::{Error, Result};
+use crate::futures::market::FuturesMarket;
+use crate::market::Market;
+use crate::model::{KlineSummaries, KlineSummary};
+use crate::pagination;
+use serde_json::Value;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+const HEADER: &str = "open_time,open,high,low,close,volume,close_time,quote_asset_volume,\
+                      number_of_trades,taker_buy_base_asset_volume,taker_buy_quote_asset_volume";
+
+/// A kline endpoint the downloader can read from.
+pub trait KlineSource {
+    /// Name of the market in the cache layout, e.g. `"spot"`.
+    fn market(&self) -> &str;
+
+    /// Most candles one request may return.
+    fn max_limit(&self) -> u16;
+
+    /// Up to `limit` candles opening from `start_time` to `end_time`.
+    fn klines(
+        &self, symbol: &str, interval: &str, start_time: u64, end_time: u64, limit: u16,
+    ) -> impl Future<Output = Result<Vec<KlineSummary>>> + Send;
+}
+
+impl KlineSource for Market {
+    fn market(&self) -> &str {
+        "spot"
+    }
+
+    fn max_limit(&self) -> u16 {
+        1000
+    }
+
+    async fn klines(
+        &self, symbol: &str, interval: &str, start_time: u64, end_time: u64, limit: u16,
+    ) -> Result<Vec<KlineSummary>> {
+        let KlineSummaries::AllKlineSummaries(klines) = self
+            .get_klines(symbol, interval, limit, start_time, end_time)
+            .await?;
+        Ok(klines)
+    }
+}
+
+impl KlineSource for FuturesMarket {
+    fn market(&self) -> &str {
+        "futures"
+    }
+
+    fn max_limit(&self) -> u16 {
+        1500
+    }
+
+    async fn klines(
+        &self, symbol: &str, interval: &str, start_time: u64, end_time: u64, limit: u16,
+    ) -> Result<Vec<KlineSummary>> {
+        let KlineSummaries::AllKlineSummaries(klines) = self
+            .get_klines(symbol, interval, limit, start_time, end_time)
+            .await?;
+        Ok(klines)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GapKind {
+    /// The exchange has no candles here.
+    Exchange,
+    /// Not downloaded yet.
+    Missing,
+}
+
+/// Open times from `start` to `end` (inclusive) with no candle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gap {
+    pub start: u64,
+    pub end: u64,
+    pub kind: GapKind,
+}
+
+/// What a [`sync`](KlineDownloader::sync) did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncReport {
+    /// New candles written to the cache.
+    pub downloaded: usize,
+    /// Holes left in the synced range, oldest first.
+    pub gaps: Vec<Gap>,
+}
+
+/// Length of a kline interval such as `"1m"` or `"4h"`, in milliseconds.
+/// Monthly (`"1M"`) candles have no fixed length and fail here.
+pub fn interval_millis(interval: &str) -> Result<u64> {
+    match KlineStep::parse(interval)? {
+        KlineStep::Fixed(step) => Ok(step),
+        KlineStep::Monthly => Err(Error::Custom(format!(
+            "kline interval {} has no fixed length",
+            interval
+        ))),
+    }
+}
+
+// Distance between the open times of consecutive candles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KlineStep {
+    Fixed(u64),
+    Monthly,
+}
+
+impl KlineStep {
+    fn parse(interval: &str) -> Result<Self> {
+        let unsupported = || Error::Custom(format!("unsupported kline interval {}", interval));
+        let (index, unit) = interval.char_indices().last().ok_or_else(unsupported)?;
+        let count = interval[..index]
+            .parse::<u64>()
+            .ok()
+            .filter(|count| *count > 0)
+            .ok_or_else(unsupported)?;
+        let unit = match unit {
+            's' => 1000,
+            'm' => 60 * 1000,
+            'h' => pagination::HOUR,
+            'd' => pagination::DAY,
+            'w' => 7 * pagination::DAY,
+            'M' if count == 1 => return Ok(Self::Monthly),
+            _ => return Err(unsupported()),
+        };
+        Ok(Self::Fixed(count * unit))
+    }
+
+    // Open time of the candle after the one opening at `open`.
+    fn next(self, open: u64) -> u64 {
+        match self {
+            Self::Fixed(step) => open.saturating_add(step),
+            Self::Monthly => {
+                let (year, month) = year_month(open);
+                month_start(year, month + 1)
+            }
+        }
+    }
+
+    // Open time of the candle before the one opening at `open`.
+    fn prev(self, open: u64) -> u64 {
+        match self {
+            Self::Fixed(step) => open.saturating_sub(step),
+            Self::Monthly => {
+                let (year, month) = year_month(open);
+                month_start(year, month - 1)
+            }
+        }
+    }
+
+    // Latest open time of a candle that has closed by `now`.
+    fn last_closed(self, now: u64) -> u64 {
+        match self {
+            Self::Fixed(step) => now.saturating_sub(step),
+            Self::Monthly => {
+                let (year, month) = year_month(now);
+                self.prev(month_start(year, month))
+            }
+        }
+    }
+}
+
+// UTC year and month (1-12) of a timestamp in milliseconds.
+fn year_month(millis: u64) -> (i64, i64) {
+    // Civil-from-days by Howard Hinnant, with March as the first month.
+    let days = (millis / pagination::DAY) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month)
+}
+
+// Milliseconds at 00:00 UTC on the first of `month`, which may run past
+// 1-12 and then carries into the year.
+fn month_start(year: i64, month: i64) -> u64 {
+    let year = year + (month - 1).div_euclid(12);
+    let month = (month - 1).rem_euclid(12) + 1;
+    // Days-from-civil, the inverse of `year_month`.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_index = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_index + 2) / 5;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+    (days.max(0) as u64) * pagination::DAY
+}
+
+/// Candles stored on disk under one directory.
+#[derive(Debug, Clone)]
+pub struct KlineCache {
+    dir: PathBuf,
+}
+
+impl KlineCache {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The file holding the candles of `symbol` at `interval`.
+    pub fn path(&self, market: &str, symbol: &str, interval: &str) -> PathBuf {
+        self.dir
+            .join(market)
+            .join(symbol)
+            .join(format!("{}.csv", interval))
+    }
+
+    fn gaps_path(&self, market: &str, symbol: &str, interval: &str) -> PathBuf {
+        self.dir
+            .join(market)
+            .join(symbol)
+            .join(format!("{}.gaps.csv", interval))
+    }
+
+    /// Every cached candle, oldest first; empty when nothing is cached.
+    pub async fn load(
+        &self, market: &str, symbol: &str, interval: &str,
+    ) -> Result<Vec<KlineSummary>> {
+        let path = self.path(market, symbol, interval);
+        let Some(content) = read_optional(&path).await? else {
+            return Ok(Vec::new());
+        };
+        content
+            .lines()
+            .enumerate()
+            .skip(1)
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| {
+                parse_row(line).ok_or_else(|| {
+                    Error::Custom(format!("{}:{}: invalid kline row", path.display(), i + 1))
+                })
+            })
+            .collect()
+    }
+
+    /// Cached candles opening from `start_time` to `end_time`.
+    pub async fn load_range(
+        &self, market: &str, symbol: &str, interval: &str, start_time: u64, end_time: u64,
+    ) -> Result<Vec<KlineSummary>> {
+        let mut klines = self.load(market, symbol, interval).await?;
+        klines.retain(|k| in_range(k, start_time, end_time));
+        Ok(klines)
+    }
+
+    /// `close_time` of the newest cached candle.
+    pub async fn last_close_time(
+        &self, market: &str, symbol: &str, interval: &str,
+    ) -> Result<Option<u64>> {
+        let klines = self.load(market, symbol, interval).await?;
+        Ok(klines.last().map(|k| k.close_time as u64))
+    }
+
+    /// Holes in the cached candles from `start_time` to `end_time`.
+    pub async fn gaps(
+        &self, market: &str, symbol: &str, interval: &str, start_time: u64, end_time: u64,
+    ) -> Result<Vec<Gap>> {
+        let step = KlineStep::parse(interval)?;
+        let klines = self.load(market, symbol, interval).await?;
+        let known = self.exchange_gaps(market, symbol, interval).await?;
+        Ok(find_gaps(&klines, step, start_time, end_time, &known))
+    }
+
+    /// Add `klines` to the cache. Candles already cached are kept as they
+    /// are.
+    pub async fn store(
+        &self, market: &str, symbol: &str, interval: &str, klines: &[KlineSummary],
+    ) -> Result<usize> {
+        let path = self.path(market, symbol, interval);
+        let mut cached = self.load(market, symbol, interval).await?;
+        let last_open = cached.last().map(|k| k.open_time);
+
+        let mut new: Vec<KlineSummary> = klines
+            .iter()
+            .filter(|k| {
+                cached
+                    .binary_search_by_key(&k.open_time, |c| c.open_time)
+                    .is_err()
+            })
+            .cloned()
+            .collect();
+        new.sort_by_key(|k| k.open_time);
+        new.dedup_by_key(|k| k.open_time);
+        if new.is_empty() {
+            return Ok(0);
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+
+        let appends = last_open.is_none_or(|last| new[0].open_time > last);
+        if appends {
+            let mut file = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .await?;
+            let mut content = String::new();
+            if last_open.is_none() {
+                content.push_str(HEADER);
+                content.push('\n');
+            }
+            for kline in &new {
+                push_row(&mut content, kline);
+            }
+            file.write_all(content.as_bytes()).await?;
+            file.flush().await?;
+        } else {
+            let count = new.len();
+            cached.append(&mut new);
+            cached.sort_by_key(|k| k.open_time);
+            let mut content = format!("{}\n", HEADER);
+            for kline in &cached {
+                push_row(&mut content, kline);
+            }
+            write_replacing(&path, &content).await?;
+            return Ok(count);
+        }
+        Ok(new.len())
+    }
+
+    async fn exchange_gaps(
+        &self, market: &str, symbol: &str, interval: &str,
+    ) -> Result<Vec<(u64, u64)>> {
+        let path = self.gaps_path(market, symbol, interval);
+        let Some(content) = read_optional(&path).await? else {
+            return Ok(Vec::new());
+        };
+        content
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                line.split_once(',')
+                    .and_then(|(start, end)| Some((start.parse().ok()?, end.parse().ok()?)))
+                    .ok_or_else(|| {
+                        Error::Custom(format!("{}: invalid gap row {}", path.display(), line))
+                    })
+            })
+            .collect()
+    }
+
+    async fn record_exchange_gaps(
+        &self, market: &str, symbol: &str, interval: &str, gaps: &[Gap],
+    ) -> Result<()> {
+        if gaps.is_empty() {
+            return Ok(());
+        }
+        let mut known = self.exchange_gaps(market, symbol, interval).await?;
+        known.extend(gaps.iter().map(|gap| (gap.start, gap.end)));
+        known.sort_unstable();
+        known.dedup();
+        let content: String = known
+            .iter()
+            .map(|(start, end)| format!("{},{}\n", start, end))
+            .collect();
+        write_replacing(&self.gaps_path(market, symbol, interval), &content).await
+    }
+}
+
+/// Downloads klines from a [`KlineSource`] and keeps them in a
+/// [`KlineCache`].
+pub struct KlineDownloader<S> {
+    source: S,
+    cache: KlineCache,
+}
+
+impl<S: KlineSource> KlineDownloader<S> {
+    pub fn new(source: S, cache: KlineCache) -> Self {
+        Self { source, cache }
+    }
+
+    pub fn cache(&self) -> &KlineCache {
+        &self.cache
+    }
+
+    /// Candles opening from `start_time` to `end_time`, straight from the
+    /// exchange in as many requests as needed. The cache is not used.
+    pub async fn download(
+        &self, symbol: &str, interval: &str, start_time: u64, end_time: u64,
+    ) -> Result<Vec<KlineSummary>> {
+        let limit = self.source.max_limit();
+        let mut klines = Vec::new();
+        let mut cursor = start_time;
+        while cursor <= end_time {
+            let batch = self
+                .source
+                .klines(symbol, interval, cursor, end_time, limit)
+                .await?;
+            let Some(last) = batch.last() else {
+                break;
+            };
+            let next = last.close_time as u64 + 1;
+            let full = batch.len() >= usize::from(limit);
+            klines.extend(batch.into_iter().filter(|k| in_range(k, cursor, end_time)));
+            if !full || next <= cursor {
+                break;
+            }
+            cursor = next;
+        }
+        Ok(klines)
+    }
+
+    /// Bring the cache up to date from `start_time` to `end_time` (default
+    /// now), downloading only the ranges it lacks. Candles still open are
+    /// left for a later sync.
+    pub async fn sync<E>(
+        &self, symbol: &str, interval: &str, start_time: u64, end_time: E,
+    ) -> Result<SyncReport>
+    where
+        E: Into<Option<u64>>,
+    {
+        let market = self.source.market();
+        let step = KlineStep::parse(interval)?;
+        let now = pagination::end_or_now(None);
+        let end_time = end_time.into().unwrap_or(now).min(step.last_closed(now));
+
+        let cached = self.cache.load(market, symbol, interval).await?;
+        let known = self.cache.exchange_gaps(market, symbol, interval).await?;
+        let missing: Vec<Gap> = find_gaps(&cached, step, start_time, end_time, &known)
+            .into_iter()
+            .filter(|gap| gap.kind == GapKind::Missing)
+            .collect();
+
+        let mut fetched = Vec::new();
+        for gap in &missing {
+            let klines = self.download(symbol, interval, gap.start, gap.end).await?;
+            fetched.extend(klines.into_iter().filter(|k| (k.close_time as u64) < now));
+        }
+        let downloaded = self.cache.store(market, symbol, interval, &fetched).await?;
+
+        // A hole inside a range just downloaded, with a candle after it, is
+        // the exchange's. Holes at the end may still fill in.
+        let cached = self.cache.load(market, symbol, interval).await?;
+        let exchange: Vec<Gap> = find_gaps(&cached, step, start_time, end_time, &known)
+            .into_iter()
+            .filter(|gap| {
+                gap.kind == GapKind::Missing
+                    && missing
+                        .iter()
+                        .any(|m| m.start <= gap.start && gap.end <= m.end)
+                    && cached.iter().any(|k| k.open_time as u64 == gap.end + 1)
+            })
+            .collect();
+        self.cache
+            .record_exchange_gaps(market, symbol, interval, &exchange)
+            .await?;
+
+        let gaps = self
+            .cache
+            .gaps(market, symbol, interval, start_time, end_time)
+            .await?;
+        Ok(SyncReport { downloaded, gaps })
+    }
+
+    /// Candles opening from `start_time` to `end_time`, synced first.
+    pub async fn klines(
+        &self, symbol: &str, interval: &str, start_time: u64, end_time: u64,
+    ) -> Result<Vec<KlineSummary>> {
+        self.sync(symbol, interval, start_time, end_time).await?;
+        self.cache
+            .load_range(self.source.market(), symbol, interval, start_time, end_time)
+            .await
+    }
+}
+
+// Holes among `klines` (sorted by open time) from `start` to `end`, labelled
+// with the exchange gaps recorded so far. The range before the first candle
+// is a hole only if a whole candle fits in it.
+fn find_gaps(
+    klines: &[KlineSummary], step: KlineStep, start: u64, end: u64, known: &[(u64, u64)],
+) -> Vec<Gap> {
+    let mut holes = Vec::new();
+    let opens: Vec<u64> = klines
+        .iter()
+        .map(|k| k.open_time as u64)
+        .filter(|&open| open >= start && open <= end)
+        .collect();
+    match (opens.first(), opens.last()) {
+        (Some(&first), Some(&last)) => {
+            if step.prev(first) >= start {
+                holes.push((start, first - 1));
+            }
+            for pair in opens.windows(2) {
+                if step.next(pair[0]) < pair[1] {
+                    holes.push((step.next(pair[0]), pair[1] - 1));
+                }
+            }
+            if step.next(last) <= end {
+                holes.push((step.next(last), end));
+            }
+        }
+        _ if start <= end => holes.push((start, end)),
+        _ => {}
+    }
+    holes
+        .into_iter()
+        .map(|(start, end)| {
+            let recorded = known.iter().any(|&(s, e)| s <= start && end <= e);
+            Gap {
+                start,
+                end,
+                kind: if recorded {
+                    GapKind::Exchange
+                } else {
+                    GapKind::Missing
+                },
+            }
+        })
+        .collect()
+}
+
+fn in_range(kline: &KlineSummary, start: u64, end: u64) -> bool {
+    let open = kline.open_time as u64;
+    open >= start && open <= end
+}
+
+fn push_row(content: &mut String, k: &KlineSummary) {
+    content.push_str(&format!(
+        "{},{},{},{},{},{},{},{},{},{},{}\n",
+        k.open_time,
+        k.open,
+        k.high,
+        k.low,
+        k.close,
+        k.volume,
+        k.close_time,
+        k.quote_asset_volume,
+        k.number_of_trades,
+        k.taker_buy_base_asset_volume,
+        k.taker_buy_quote_asset_volume
+    ));
+}
+
+// Read back through the same conversion as the REST response.
+fn parse_row(line: &str) -> Option<KlineSummary> {
+    let row = line
+        .split(',')
+        .enumerate()
+        .map(|(i, field)| match i {
+            0 | 6 | 8 => field.parse::<i64>().ok().map(Value::from),
+            _ => Some(Value::String(field.to_string())),
+        })
+        .collect::<Option<Vec<Value>>>()?;
+    if row.len() != 11 {
+        return None;
+    }
+    KlineSummary::try_from(&row).ok()
+}
+
+async fn read_optional(path: &Path) -> Result<Option<String>> {
+    match fs::read_to_string(path).await {
+        Ok(content) => Ok(Some(content)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+// Write next to `path` and rename over it, so a crash never leaves half a
+// file behind.
+async fn write_replacing(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    let tmp = path.with_extension("csv.tmp");
+    fs::write(&tmp, content).await?;
+    fs::rename(&tmp, path).await?;
+    Ok(())
+}
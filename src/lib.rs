@@ -25,10 +25,23 @@ pub mod model;
 pub mod account;
 pub mod api;
 pub mod config;
+pub mod event_channel;
 pub mod general;
+pub mod kline_cache;
+pub mod margin;
 pub mod market;
+pub mod orderbook;
+pub mod pagination;
+pub mod rate_limit;
 pub mod savings;
+pub mod signer;
+pub mod streams;
+pub mod symbol_rules;
+pub mod time_sync;
+pub mod user_data_stream;
 pub mod userstream;
 pub mod websockets;
 
+pub mod coin_futures;
 pub mod futures;
+pub mod options;
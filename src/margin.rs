@@ -0,0 +1,515 @@
+/*!
+Cross and isolated margin trading on `/sapi/v1/margin`.
+
+Orders are spot orders ([`SpotOrderRequest`]) wrapped in a
+[`MarginOrderRequest`], which adds the isolated flag and the
+[`SideEffectType`] that borrows or repays around the order. Calls that take
+an `isolated_symbol` act on the isolated account of that symbol, or on the
+cross margin account with `None`.
+
+```no_run
+use binance_rs_plus::account::{OrderSide, OrderType, SpotOrderRequest, TimeInForce};
+use binance_rs_plus::api::Binance;
+use binance_rs_plus::margin::*;
+use binance_rs_plus::model::Number;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let margin: Margin = Binance::new(Some("api_key".into()), Some("secret_key".into()));
+
+    let order = SpotOrderRequest::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+        .set_quantity("0.01".parse::<Number>()?)
+        .set_price(60000.into())
+        .set_time_in_force(TimeInForce::GTC);
+    let order = MarginOrderRequest::new(order).set_side_effect_type(SideEffectType::MarginBuy);
+    let transaction = margin.place(order).await?;
+    println!("borrowed {:?}", transaction.margin_buy_borrow_amount);
+    Ok(())
+}
+```
+*/
+
+use crate::util::build_signed_request;
+use crate::model::{
+    InterestHistory, IsolatedMarginAccountInfo, MarginAccountDetails, MarginOrder, MarginOrderList,
+    MarginTransaction, MaxBorrowable, MaxTransferable, Number, OrderCanceled, Success,
+    TransactionId, UserDataStream,
+};
+use crate::account::{OcoOrderRequest, SpotOrderRequest};
+use crate::client::Client;
+use crate::errors::Result;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use crate::api::API;
+use crate::api::Sapi;
+
+#[derive(Clone)]
+pub struct Margin {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+/// What a margin order does about loans.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum SideEffectType {
+    NoSideEffect,
+    /// Borrow what the order needs beyond the free balance.
+    MarginBuy,
+    /// Repay debt with what the order returns.
+    AutoRepay,
+    /// Both borrow for the order and repay with its proceeds.
+    AutoBorrowRepay,
+}
+
+impl Display for SideEffectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSideEffect => write!(f, "NO_SIDE_EFFECT"),
+            Self::MarginBuy => write!(f, "MARGIN_BUY"),
+            Self::AutoRepay => write!(f, "AUTO_REPAY"),
+            Self::AutoBorrowRepay => write!(f, "AUTO_BORROW_REPAY"),
+        }
+    }
+}
+
+/// A request to `/sapi/v1/margin/order`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarginOrderRequest {
+    pub order: SpotOrderRequest,
+    pub is_isolated: bool,
+    pub side_effect_type: Option<SideEffectType>,
+    /// With `AUTO_BORROW_REPAY`, repay the borrowed amount if the order is
+    /// cancelled. Defaults to true on the exchange.
+    pub auto_repay_at_cancel: Option<bool>,
+}
+
+impl MarginOrderRequest {
+    pub fn new(order: SpotOrderRequest) -> Self {
+        Self {
+            order,
+            is_isolated: false,
+            side_effect_type: None,
+            auto_repay_at_cancel: None,
+        }
+    }
+
+    pub fn set_isolated(mut self, is_isolated: bool) -> Self {
+        self.is_isolated = is_isolated;
+        self
+    }
+
+    pub fn set_side_effect_type(mut self, side_effect_type: SideEffectType) -> Self {
+        self.side_effect_type = Some(side_effect_type);
+        self
+    }
+
+    pub fn set_auto_repay_at_cancel(mut self, auto_repay_at_cancel: bool) -> Self {
+        self.auto_repay_at_cancel = Some(auto_repay_at_cancel);
+        self
+    }
+
+    /// Request parameters, without `recvWindow`, `timestamp` and `signature`.
+    pub fn to_params(&self) -> BTreeMap<String, String> {
+        let mut parameters = self.order.to_params();
+        parameters.insert("isIsolated".into(), isolated_param(self.is_isolated));
+        if let Some(side_effect_type) = self.side_effect_type {
+            parameters.insert("sideEffectType".into(), side_effect_type.to_string());
+        }
+        if let Some(auto_repay) = self.auto_repay_at_cancel {
+            parameters.insert("autoRepayAtCancel".into(), auto_repay.to_string());
+        }
+        parameters
+    }
+}
+
+impl From<SpotOrderRequest> for MarginOrderRequest {
+    fn from(order: SpotOrderRequest) -> Self {
+        MarginOrderRequest::new(order)
+    }
+}
+
+/// A request to `/sapi/v1/margin/order/oco`: the spot OCO parameters of
+/// [`OcoOrderRequest`] plus the margin ones.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarginOcoRequest {
+    pub order: OcoOrderRequest,
+    pub is_isolated: bool,
+    pub side_effect_type: Option<SideEffectType>,
+}
+
+impl MarginOcoRequest {
+    pub fn new(order: OcoOrderRequest) -> Self {
+        Self {
+            order,
+            is_isolated: false,
+            side_effect_type: None,
+        }
+    }
+
+    pub fn set_isolated(mut self, is_isolated: bool) -> Self {
+        self.is_isolated = is_isolated;
+        self
+    }
+
+    pub fn set_side_effect_type(mut self, side_effect_type: SideEffectType) -> Self {
+        self.side_effect_type = Some(side_effect_type);
+        self
+    }
+
+    /// Request parameters, without `recvWindow`, `timestamp` and `signature`.
+    pub fn to_params(&self) -> BTreeMap<String, String> {
+        let mut parameters = self.order.to_params();
+        parameters.insert("isIsolated".into(), isolated_param(self.is_isolated));
+        if let Some(side_effect_type) = self.side_effect_type {
+            parameters.insert("sideEffectType".into(), side_effect_type.to_string());
+        }
+        parameters
+    }
+}
+
+impl From<OcoOrderRequest> for MarginOcoRequest {
+    fn from(order: OcoOrderRequest) -> Self {
+        MarginOcoRequest::new(order)
+    }
+}
+
+/// Filters for [`Margin::interest_history`]. Without a time range the last
+/// 7 days are returned; a range can span at most 30 days.
+#[derive(Debug, Clone, Default)]
+pub struct InterestHistoryRequest {
+    pub asset: Option<String>,
+    pub isolated_symbol: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Page number, from 1.
+    pub current: Option<u32>,
+    /// Page size, max 100.
+    pub size: Option<u32>,
+}
+
+impl Margin {
+    /// Borrow `amount` of `asset` into the cross margin account, or into the
+    /// isolated account of `isolated_symbol`.
+    pub async fn borrow<S>(
+        &self, asset: S, amount: Number, isolated_symbol: Option<String>,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+    {
+        self.borrow_repay("BORROW", asset.into(), amount, isolated_symbol)
+            .await
+    }
+
+    /// Repay `amount` of `asset`, interest first.
+    pub async fn repay<S>(
+        &self, asset: S, amount: Number, isolated_symbol: Option<String>,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+    {
+        self.borrow_repay("REPAY", asset.into(), amount, isolated_symbol)
+            .await
+    }
+
+    async fn borrow_repay(
+        &self, operation: &str, asset: String, amount: Number, isolated_symbol: Option<String>,
+    ) -> Result<TransactionId> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("asset".into(), asset);
+        parameters.insert("amount".into(), amount.to_string());
+        parameters.insert("type".into(), operation.into());
+        insert_isolated_symbol(&mut parameters, isolated_symbol);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::MarginBorrowRepay), request)
+            .await
+    }
+
+    /// Place a margin order of any type.
+    ///
+    /// The wrapped spot order is checked with [`SpotOrderRequest::validate`]
+    /// first.
+    pub async fn place<O>(&self, order: O) -> Result<MarginTransaction>
+    where
+        O: Into<MarginOrderRequest>,
+    {
+        let order = order.into();
+        order.order.validate()?;
+        let request = build_signed_request(order.to_params(), self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::MarginOrder), request)
+            .await
+    }
+
+    pub async fn order_status<S>(
+        &self, symbol: S, order_id: u64, is_isolated: bool,
+    ) -> Result<MarginOrder>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+        parameters.insert("isIsolated".into(), isolated_param(is_isolated));
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginOrder), Some(request))
+            .await
+    }
+
+    // Open orders of ONE symbol, or of all cross margin symbols with `None`
+    pub async fn get_open_orders(
+        &self, symbol: Option<String>, is_isolated: bool,
+    ) -> Result<Vec<MarginOrder>> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+        parameters.insert("isIsolated".into(), isolated_param(is_isolated));
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginOpenOrders), Some(request))
+            .await
+    }
+
+    pub async fn cancel_order<S>(
+        &self, symbol: S, order_id: u64, is_isolated: bool,
+    ) -> Result<OrderCanceled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+        parameters.insert("isIsolated".into(), isolated_param(is_isolated));
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Savings(Sapi::MarginOrder), Some(request))
+            .await
+    }
+
+    pub async fn cancel_order_with_client_id<S>(
+        &self, symbol: S, client_order_id: String, is_isolated: bool,
+    ) -> Result<OrderCanceled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("origClientOrderId".into(), client_order_id);
+        parameters.insert("isIsolated".into(), isolated_param(is_isolated));
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Savings(Sapi::MarginOrder), Some(request))
+            .await
+    }
+
+    // Cancels the open orders and order lists of a symbol
+    pub async fn cancel_all_open_orders<S>(
+        &self, symbol: S, is_isolated: bool,
+    ) -> Result<Vec<OrderCanceled>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("isIsolated".into(), isolated_param(is_isolated));
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Savings(Sapi::MarginOpenOrders), Some(request))
+            .await
+    }
+
+    /// Place a margin OCO order. The wrapped spot OCO is checked with
+    /// [`OcoOrderRequest::validate`] first.
+    pub async fn place_oco<O>(&self, order: O) -> Result<MarginOrderList>
+    where
+        O: Into<MarginOcoRequest>,
+    {
+        let order = order.into();
+        order.order.validate()?;
+        let request = build_signed_request(order.to_params(), self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::MarginOco), request)
+            .await
+    }
+
+    pub async fn cancel_order_list<S>(
+        &self, symbol: S, order_list_id: u64, is_isolated: bool,
+    ) -> Result<MarginOrderList>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+        parameters.insert("isIsolated".into(), isolated_param(is_isolated));
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Savings(Sapi::MarginOrderList), Some(request))
+            .await
+    }
+
+    /// Cross margin balances, loans and margin level.
+    pub async fn account_details(&self) -> Result<MarginAccountDetails> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginAccount), Some(request))
+            .await
+    }
+
+    /// Isolated margin accounts of up to 5 `symbols`, or of all of them
+    /// with an empty slice.
+    pub async fn isolated_account_details<S: Display>(
+        &self, symbols: &[S],
+    ) -> Result<IsolatedMarginAccountInfo> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if !symbols.is_empty() {
+            let symbols: Vec<String> = symbols.iter().map(|s| s.to_string()).collect();
+            parameters.insert("symbols".into(), symbols.join(","));
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::IsolatedMarginAccount), Some(request))
+            .await
+    }
+
+    pub async fn max_borrowable<S>(
+        &self, asset: S, isolated_symbol: Option<String>,
+    ) -> Result<MaxBorrowable>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("asset".into(), asset.into());
+        if let Some(symbol) = isolated_symbol {
+            parameters.insert("isolatedSymbol".into(), symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginMaxBorrowable), Some(request))
+            .await
+    }
+
+    pub async fn max_transferable<S>(
+        &self, asset: S, isolated_symbol: Option<String>,
+    ) -> Result<MaxTransferable>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("asset".into(), asset.into());
+        if let Some(symbol) = isolated_symbol {
+            parameters.insert("isolatedSymbol".into(), symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginMaxTransferable), Some(request))
+            .await
+    }
+
+    pub async fn interest_history(
+        &self, history_request: InterestHistoryRequest,
+    ) -> Result<InterestHistory> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(asset) = history_request.asset {
+            parameters.insert("asset".into(), asset);
+        }
+        if let Some(symbol) = history_request.isolated_symbol {
+            parameters.insert("isolatedSymbol".into(), symbol);
+        }
+        if let Some(start_time) = history_request.start_time {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = history_request.end_time {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(current) = history_request.current {
+            parameters.insert("current".into(), current.to_string());
+        }
+        if let Some(size) = history_request.size {
+            parameters.insert("size".into(), size.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginInterestHistory), Some(request))
+            .await
+    }
+
+    // Cross margin user stream. The events are the spot user data events.
+    pub async fn start_user_stream(&self) -> Result<UserDataStream> {
+        self.client
+            .post(API::Savings(Sapi::MarginUserDataStream))
+            .await
+    }
+
+    pub async fn keep_alive_user_stream(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .put(API::Savings(Sapi::MarginUserDataStream), listen_key)
+            .await
+    }
+
+    pub async fn close_user_stream(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .delete(API::Savings(Sapi::MarginUserDataStream), listen_key)
+            .await
+    }
+
+    // Isolated margin user stream of one symbol
+    pub async fn start_isolated_user_stream<S: Display>(
+        &self, symbol: S,
+    ) -> Result<UserDataStream> {
+        self.client
+            .post_with_params(
+                API::Savings(Sapi::IsolatedMarginUserDataStream),
+                format!("symbol={}", symbol),
+            )
+            .await
+    }
+
+    pub async fn keep_alive_isolated_user_stream<S: Display>(
+        &self, symbol: S, listen_key: &str,
+    ) -> Result<Success> {
+        self.client
+            .put_with_params(
+                API::Savings(Sapi::IsolatedMarginUserDataStream),
+                format!("listenKey={}&symbol={}", listen_key, symbol),
+            )
+            .await
+    }
+
+    pub async fn close_isolated_user_stream<S: Display>(
+        &self, symbol: S, listen_key: &str,
+    ) -> Result<Success> {
+        self.client
+            .delete_with_params(
+                API::Savings(Sapi::IsolatedMarginUserDataStream),
+                format!("listenKey={}&symbol={}", listen_key, symbol),
+            )
+            .await
+    }
+}
+
+fn isolated_param(is_isolated: bool) -> String {
+    if is_isolated { "TRUE" } else { "FALSE" }.to_string()
+}
+
+fn insert_isolated_symbol(parameters: &mut BTreeMap<String, String>, symbol: Option<String>) {
+    if let Some(symbol) = symbol {
+        parameters.insert("isIsolated".into(), "TRUE".into());
+        parameters.insert("symbol".into(), symbol);
+    } else {
+        parameters.insert("isIsolated".into(), "FALSE".into());
+    }
+}
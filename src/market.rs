@@ -1,9 +1,11 @@
 use crate::util::build_request;
 use crate::model::{
     AggTrade, AveragePrice, BookTickers, KlineSummaries, KlineSummary, OrderBook, PriceStats,
-    Prices, SymbolPrice, Tickers,
+    Prices, SymbolPrice, Tickers, Trade,
 };
 use crate::client::Client;
+use crate::pagination::{self, HOUR, PageLimits};
+use futures_util::Stream;
 use crate::errors::Result; // Error will be brought in by Result if needed via crate::errors::Error
 use std::collections::BTreeMap;
 use serde_json::Value;
@@ -117,6 +119,49 @@ impl Market {
         self.client.get(API::Spot(Spot::Ticker24hr), None).await // .await added
     }
 
+    // Most recent trades of a symbol, up to 'limit' (default 500, max 1000)
+    pub async fn get_recent_trades<S1, S2>(&self, symbol: S1, limit: S2) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+
+        let request = build_request(parameters);
+        self.client
+            .get(API::Spot(Spot::Trades), Some(request))
+            .await
+    }
+
+    /// Older trades of a symbol, starting at trade id `from_id`, or the most
+    /// recent ones without it.
+    pub async fn get_historical_trades<S1, S2, S3>(
+        &self, symbol: S1, from_id: S2, limit: S3,
+    ) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(fi) = from_id.into() {
+            parameters.insert("fromId".into(), format!("{}", fi));
+        }
+
+        let request = build_request(parameters);
+        self.client
+            .get(API::Spot(Spot::HistoricalTrades), Some(request))
+            .await
+    }
+
     /// Get aggregated historical trades.
     ///
     /// If you provide start_time, you also need to provide end_time.
@@ -162,6 +207,40 @@ impl Market {
             .await // .await added
     }
 
+    /// Stream every aggregated trade from `start_time` to `end_time`
+    /// (default now), one hour window and 1000 trades per request.
+    pub fn iter_agg_trades<S, E>(
+        &self, symbol: S, start_time: u64, end_time: E,
+    ) -> impl Stream<Item = Result<AggTrade>> + use<S, E>
+    where
+        S: Into<String>,
+        E: Into<Option<u64>>,
+    {
+        let market = self.clone();
+        let symbol = symbol.into();
+        let end_time = pagination::end_or_now(end_time.into());
+        pagination::by_id(
+            start_time,
+            end_time,
+            PageLimits::new(HOUR, 1000),
+            move |page| {
+                let market = market.clone();
+                let symbol = symbol.clone();
+                async move {
+                    market
+                        .get_agg_trades(
+                            symbol,
+                            page.from_id,
+                            page.start_time,
+                            page.end_time,
+                            page.limit,
+                        )
+                        .await
+                }
+            },
+        )
+    }
+
     // Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
     // https://github.com/binance-exchange/binance-official-api-docs/blob/master/rest-api.md#klinecandlestick-data
     pub async fn get_klines<S1, S2, S3, S4, S5>(
@@ -2,6 +2,23 @@ use serde::{Deserialize, Serialize};
 use serde_json::{from_value, Value};
 use crate::errors::{Error, Result};
 
+/// Numeric type of prices, quantities and balances that Binance sends as
+/// numbers or numeric strings.
+///
+/// `f64` by default. With the `decimal` feature it is
+/// `rust_decimal::Decimal`, which keeps the exact value Binance sent.
+#[cfg(not(feature = "decimal"))]
+pub type Number = f64;
+#[cfg(feature = "decimal")]
+pub type Number = rust_decimal::Decimal;
+
+/// Numeric fields that are kept as the raw `String` by default, and parsed
+/// to `rust_decimal::Decimal` with the `decimal` feature.
+#[cfg(not(feature = "decimal"))]
+pub type NumberString = String;
+#[cfg(feature = "decimal")]
+pub type NumberString = rust_decimal::Decimal;
+
 #[derive(Deserialize, Clone)]
 pub struct Empty {}
 
@@ -92,6 +109,9 @@ pub enum Filters {
         notional: Option<String>,
         min_notional: Option<String>,
         apply_to_market: Option<bool>,
+        apply_min_to_market: Option<bool>,
+        max_notional: Option<String>,
+        apply_max_to_market: Option<bool>,
         avg_price_mins: Option<f64>,
     },
     #[serde(rename = "ICEBERG_PARTS")]
@@ -143,8 +163,8 @@ pub struct AccountInformation {
 #[serde(rename_all = "camelCase")]
 pub struct Balance {
     pub asset: String,
-    pub free: String,
-    pub locked: String,
+    pub free: NumberString,
+    pub locked: NumberString,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -155,22 +175,22 @@ pub struct Order {
     pub order_list_id: i64,
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
-    pub orig_qty: String,
-    pub executed_qty: String,
-    pub cummulative_quote_qty: String,
+    pub price: Number,
+    pub orig_qty: NumberString,
+    pub executed_qty: NumberString,
+    pub cummulative_quote_qty: NumberString,
     pub status: String,
     pub time_in_force: String,
     #[serde(rename = "type")]
     pub type_name: String,
     pub side: String,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
-    pub iceberg_qty: String,
+    pub stop_price: Number,
+    pub iceberg_qty: NumberString,
     pub time: u64,
     pub update_time: u64,
     pub is_working: bool,
-    pub orig_quote_order_qty: String,
+    pub orig_quote_order_qty: NumberString,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -204,40 +224,312 @@ pub struct Transaction {
     pub order_list_id: Option<i64>,
     pub client_order_id: String,
     pub transact_time: u64,
-    #[serde(with = "string_or_float")]
-    pub price: f64,
-    #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
-    #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
-    #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    // Responses with newOrderRespType=ACK stop at transactTime.
+    #[serde(with = "string_or_float", default)]
+    pub price: Number,
+    #[serde(with = "string_or_float", default)]
+    pub orig_qty: Number,
+    #[serde(with = "string_or_float", default)]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float", default)]
+    pub cummulative_quote_qty: Number,
     #[serde(with = "string_or_float", default = "default_stop_price")]
-    pub stop_price: f64,
+    pub stop_price: Number,
+    #[serde(default)]
     pub status: String,
+    #[serde(default)]
     pub time_in_force: String,
-    #[serde(rename = "type")]
+    #[serde(rename = "type", default)]
     pub type_name: String,
+    #[serde(default)]
     pub side: String,
     pub fills: Option<Vec<FillInfo>>,
 }
 
-fn default_stop_price() -> f64 {
-    0.0
+fn default_stop_price() -> Number {
+    Number::default()
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct FillInfo {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Number,
     pub commission_asset: String,
     pub trade_id: Option<u64>,
 }
+/// Response to `/api/v3/order/cancelReplace` when both steps succeeded.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplace {
+    /// "SUCCESS", "FAILURE" or "NOT_ATTEMPTED".
+    pub cancel_result: String,
+    pub new_order_result: String,
+    pub cancel_response: OrderCanceled,
+    pub new_order_response: Transaction,
+}
+
+/// An OCO or other order list, as returned when placing or cancelling it.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderList {
+    pub order_list_id: i64,
+    /// "OCO", "OTO", ...
+    pub contingency_type: String,
+    pub list_status_type: String,
+    pub list_order_status: String,
+    pub list_client_order_id: String,
+    pub transaction_time: u64,
+    pub symbol: String,
+    pub orders: Vec<OrderListOrder>,
+    /// Empty when listed or queried; filled when placed or cancelled.
+    #[serde(default)]
+    pub order_reports: Vec<OrderReport>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+}
+
+/// State of one order of an order list.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderReport {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    #[serde(default)]
+    pub orig_client_order_id: Option<String>,
+    pub transact_time: u64,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: Number,
+    pub status: String,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub side: String,
+    #[serde(default, with = "string_or_float_opt")]
+    pub stop_price: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub iceberg_qty: Option<Number>,
+}
+
+/// Response to a new margin order: the spot fields plus the amount
+/// borrowed for it with `MARGIN_BUY` or `AUTO_BORROW_REPAY`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTransaction {
+    #[serde(flatten)]
+    pub transaction: Transaction,
+    #[serde(default)]
+    pub is_isolated: bool,
+    #[serde(default, with = "string_or_float_opt")]
+    pub margin_buy_borrow_amount: Option<Number>,
+    #[serde(default)]
+    pub margin_buy_borrow_asset: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginOrderList {
+    #[serde(flatten)]
+    pub order_list: OrderList,
+    #[serde(default)]
+    pub is_isolated: bool,
+    #[serde(default, with = "string_or_float_opt")]
+    pub margin_buy_borrow_amount: Option<Number>,
+    #[serde(default)]
+    pub margin_buy_borrow_asset: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: Number,
+    pub status: String,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub side: String,
+    #[serde(with = "string_or_float")]
+    pub stop_price: Number,
+    #[serde(with = "string_or_float")]
+    pub iceberg_qty: Number,
+    pub time: u64,
+    pub update_time: u64,
+    pub is_working: bool,
+    pub is_isolated: bool,
+}
+
+/// Cross margin account.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAccountDetails {
+    pub borrow_enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub margin_level: Number,
+    #[serde(with = "string_or_float")]
+    pub total_asset_of_btc: Number,
+    #[serde(with = "string_or_float")]
+    pub total_liability_of_btc: Number,
+    #[serde(with = "string_or_float")]
+    pub total_net_asset_of_btc: Number,
+    pub trade_enabled: bool,
+    #[serde(default)]
+    pub transfer_in_enabled: bool,
+    #[serde(default)]
+    pub transfer_out_enabled: bool,
+    /// "MARGIN_1" for Cross Margin Classic, "MARGIN_2" for Cross Margin Pro.
+    #[serde(default)]
+    pub account_type: Option<String>,
+    pub user_assets: Vec<MarginAsset>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub borrowed: Number,
+    #[serde(with = "string_or_float")]
+    pub free: Number,
+    #[serde(with = "string_or_float")]
+    pub interest: Number,
+    #[serde(with = "string_or_float")]
+    pub locked: Number,
+    #[serde(with = "string_or_float")]
+    pub net_asset: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAccountInfo {
+    pub assets: Vec<IsolatedMarginPair>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_asset_of_btc: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_liability_of_btc: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_net_asset_of_btc: Option<Number>,
+}
+
+/// The isolated margin account of one symbol.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginPair {
+    pub symbol: String,
+    pub base_asset: IsolatedMarginAsset,
+    pub quote_asset: IsolatedMarginAsset,
+    pub isolated_created: bool,
+    pub enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub margin_level: Number,
+    /// "EXCESSIVE", "NORMAL", "MARGIN_CALL", "PRE_LIQUIDATION" or "FORCE_LIQUIDATION".
+    pub margin_level_status: String,
+    #[serde(with = "string_or_float")]
+    pub margin_ratio: Number,
+    #[serde(with = "string_or_float")]
+    pub index_price: Number,
+    #[serde(with = "string_or_float")]
+    pub liquidate_price: Number,
+    #[serde(with = "string_or_float")]
+    pub liquidate_rate: Number,
+    pub trade_enabled: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAsset {
+    pub asset: String,
+    pub borrow_enabled: bool,
+    pub repay_enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub borrowed: Number,
+    #[serde(with = "string_or_float")]
+    pub free: Number,
+    #[serde(with = "string_or_float")]
+    pub interest: Number,
+    #[serde(with = "string_or_float")]
+    pub locked: Number,
+    #[serde(with = "string_or_float")]
+    pub net_asset: Number,
+    #[serde(with = "string_or_float")]
+    pub net_asset_of_btc: Number,
+    #[serde(with = "string_or_float")]
+    pub total_asset: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxBorrowable {
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    /// Limit of the account's VIP level.
+    #[serde(with = "string_or_float")]
+    pub borrow_limit: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxTransferable {
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestHistory {
+    pub rows: Vec<InterestRecord>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestRecord {
+    pub tx_id: u64,
+    /// Binance's spelling.
+    pub interest_accured_time: u64,
+    pub asset: String,
+    #[serde(default)]
+    pub raw_asset: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub principal: Number,
+    #[serde(with = "string_or_float")]
+    pub interest: Number,
+    #[serde(with = "string_or_float")]
+    pub interest_rate: Number,
+    /// "PERIODIC", "ON_BORROW", "PERIODIC_CONVERTED", ...
+    #[serde(rename = "type")]
+    pub interest_type: String,
+    #[serde(default)]
+    pub isolated_symbol: Option<String>,
+}
+
 /// Response to a test order (endpoint /api/v3/order/test).
 ///
 /// Currently, the API responds {} on a successfull test transaction,
@@ -257,13 +549,13 @@ pub struct OrderBook {
 #[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
 pub struct Bids {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 impl Bids {
-    pub fn new(price: f64, qty: f64) -> Bids {
+    pub fn new(price: Number, qty: Number) -> Bids {
         Bids { price, qty }
     }
 }
@@ -271,9 +563,9 @@ impl Bids {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Asks {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -296,14 +588,14 @@ pub enum Prices {
 pub struct SymbolPrice {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AveragePrice {
     pub mins: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -323,13 +615,13 @@ pub enum KlineSummaries {
 pub struct Tickers {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub bid_price: f64,
+    pub bid_price: Number,
     #[serde(with = "string_or_float")]
-    pub bid_qty: f64,
+    pub bid_qty: Number,
     #[serde(with = "string_or_float")]
-    pub ask_price: f64,
+    pub ask_price: Number,
     #[serde(with = "string_or_float")]
-    pub ask_qty: f64,
+    pub ask_qty: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -337,10 +629,10 @@ pub struct Tickers {
 pub struct TradeHistory {
     pub id: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
-    pub commission: String,
+    pub qty: Number,
+    pub commission: NumberString,
     pub commission_asset: String,
     pub time: u64,
     pub is_buyer: bool,
@@ -352,25 +644,25 @@ pub struct TradeHistory {
 #[serde(rename_all = "camelCase")]
 pub struct PriceStats {
     pub symbol: String,
-    pub price_change: String,
-    pub price_change_percent: String,
-    pub weighted_avg_price: String,
+    pub price_change: NumberString,
+    pub price_change_percent: NumberString,
+    pub weighted_avg_price: NumberString,
     #[serde(with = "string_or_float")]
-    pub prev_close_price: f64,
+    pub prev_close_price: Number,
     #[serde(with = "string_or_float")]
-    pub last_price: f64,
+    pub last_price: Number,
     #[serde(with = "string_or_float")]
-    pub bid_price: f64,
+    pub bid_price: Number,
     #[serde(with = "string_or_float")]
-    pub ask_price: f64,
+    pub ask_price: Number,
     #[serde(with = "string_or_float")]
-    pub open_price: f64,
+    pub open_price: Number,
     #[serde(with = "string_or_float")]
-    pub high_price: f64,
+    pub high_price: Number,
     #[serde(with = "string_or_float")]
-    pub low_price: f64,
+    pub low_price: Number,
     #[serde(with = "string_or_float")]
-    pub volume: f64,
+    pub volume: Number,
     pub open_time: u64,
     pub close_time: u64,
     pub first_id: i64,
@@ -378,6 +670,22 @@ pub struct PriceStats {
     pub count: u64,
 }
 
+/// A trade from `/api/v3/trades` or `/api/v3/historicalTrades`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: Number,
+    pub time: u64,
+    pub is_buyer_maker: bool,
+    pub is_best_match: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AggTrade {
     #[serde(rename = "T")]
@@ -393,9 +701,9 @@ pub struct AggTrade {
     #[serde(rename = "M")]
     pub best_match: bool,
     #[serde(rename = "p", with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(rename = "q", with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -440,11 +748,11 @@ pub struct EventBalance {
     #[serde(rename = "a")]
     pub asset: String,
     #[serde(rename = "wb")]
-    pub wallet_balance: String,
+    pub wallet_balance: NumberString,
     #[serde(rename = "cw")]
-    pub cross_wallet_balance: String,
+    pub cross_wallet_balance: NumberString,
     #[serde(rename = "bc")]
-    pub balance_change: String, // Balance Change except PnL and Commission
+    pub balance_change: NumberString, // Balance Change except PnL and Commission
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -453,27 +761,25 @@ pub struct EventPosition {
     #[serde(rename = "s")]
     pub symbol: String,
     #[serde(rename = "pa")]
-    pub position_amount: String,
+    pub position_amount: NumberString,
     #[serde(rename = "ep")]
-    pub entry_price: String,
+    pub entry_price: NumberString,
     #[serde(rename = "cr")]
-    pub accumulated_realized: String, // (Pre-fee) Accumulated Realized
+    pub accumulated_realized: NumberString, // (Pre-fee) Accumulated Realized
     #[serde(rename = "up")]
-    pub unrealized_pnl: String,
+    pub unrealized_pnl: NumberString,
     #[serde(rename = "mt")]
     pub margin_type: String,
     #[serde(rename = "iw")]
-    pub isolated_wallet: String,
+    pub isolated_wallet: NumberString,
     #[serde(rename = "ps")]
     pub position_side: String,
 }
 
+/// Spot `outboundAccountPosition`: balances of the assets that changed.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct BalanceUpdateEvent {
-    #[serde(rename = "B")]
-    pub balance: Vec<EventBalance>,
-
+pub struct AccountPositionEvent {
     #[serde(rename = "e")]
     pub event_type: String,
 
@@ -482,6 +788,39 @@ pub struct BalanceUpdateEvent {
 
     #[serde(rename = "u")]
     pub last_account_update_time: u64,
+
+    #[serde(rename = "B")]
+    pub balances: Vec<AccountPositionBalance>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountPositionBalance {
+    #[serde(rename = "a")]
+    pub asset: String,
+    #[serde(rename = "f")]
+    pub free: NumberString,
+    #[serde(rename = "l")]
+    pub locked: NumberString,
+}
+
+/// Spot `balanceUpdate`: a deposit, withdrawal or transfer.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceUpdateEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "a")]
+    pub asset: String,
+
+    #[serde(rename = "d")]
+    pub balance_delta: NumberString,
+
+    #[serde(rename = "T")]
+    pub clear_time: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -509,10 +848,10 @@ pub struct OrderTradeEvent {
     pub time_in_force: String,
 
     #[serde(rename = "q")]
-    pub qty: String,
+    pub qty: NumberString,
 
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: NumberString,
 
     #[serde(skip, rename = "P")]
     pub p_ignore: String,
@@ -539,16 +878,16 @@ pub struct OrderTradeEvent {
     pub order_id: u64,
 
     #[serde(rename = "l")]
-    pub qty_last_filled_trade: String,
+    pub qty_last_filled_trade: NumberString,
 
     #[serde(rename = "z")]
-    pub accumulated_qty_filled_trades: String,
+    pub accumulated_qty_filled_trades: NumberString,
 
     #[serde(rename = "L")]
-    pub price_last_filled_trade: String,
+    pub price_last_filled_trade: NumberString,
 
     #[serde(rename = "n")]
-    pub commission: String,
+    pub commission: NumberString,
 
     #[serde(skip, rename = "N")]
     pub asset_commisioned: Option<String>,
@@ -595,10 +934,10 @@ pub struct AggrTradesEvent {
     pub aggregated_trade_id: u64,
 
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: NumberString,
 
     #[serde(rename = "q")]
-    pub qty: String,
+    pub qty: NumberString,
 
     #[serde(rename = "f")]
     pub first_break_trade_id: u64,
@@ -639,10 +978,10 @@ pub struct TradeEvent {
     pub trade_id: u64,
 
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: NumberString,
 
     #[serde(rename = "q")]
-    pub qty: String,
+    pub qty: NumberString,
 
     #[serde(rename = "b")]
     pub buyer_order_id: u64,
@@ -673,7 +1012,7 @@ pub struct IndexPriceEvent {
     pub pair: String,
 
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: NumberString,
 }
 // https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream
 // https://binance-docs.github.io/apidocs/delivery/en/#mark-price-stream
@@ -684,7 +1023,7 @@ pub struct MarkPriceEvent {
     pub event_time: u64,
 
     #[serde(rename = "P")]
-    pub estimate_settle_price: String,
+    pub estimate_settle_price: NumberString,
 
     #[serde(rename = "T")]
     pub next_funding_time: u64,
@@ -696,10 +1035,10 @@ pub struct MarkPriceEvent {
     pub index_price: Option<String>,
 
     #[serde(rename = "p")]
-    pub mark_price: String,
+    pub mark_price: NumberString,
 
     #[serde(rename = "r")]
-    pub funding_rate: String,
+    pub funding_rate: NumberString,
 
     #[serde(rename = "s")]
     pub symbol: String,
@@ -737,22 +1076,22 @@ pub struct LiquidationOrder {
     pub time_in_force: String,
 
     #[serde(rename = "q")]
-    pub original_quantity: String,
+    pub original_quantity: NumberString,
 
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: NumberString,
 
     #[serde(rename = "ap")]
-    pub average_price: String,
+    pub average_price: NumberString,
 
     #[serde(rename = "X")]
     pub order_status: String,
 
     #[serde(rename = "l")]
-    pub order_last_filled_quantity: String,
+    pub order_last_filled_quantity: NumberString,
 
     #[serde(rename = "z")]
-    pub order_filled_accumulated_quantity: String,
+    pub order_filled_accumulated_quantity: NumberString,
 
     #[serde(rename = "T")]
     pub order_trade_time: u64,
@@ -768,16 +1107,16 @@ pub struct BookTickerEvent {
     pub symbol: String,
 
     #[serde(rename = "b")]
-    pub best_bid: String,
+    pub best_bid: NumberString,
 
     #[serde(rename = "B")]
-    pub best_bid_qty: String,
+    pub best_bid_qty: NumberString,
 
     #[serde(rename = "a")]
-    pub best_ask: String,
+    pub best_ask: NumberString,
 
     #[serde(rename = "A")]
-    pub best_ask_qty: String,
+    pub best_ask_qty: NumberString,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -793,49 +1132,49 @@ pub struct DayTickerEvent {
     pub symbol: String,
 
     #[serde(rename = "p")]
-    pub price_change: String,
+    pub price_change: NumberString,
 
     #[serde(rename = "P")]
-    pub price_change_percent: String,
+    pub price_change_percent: NumberString,
 
     #[serde(rename = "w")]
-    pub average_price: String,
+    pub average_price: NumberString,
 
     #[serde(rename = "x")]
-    pub prev_close: String,
+    pub prev_close: NumberString,
 
     #[serde(rename = "c")]
-    pub current_close: String,
+    pub current_close: NumberString,
 
     #[serde(rename = "Q")]
-    pub current_close_qty: String,
+    pub current_close_qty: NumberString,
 
     #[serde(rename = "b")]
-    pub best_bid: String,
+    pub best_bid: NumberString,
 
     #[serde(rename = "B")]
-    pub best_bid_qty: String,
+    pub best_bid_qty: NumberString,
 
     #[serde(rename = "a")]
-    pub best_ask: String,
+    pub best_ask: NumberString,
 
     #[serde(rename = "A")]
-    pub best_ask_qty: String,
+    pub best_ask_qty: NumberString,
 
     #[serde(rename = "o")]
-    pub open: String,
+    pub open: NumberString,
 
     #[serde(rename = "h")]
-    pub high: String,
+    pub high: NumberString,
 
     #[serde(rename = "l")]
-    pub low: String,
+    pub low: NumberString,
 
     #[serde(rename = "v")]
-    pub volume: String,
+    pub volume: NumberString,
 
     #[serde(rename = "q")]
-    pub quote_volume: String,
+    pub quote_volume: NumberString,
 
     #[serde(rename = "O")]
     pub open_time: u64,
@@ -866,31 +1205,31 @@ pub struct WindowTickerEvent {
     pub symbol: String,
 
     #[serde(rename = "p")]
-    pub price_change: String,
+    pub price_change: NumberString,
 
     #[serde(rename = "P")]
-    pub price_change_percent: String,
+    pub price_change_percent: NumberString,
 
     #[serde(rename = "o")]
-    pub open: String,
+    pub open: NumberString,
 
     #[serde(rename = "h")]
-    pub high: String,
+    pub high: NumberString,
 
     #[serde(rename = "l")]
-    pub low: String,
+    pub low: NumberString,
 
     #[serde(rename = "c")]
-    pub current_close: String,
+    pub current_close: NumberString,
 
     #[serde(rename = "w")]
-    pub average_price: String,
+    pub average_price: NumberString,
 
     #[serde(rename = "v")]
-    pub volume: String,
+    pub volume: NumberString,
 
     #[serde(rename = "q")]
-    pub quote_volume: String,
+    pub quote_volume: NumberString,
 
     #[serde(rename = "O")]
     pub open_time: u64,
@@ -921,22 +1260,22 @@ pub struct MiniTickerEvent {
     pub symbol: String,
 
     #[serde(rename = "c")]
-    pub close: String,
+    pub close: NumberString,
 
     #[serde(rename = "o")]
-    pub open: String,
+    pub open: NumberString,
 
     #[serde(rename = "h")]
-    pub high: String,
+    pub high: NumberString,
 
     #[serde(rename = "l")]
-    pub low: String,
+    pub low: NumberString,
 
     #[serde(rename = "v")]
-    pub volume: String,
+    pub volume: NumberString,
 
     #[serde(rename = "q")]
-    pub quote_volume: String,
+    pub quote_volume: NumberString,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -998,25 +1337,25 @@ pub struct IndexKlineEvent {
 pub struct KlineSummary {
     pub open_time: i64,
 
-    pub open: String,
+    pub open: NumberString,
 
-    pub high: String,
+    pub high: NumberString,
 
-    pub low: String,
+    pub low: NumberString,
 
-    pub close: String,
+    pub close: NumberString,
 
-    pub volume: String,
+    pub volume: NumberString,
 
     pub close_time: i64,
 
-    pub quote_asset_volume: String,
+    pub quote_asset_volume: NumberString,
 
     pub number_of_trades: i64,
 
-    pub taker_buy_base_asset_volume: String,
+    pub taker_buy_base_asset_volume: NumberString,
 
-    pub taker_buy_quote_asset_volume: String,
+    pub taker_buy_quote_asset_volume: NumberString,
 }
 
 fn get_value(row: &[Value], index: usize, name: &'static str) -> Result<Value> {
@@ -1076,19 +1415,19 @@ pub struct Kline {
     pub last_trade_id: i64,
 
     #[serde(rename = "o")]
-    pub open: String,
+    pub open: NumberString,
 
     #[serde(rename = "c")]
-    pub close: String,
+    pub close: NumberString,
 
     #[serde(rename = "h")]
-    pub high: String,
+    pub high: NumberString,
 
     #[serde(rename = "l")]
-    pub low: String,
+    pub low: NumberString,
 
     #[serde(rename = "v")]
-    pub volume: String,
+    pub volume: NumberString,
 
     #[serde(rename = "n")]
     pub number_of_trades: i64,
@@ -1097,13 +1436,13 @@ pub struct Kline {
     pub is_final_bar: bool,
 
     #[serde(rename = "q")]
-    pub quote_asset_volume: String,
+    pub quote_asset_volume: NumberString,
 
     #[serde(rename = "V")]
-    pub taker_buy_base_asset_volume: String,
+    pub taker_buy_base_asset_volume: NumberString,
 
     #[serde(rename = "Q")]
-    pub taker_buy_quote_asset_volume: String,
+    pub taker_buy_quote_asset_volume: NumberString,
 
     #[serde(skip, rename = "B")]
     pub ignore_me: String,
@@ -1128,19 +1467,19 @@ pub struct ContinuousKline {
     pub last_trade_id: i64,
 
     #[serde(rename = "o")]
-    pub open: String,
+    pub open: NumberString,
 
     #[serde(rename = "c")]
-    pub close: String,
+    pub close: NumberString,
 
     #[serde(rename = "h")]
-    pub high: String,
+    pub high: NumberString,
 
     #[serde(rename = "l")]
-    pub low: String,
+    pub low: NumberString,
 
     #[serde(rename = "v")]
-    pub volume: String,
+    pub volume: NumberString,
 
     #[serde(rename = "n")]
     pub number_of_trades: i64,
@@ -1149,13 +1488,13 @@ pub struct ContinuousKline {
     pub is_final_bar: bool,
 
     #[serde(rename = "q")]
-    pub quote_volume: String,
+    pub quote_volume: NumberString,
 
     #[serde(rename = "V")]
-    pub active_buy_volume: String,
+    pub active_buy_volume: NumberString,
 
     #[serde(rename = "Q")]
-    pub active_volume_buy_quote: String,
+    pub active_volume_buy_quote: NumberString,
 
     #[serde(skip, rename = "B")]
     pub ignore_me: String,
@@ -1184,19 +1523,19 @@ pub struct IndexKline {
     pub last_trade_id: i64,
 
     #[serde(rename = "o")]
-    pub open: String,
+    pub open: NumberString,
 
     #[serde(rename = "c")]
-    pub close: String,
+    pub close: NumberString,
 
     #[serde(rename = "h")]
-    pub high: String,
+    pub high: NumberString,
 
     #[serde(rename = "l")]
-    pub low: String,
+    pub low: NumberString,
 
     #[serde(rename = "v")]
-    pub volume: String,
+    pub volume: NumberString,
 
     #[serde(rename = "n")]
     pub number_of_trades: i64,
@@ -1253,24 +1592,24 @@ pub struct CoinInfo {
     pub coin: String,
     pub deposit_all_enable: bool,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Number,
     #[serde(with = "string_or_float")]
-    pub freeze: f64,
+    pub freeze: Number,
     #[serde(with = "string_or_float")]
-    pub ipoable: f64,
+    pub ipoable: Number,
     #[serde(with = "string_or_float")]
-    pub ipoing: f64,
+    pub ipoing: Number,
     pub is_legal_money: bool,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Number,
     pub name: String,
     pub network_list: Vec<Network>,
     #[serde(with = "string_or_float")]
-    pub storage: f64,
+    pub storage: Number,
     pub trading: bool,
     pub withdraw_all_enable: bool,
     #[serde(with = "string_or_float")]
-    pub withdrawing: f64,
+    pub withdrawing: Number,
 }
 
 /// Part of the Savings API get all coins response
@@ -1296,9 +1635,9 @@ pub struct Network {
     pub withdraw_desc: Option<String>,
     pub withdraw_enable: bool,
     #[serde(with = "string_or_float")]
-    pub withdraw_fee: f64,
+    pub withdraw_fee: Number,
     #[serde(with = "string_or_float")]
-    pub withdraw_min: f64,
+    pub withdraw_min: Number,
     // pub insert_time: Option<u64>, //commented out for now, because they are not inside the actual response (only the api doc example)
     // pub update_time: Option<u64>,
     pub withdraw_integer_multiple: Option<String>,
@@ -1308,11 +1647,11 @@ pub struct Network {
 #[serde(rename_all = "camelCase")]
 pub struct AssetDetail {
     #[serde(with = "string_or_float")]
-    pub min_withdraw_amount: f64,
+    pub min_withdraw_amount: Number,
     /// false if ALL of networks' are false
     pub deposit_status: bool,
     #[serde(with = "string_or_float")]
-    pub withdraw_fee: f64,
+    pub withdraw_fee: Number,
     /// false if ALL of networks' are false
     pub withdraw_status: bool,
     /// reason
@@ -1340,7 +1679,7 @@ pub(crate) mod string_or_float {
         serializer.collect_str(value)
     }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<super::Number, D::Error>
     where
         D: Deserializer<'de>,
     {
@@ -1354,20 +1693,43 @@ pub(crate) mod string_or_float {
         match StringOrFloat::deserialize(deserializer)? {
             StringOrFloat::String(s) => {
                 if s == "INF" {
-                    Ok(f64::INFINITY)
+                    Ok(infinity())
                 } else {
                     s.parse().map_err(de::Error::custom)
                 }
             }
-            StringOrFloat::Float(i) => Ok(i),
+            StringOrFloat::Float(i) => from_float(i).map_err(de::Error::custom),
         }
     }
+
+    #[cfg(not(feature = "decimal"))]
+    fn infinity() -> f64 {
+        f64::INFINITY
+    }
+
+    #[cfg(feature = "decimal")]
+    fn infinity() -> rust_decimal::Decimal {
+        rust_decimal::Decimal::MAX
+    }
+
+    #[cfg(not(feature = "decimal"))]
+    fn from_float(value: f64) -> Result<f64, std::convert::Infallible> {
+        Ok(value)
+    }
+
+    // A JSON number has already been read as f64 here; its shortest
+    // representation is the literal Binance sent.
+    #[cfg(feature = "decimal")]
+    fn from_float(value: f64) -> Result<rust_decimal::Decimal, rust_decimal::Error> {
+        value.to_string().parse()
+    }
 }
 
 pub(crate) mod string_or_float_opt {
     use std::fmt;
 
-    use serde::{Serializer, Deserialize, Deserializer};
+    use serde::de::IntoDeserializer;
+    use serde::{Deserialize, Deserializer, Serializer};
 
     pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1380,20 +1742,28 @@ pub(crate) mod string_or_float_opt {
         }
     }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+    // `null` and "" are read as `None`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<super::Number>, D::Error>
     where
         D: Deserializer<'de>,
     {
         #[derive(Deserialize)]
         #[serde(untagged)]
         enum StringOrFloat {
-            String(()),
-            Float(()),
+            String(String),
+            Float(f64),
         }
 
-        Ok(Some(crate::model::string_or_float::deserialize(
-            deserializer,
-        )?))
+        match Option::<StringOrFloat>::deserialize(deserializer)? {
+            None => Ok(None),
+            Some(StringOrFloat::String(s)) if s.is_empty() => Ok(None),
+            Some(StringOrFloat::String(s)) => {
+                crate::model::string_or_float::deserialize(s.into_deserializer()).map(Some)
+            }
+            Some(StringOrFloat::Float(f)) => {
+                crate::model::string_or_float::deserialize(f.into_deserializer()).map(Some)
+            }
+        }
     }
 }
 
@@ -1428,6 +1798,8 @@ pub(crate) mod string_or_bool {
     }
 }
 
+// The expected Debug output quotes the NumberString fields.
+#[cfg(not(feature = "decimal"))]
 #[test]
 fn test_account_update_event() {
     let json = r#"
@@ -0,0 +1,233 @@
+/*!
+Options account, positions and orders. Only LIMIT orders are supported;
+quantities are a number of contracts.
+*/
+
+use std::collections::BTreeMap;
+use crate::util::build_signed_request;
+use crate::errors::Result;
+use crate::client::Client;
+use crate::api::{API, Options};
+use crate::model::{Empty, Number};
+use crate::account::OrderSide;
+use crate::futures::account::TimeInForce;
+use crate::options::model::{AccountInformation, Order, Position, TradeHistory};
+
+#[derive(Clone)]
+pub struct OptionsAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+/// A LIMIT order. `time_in_force` defaults to GTC on the exchange.
+#[derive(Debug, Clone)]
+pub struct OptionsOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub quantity: Number,
+    pub price: Number,
+    pub time_in_force: Option<TimeInForce>,
+    pub reduce_only: Option<bool>,
+    pub post_only: Option<bool>,
+    pub client_order_id: Option<String>,
+    /// Cancel the order when market maker protection triggers.
+    pub is_mmp: Option<bool>,
+}
+
+impl OptionsAccount {
+    pub async fn limit_buy(
+        &self, symbol: impl Into<String>, qty: impl Into<Number>, price: Number,
+    ) -> Result<Order> {
+        self.custom_order(limit_order(
+            symbol.into(),
+            OrderSide::Buy,
+            qty.into(),
+            price,
+        ))
+        .await
+    }
+
+    pub async fn limit_sell(
+        &self, symbol: impl Into<String>, qty: impl Into<Number>, price: Number,
+    ) -> Result<Order> {
+        self.custom_order(limit_order(
+            symbol.into(),
+            OrderSide::Sell,
+            qty.into(),
+            price,
+        ))
+        .await
+    }
+
+    pub async fn custom_order(&self, order_request: OptionsOrderRequest) -> Result<Order> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), order_request.symbol);
+        parameters.insert("side".into(), order_request.side.to_string());
+        parameters.insert("type".into(), "LIMIT".to_string());
+        parameters.insert("quantity".into(), order_request.quantity.to_string());
+        parameters.insert("price".into(), order_request.price.to_string());
+        parameters.insert("newOrderRespType".into(), "RESULT".to_string());
+        if let Some(time_in_force) = order_request.time_in_force {
+            parameters.insert("timeInForce".into(), time_in_force.to_string());
+        }
+        if let Some(reduce_only) = order_request.reduce_only {
+            parameters.insert("reduceOnly".into(), reduce_only.to_string());
+        }
+        if let Some(post_only) = order_request.post_only {
+            parameters.insert("postOnly".into(), post_only.to_string());
+        }
+        if let Some(client_order_id) = order_request.client_order_id {
+            parameters.insert("clientOrderId".into(), client_order_id);
+        }
+        if let Some(is_mmp) = order_request.is_mmp {
+            parameters.insert("isMmp".into(), is_mmp.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Options(Options::Order), request)
+            .await
+    }
+
+    pub async fn order_status<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Options(Options::Order), Some(request))
+            .await
+    }
+
+    pub async fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Options(Options::Order), Some(request))
+            .await
+    }
+
+    pub async fn cancel_order_with_client_id<S>(
+        &self, symbol: S, client_order_id: String,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("clientOrderId".into(), client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Options(Options::Order), Some(request))
+            .await
+    }
+
+    pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed::<Empty>(API::Options(Options::AllOpenOrders), Some(request))
+            .await?;
+        Ok(())
+    }
+
+    // Open orders of ONE symbol, or of all symbols with `None`
+    pub async fn get_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>>
+    where
+        S: Into<Option<String>>,
+    {
+        let mut parameters = BTreeMap::new();
+        if let Some(symbol) = symbol.into() {
+            parameters.insert("symbol".into(), symbol);
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Options(Options::OpenOrders), Some(request))
+            .await
+    }
+
+    // Positions of ONE symbol, or of all symbols with `None`
+    pub async fn position_information<S>(&self, symbol: S) -> Result<Vec<Position>>
+    where
+        S: Into<Option<String>>,
+    {
+        let mut parameters = BTreeMap::new();
+        if let Some(symbol) = symbol.into() {
+            parameters.insert("symbol".into(), symbol);
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Options(Options::Position), Some(request))
+            .await
+    }
+
+    // Margin, equity and the aggregated Greeks per underlying
+    pub async fn account_information(&self) -> Result<AccountInformation> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Options(Options::Account), Some(request))
+            .await
+    }
+
+    pub async fn get_user_trades<S, F, N>(
+        &self, symbol: S, from_id: F, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<TradeHistory>>
+    where
+        S: Into<Option<String>>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters = BTreeMap::new();
+        if let Some(symbol) = symbol.into() {
+            parameters.insert("symbol".into(), symbol);
+        }
+        if let Some(fid) = from_id.into() {
+            parameters.insert("fromId".into(), fid.to_string());
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(lim) = limit.into() {
+            parameters.insert("limit".into(), lim.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Options(Options::UserTrades), Some(request))
+            .await
+    }
+}
+
+fn limit_order(
+    symbol: String, side: OrderSide, quantity: Number, price: Number,
+) -> OptionsOrderRequest {
+    OptionsOrderRequest {
+        symbol,
+        side,
+        quantity,
+        price,
+        time_in_force: None,
+        reduce_only: None,
+        post_only: None,
+        client_order_id: None,
+        is_mmp: None,
+    }
+}
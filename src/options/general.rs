@@ -0,0 +1,54 @@
+use crate::model::Empty;
+use crate::options::model::{ExchangeInformation, OptionSymbol, ServerTime};
+use crate::client::Client;
+use crate::errors::{Result, Error};
+use crate::api::API;
+use crate::api::Options;
+
+#[derive(Clone)]
+pub struct OptionsGeneral {
+    pub client: Client,
+}
+
+impl OptionsGeneral {
+    // Test connectivity
+    pub async fn ping(&self) -> Result<String> {
+        self.client
+            .get::<Empty>(API::Options(Options::Ping), None)
+            .await?;
+        Ok("pong".into())
+    }
+
+    // Check server time
+    pub async fn get_server_time(&self) -> Result<ServerTime> {
+        self.client.get(API::Options(Options::Time), None).await
+    }
+
+    // Obtain exchange information
+    // - Current exchange trading rules and symbol information
+    // - Also applies the reported rate limits to the client's rate limiter
+    pub async fn exchange_info(&self) -> Result<ExchangeInformation> {
+        let info: ExchangeInformation = self
+            .client
+            .get(API::Options(Options::ExchangeInfo), None)
+            .await?;
+        self.client
+            .rate_limiter()
+            .set_exchange_limits(&info.rate_limits);
+        Ok(info)
+    }
+
+    // Get Symbol information, e.g. "BTC-250926-60000-C"
+    pub async fn get_symbol_info<S>(&self, symbol: S) -> Result<OptionSymbol>
+    where
+        S: Into<String>,
+    {
+        let upper_symbol = symbol.into().to_uppercase();
+        self.exchange_info()
+            .await?
+            .option_symbols
+            .into_iter()
+            .find(|item| item.symbol == upper_symbol)
+            .ok_or_else(|| Error::Custom("Symbol not found".to_string()))
+    }
+}
@@ -0,0 +1,143 @@
+/*!
+Options market data. Option symbols look like "BTC-250926-60000-C": the
+underlying, expiry (yyMMdd), strike and C(all)/P(ut).
+*/
+
+use crate::util::build_request;
+use crate::options::model::{IndexPrice, Kline, MarkPrice, OpenInterest, OrderBook, PriceStats, Trade};
+use crate::client::Client;
+use crate::errors::Result;
+use std::collections::BTreeMap;
+use crate::api::API;
+use crate::api::Options;
+
+#[derive(Clone)]
+pub struct OptionsMarket {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl OptionsMarket {
+    // Order book (Default 100; max 1000)
+    pub async fn get_depth<S>(&self, symbol: S) -> Result<OrderBook>
+    where
+        S: Into<String>,
+    {
+        self.get_with(Options::Depth, "symbol", symbol.into()).await
+    }
+
+    // Order book at a custom depth. Currently supported values
+    // are 10, 20, 50, 100, 500, 1000
+    pub async fn get_custom_depth<S>(&self, symbol: S, depth: u64) -> Result<OrderBook>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("limit".into(), depth.to_string());
+        let request = build_request(parameters);
+        self.client
+            .get(API::Options(Options::Depth), Some(request))
+            .await
+    }
+
+    pub async fn get_trades<S>(&self, symbol: S) -> Result<Vec<Trade>>
+    where
+        S: Into<String>,
+    {
+        self.get_with(Options::Trades, "symbol", symbol.into())
+            .await
+    }
+
+    // Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
+    pub async fn get_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<Kline>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), format!("{}", st));
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), format!("{}", et));
+        }
+        let request = build_request(parameters);
+        self.client
+            .get(API::Options(Options::Klines), Some(request))
+            .await
+    }
+
+    // Mark price, implied volatility and Greeks of ONE symbol
+    pub async fn get_mark_price<S>(&self, symbol: S) -> Result<Vec<MarkPrice>>
+    where
+        S: Into<String>,
+    {
+        self.get_with(Options::Mark, "symbol", symbol.into()).await
+    }
+
+    // Mark price, implied volatility and Greeks of all symbols
+    pub async fn get_mark_prices(&self) -> Result<Vec<MarkPrice>> {
+        self.client.get(API::Options(Options::Mark), None).await
+    }
+
+    // 24hr ticker price change statistics of ONE symbol
+    pub async fn get_24h_price_stats<S>(&self, symbol: S) -> Result<Vec<PriceStats>>
+    where
+        S: Into<String>,
+    {
+        self.get_with(Options::Ticker, "symbol", symbol.into())
+            .await
+    }
+
+    // 24hr ticker price change statistics for all symbols
+    pub async fn get_all_24h_price_stats(&self) -> Result<Vec<PriceStats>> {
+        self.client.get(API::Options(Options::Ticker), None).await
+    }
+
+    // Spot index price of an underlying, e.g. "BTCUSDT"
+    pub async fn get_index_price<S>(&self, underlying: S) -> Result<IndexPrice>
+    where
+        S: Into<String>,
+    {
+        self.get_with(Options::Index, "underlying", underlying.into())
+            .await
+    }
+
+    // Open interest of every symbol of an underlying asset ("BTC") expiring
+    // on `expiration` (yyMMdd, e.g. "250926")
+    pub async fn open_interest<S1, S2>(
+        &self, underlying_asset: S1, expiration: S2,
+    ) -> Result<Vec<OpenInterest>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("underlyingAsset".into(), underlying_asset.into());
+        parameters.insert("expiration".into(), expiration.into());
+        let request = build_request(parameters);
+        self.client
+            .get(API::Options(Options::OpenInterest), Some(request))
+            .await
+    }
+
+    async fn get_with<T: serde::de::DeserializeOwned>(
+        &self, route: Options, key: &str, value: String,
+    ) -> Result<T> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert(key.into(), value);
+        let request = build_request(parameters);
+        self.client.get(API::Options(route), Some(request)).await
+    }
+}
@@ -0,0 +1,13 @@
+//! European options on `/eapi`: USDT-margined calls and puts on BTC, ETH and
+//! other underlyings, with mark prices, implied volatility and Greeks.
+//!
+//! The services are built like the futures ones, from
+//! `Config::options_rest_api_endpoint`. Market and user data streams go
+//! through [`OptionsWebSockets`](websockets::OptionsWebSockets).
+
+pub mod account;
+pub mod general;
+pub mod market;
+pub mod model;
+pub mod userstream;
+pub mod websockets;
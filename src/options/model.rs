@@ -0,0 +1,722 @@
+use std::fmt::Display;
+use serde::{Deserialize, Serialize};
+use crate::model::{string_or_float, string_or_float_opt, Number};
+
+pub use crate::model::{Asks, Bids, Filters, RateLimit, ServerTime, UserDataStreamExpiredEvent};
+
+/// Whether an option is a call or a put.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OptionSide {
+    Call,
+    Put,
+}
+
+impl Display for OptionSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Call => write!(f, "CALL"),
+            Self::Put => write!(f, "PUT"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInformation {
+    pub timezone: String,
+    pub server_time: u64,
+    pub option_contracts: Vec<OptionContract>,
+    pub option_assets: Vec<OptionAsset>,
+    pub option_symbols: Vec<OptionSymbol>,
+    pub rate_limits: Vec<RateLimit>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionContract {
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub underlying: String,
+    pub settle_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionAsset {
+    pub name: String,
+}
+
+/// An option contract, e.g. "BTC-250926-60000-C": the underlying, expiry
+/// (yyMMdd), strike and side.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionSymbol {
+    pub symbol: String,
+    pub side: OptionSide,
+    #[serde(with = "string_or_float")]
+    pub strike_price: Number,
+    pub underlying: String,
+    /// Expiry in milliseconds since the epoch.
+    pub expiry_date: u64,
+    /// Number of underlying units per contract.
+    pub unit: u64,
+    #[serde(with = "string_or_float")]
+    pub maker_fee_rate: Number,
+    #[serde(with = "string_or_float")]
+    pub taker_fee_rate: Number,
+    #[serde(with = "string_or_float")]
+    pub min_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub max_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub maintenance_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub min_initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub min_maintenance_margin: Number,
+    pub price_scale: u32,
+    pub quantity_scale: u32,
+    pub quote_asset: String,
+    pub filters: Vec<Filters>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderBook {
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    pub bids: Vec<Bids>,
+    pub asks: Vec<Asks>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    pub trade_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: Number,
+    /// Taker side: 1 for buy, -1 for sell.
+    pub side: i8,
+    pub time: u64,
+}
+
+/// Options klines come as objects rather than the arrays of the other APIs.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Kline {
+    pub open_time: u64,
+    pub close_time: u64,
+    pub interval: String,
+    #[serde(with = "string_or_float")]
+    pub open: Number,
+    #[serde(with = "string_or_float")]
+    pub high: Number,
+    #[serde(with = "string_or_float")]
+    pub low: Number,
+    #[serde(with = "string_or_float")]
+    pub close: Number,
+    #[serde(with = "string_or_float")]
+    pub volume: Number,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    pub trade_count: u64,
+    #[serde(with = "string_or_float")]
+    pub taker_volume: Number,
+    #[serde(with = "string_or_float")]
+    pub taker_amount: Number,
+}
+
+/// Mark price, implied volatilities and Greeks of an option.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPrice {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Number,
+    #[serde(with = "string_or_float", rename = "bidIV")]
+    pub bid_iv: Number,
+    #[serde(with = "string_or_float", rename = "askIV")]
+    pub ask_iv: Number,
+    #[serde(with = "string_or_float", rename = "markIV")]
+    pub mark_iv: Number,
+    #[serde(with = "string_or_float")]
+    pub delta: Number,
+    #[serde(with = "string_or_float")]
+    pub theta: Number,
+    #[serde(with = "string_or_float")]
+    pub gamma: Number,
+    #[serde(with = "string_or_float")]
+    pub vega: Number,
+    #[serde(with = "string_or_float")]
+    pub high_price_limit: Number,
+    #[serde(with = "string_or_float")]
+    pub low_price_limit: Number,
+    #[serde(default, with = "string_or_float_opt")]
+    pub risk_free_interest: Option<Number>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceStats {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price_change: Number,
+    #[serde(with = "string_or_float")]
+    pub price_change_percent: Number,
+    #[serde(with = "string_or_float")]
+    pub last_price: Number,
+    #[serde(with = "string_or_float")]
+    pub last_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub open: Number,
+    #[serde(with = "string_or_float")]
+    pub high: Number,
+    #[serde(with = "string_or_float")]
+    pub low: Number,
+    #[serde(with = "string_or_float")]
+    pub volume: Number,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    #[serde(with = "string_or_float")]
+    pub bid_price: Number,
+    #[serde(with = "string_or_float")]
+    pub ask_price: Number,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_trade_id: u64,
+    pub trade_count: u64,
+    #[serde(with = "string_or_float")]
+    pub strike_price: Number,
+    /// Estimated settlement price one hour before exercise, index price at
+    /// other times.
+    #[serde(with = "string_or_float")]
+    pub exercise_price: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexPrice {
+    pub time: u64,
+    #[serde(with = "string_or_float")]
+    pub index_price: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest: Number,
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest_usd: Number,
+    pub timestamp: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub order_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub quantity: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub fee: Number,
+    pub side: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub time_in_force: String,
+    pub reduce_only: bool,
+    pub post_only: bool,
+    pub create_time: u64,
+    pub update_time: u64,
+    pub status: String,
+    #[serde(with = "string_or_float")]
+    pub avg_price: Number,
+    #[serde(default)]
+    pub client_order_id: Option<String>,
+    pub price_scale: u32,
+    pub quantity_scale: u32,
+    pub option_side: OptionSide,
+    pub quote_asset: String,
+    /// Whether the order is protected by market maker protection.
+    #[serde(default)]
+    pub mmp: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub symbol: String,
+    /// "LONG" or "SHORT".
+    pub side: String,
+    #[serde(with = "string_or_float")]
+    pub quantity: Number,
+    #[serde(with = "string_or_float")]
+    pub reducible_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub entry_price: Number,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Number,
+    #[serde(with = "string_or_float")]
+    pub mark_value: Number,
+    /// Return on the position cost.
+    #[serde(with = "string_or_float")]
+    pub ror: Number,
+    #[serde(with = "string_or_float", rename = "unrealizedPNL")]
+    pub unrealized_pnl: Number,
+    #[serde(with = "string_or_float")]
+    pub position_cost: Number,
+    #[serde(with = "string_or_float")]
+    pub strike_price: Number,
+    pub expiry_date: u64,
+    pub price_scale: u32,
+    pub quantity_scale: u32,
+    pub option_side: OptionSide,
+    pub quote_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    pub asset: Vec<AccountAsset>,
+    pub greek: Vec<Greeks>,
+    pub time: u64,
+    /// "NORMAL", "REDUCE_ONLY", ...
+    #[serde(default)]
+    pub risk_level: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub equity: Number,
+    #[serde(with = "string_or_float")]
+    pub available: Number,
+    #[serde(with = "string_or_float")]
+    pub locked: Number,
+    #[serde(with = "string_or_float", rename = "unrealizedPNL")]
+    pub unrealized_pnl: Number,
+}
+
+/// Aggregated Greeks of the positions on one underlying.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Greeks {
+    pub underlying: String,
+    #[serde(with = "string_or_float")]
+    pub delta: Number,
+    #[serde(with = "string_or_float")]
+    pub gamma: Number,
+    #[serde(with = "string_or_float")]
+    pub theta: Number,
+    #[serde(with = "string_or_float")]
+    pub vega: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeHistory {
+    pub id: u64,
+    pub trade_id: u64,
+    pub order_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub quantity: Number,
+    #[serde(with = "string_or_float")]
+    pub fee: Number,
+    #[serde(with = "string_or_float")]
+    pub realized_profit: Number,
+    pub side: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    /// Implied volatility at the time of the trade.
+    #[serde(with = "string_or_float")]
+    pub volatility: Number,
+    /// "TAKER" or "MAKER".
+    pub liquidity: String,
+    pub quote_asset: String,
+    pub time: u64,
+    pub price_scale: u32,
+    pub quantity_scale: u32,
+    pub option_side: OptionSide,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MarkPriceEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "mp", with = "string_or_float")]
+    pub mark_price: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TickerEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open: Number,
+
+    #[serde(rename = "h", with = "string_or_float")]
+    pub high: Number,
+
+    #[serde(rename = "l", with = "string_or_float")]
+    pub low: Number,
+
+    #[serde(rename = "c", with = "string_or_float")]
+    pub close: Number,
+
+    #[serde(rename = "V", with = "string_or_float")]
+    pub volume: Number,
+
+    #[serde(rename = "A", with = "string_or_float")]
+    pub amount: Number,
+
+    #[serde(rename = "P", with = "string_or_float")]
+    pub price_change_percent: Number,
+
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price_change: Number,
+
+    #[serde(rename = "Q", with = "string_or_float")]
+    pub last_qty: Number,
+
+    #[serde(rename = "F")]
+    pub first_trade_id: String,
+
+    #[serde(rename = "L")]
+    pub last_trade_id: String,
+
+    #[serde(rename = "n")]
+    pub trade_count: u64,
+
+    #[serde(rename = "bo", with = "string_or_float")]
+    pub best_bid_price: Number,
+
+    #[serde(rename = "ao", with = "string_or_float")]
+    pub best_ask_price: Number,
+
+    #[serde(rename = "bq", with = "string_or_float")]
+    pub best_bid_qty: Number,
+
+    #[serde(rename = "aq", with = "string_or_float")]
+    pub best_ask_qty: Number,
+
+    #[serde(rename = "b", with = "string_or_float")]
+    pub bid_iv: Number,
+
+    #[serde(rename = "a", with = "string_or_float")]
+    pub ask_iv: Number,
+
+    #[serde(rename = "d", with = "string_or_float")]
+    pub delta: Number,
+
+    #[serde(rename = "t", with = "string_or_float")]
+    pub theta: Number,
+
+    #[serde(rename = "g", with = "string_or_float")]
+    pub gamma: Number,
+
+    #[serde(rename = "v", with = "string_or_float")]
+    pub vega: Number,
+
+    #[serde(rename = "vo", with = "string_or_float")]
+    pub implied_volatility: Number,
+
+    #[serde(rename = "mp", with = "string_or_float")]
+    pub mark_price: Number,
+
+    #[serde(rename = "hl", with = "string_or_float")]
+    pub high_price_limit: Number,
+
+    #[serde(rename = "ll", with = "string_or_float")]
+    pub low_price_limit: Number,
+
+    #[serde(rename = "eep", with = "string_or_float")]
+    pub estimated_exercise_price: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TradeEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "t")]
+    pub trade_id: String,
+
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: Number,
+
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: Number,
+
+    #[serde(rename = "b")]
+    pub buy_order_id: u64,
+
+    #[serde(rename = "a")]
+    pub sell_order_id: u64,
+
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+
+    /// Taker side: "1" for buy, "-1" for sell.
+    #[serde(rename = "S")]
+    pub side: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DepthEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "u")]
+    pub update_id: u64,
+
+    #[serde(rename = "pu")]
+    pub previous_update_id: u64,
+
+    #[serde(rename = "b")]
+    pub bids: Vec<Bids>,
+
+    #[serde(rename = "a")]
+    pub asks: Vec<Asks>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OpenInterestEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    /// Open interest in contracts.
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open_interest: Number,
+
+    /// Open interest in USDT.
+    #[serde(rename = "h", with = "string_or_float")]
+    pub open_interest_usd: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountUpdateEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "B")]
+    pub balances: Vec<EventBalance>,
+
+    #[serde(rename = "G", default)]
+    pub greeks: Vec<EventGreeks>,
+
+    #[serde(rename = "P", default)]
+    pub positions: Vec<EventPosition>,
+
+    #[serde(rename = "uid")]
+    pub user_id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EventBalance {
+    #[serde(rename = "a")]
+    pub asset: String,
+
+    #[serde(rename = "b", with = "string_or_float")]
+    pub balance: Number,
+
+    #[serde(rename = "m", with = "string_or_float")]
+    pub position_value: Number,
+
+    #[serde(rename = "u", with = "string_or_float")]
+    pub unrealized_pnl: Number,
+
+    #[serde(rename = "U", with = "string_or_float")]
+    pub long_unrealized_pnl: Number,
+
+    #[serde(rename = "M", with = "string_or_float")]
+    pub maintenance_margin: Number,
+
+    #[serde(rename = "i", with = "string_or_float")]
+    pub initial_margin: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EventGreeks {
+    #[serde(rename = "ui")]
+    pub underlying: String,
+
+    #[serde(rename = "d", with = "string_or_float")]
+    pub delta: Number,
+
+    #[serde(rename = "t", with = "string_or_float")]
+    pub theta: Number,
+
+    #[serde(rename = "g", with = "string_or_float")]
+    pub gamma: Number,
+
+    #[serde(rename = "v", with = "string_or_float")]
+    pub vega: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EventPosition {
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "c", with = "string_or_float")]
+    pub quantity: Number,
+
+    #[serde(rename = "r", with = "string_or_float")]
+    pub reducible_qty: Number,
+
+    #[serde(rename = "p", with = "string_or_float")]
+    pub position_value: Number,
+
+    #[serde(rename = "a", with = "string_or_float")]
+    pub entry_price: Number,
+}
+
+/// Order updates; one event can carry several orders.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderTradeEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "o")]
+    pub orders: Vec<OrderUpdate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderUpdate {
+    #[serde(rename = "T")]
+    pub create_time: u64,
+
+    #[serde(rename = "t")]
+    pub update_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "c", default)]
+    pub client_order_id: String,
+
+    #[serde(rename = "oid")]
+    pub order_id: String,
+
+    /// Negative for sell orders.
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: Number,
+
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: Number,
+
+    #[serde(rename = "r")]
+    pub reduce_only: bool,
+
+    #[serde(rename = "po")]
+    pub post_only: bool,
+
+    #[serde(rename = "S")]
+    pub status: String,
+
+    #[serde(rename = "e", with = "string_or_float")]
+    pub executed_qty: Number,
+
+    #[serde(rename = "ec", with = "string_or_float")]
+    pub executed_cost: Number,
+
+    #[serde(rename = "f", with = "string_or_float")]
+    pub fee: Number,
+
+    #[serde(rename = "tif", default)]
+    pub time_in_force: String,
+
+    #[serde(rename = "oty", default)]
+    pub order_type: String,
+
+    #[serde(rename = "fi", default)]
+    pub fills: Vec<OrderFill>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderFill {
+    #[serde(rename = "t")]
+    pub trade_id: String,
+
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: Number,
+
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: Number,
+
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+
+    /// "TAKER" or "MAKER".
+    #[serde(rename = "m")]
+    pub liquidity: String,
+
+    #[serde(rename = "f", with = "string_or_float")]
+    pub fee: Number,
+}
@@ -0,0 +1,243 @@
+use crate::config::Config;
+use crate::errors::Result;
+use crate::event_channel::{ChannelConfig, EventReceiver, EventSender, event_channel};
+use crate::options::model::{
+    AccountUpdateEvent, DepthEvent, MarkPriceEvent, OpenInterestEvent, OrderTradeEvent,
+    TickerEvent, TradeEvent, UserDataStreamExpiredEvent,
+};
+use crate::async_websocket_client::{
+    AsyncWebsocketClient, ConnectionEvent, ReconnectPolicy, SpawnedSocket, StreamEvent,
+};
+
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::websockets::{event_type, join_streams};
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+static OPTIONS_WS_BASE: &str = "wss://nbstream.binance.com/eoptions";
+
+/// Events of the options market and user data streams. Streams per
+/// underlying (e.g. "ETH@markPrice" or "ETH@openInterest@250926") deliver a
+/// list of events at once.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Serialize, Clone)]
+pub enum OptionsWebsocketEvent {
+    MarkPrice(Vec<MarkPriceEvent>),
+    Ticker(TickerEvent),
+    TickerAll(Vec<TickerEvent>),
+    Trade(TradeEvent),
+    Depth(DepthEvent),
+    OpenInterest(Vec<OpenInterestEvent>),
+    AccountUpdate(AccountUpdateEvent),
+    OrderTrade(OrderTradeEvent),
+    UserDataStreamExpiredEvent(UserDataStreamExpiredEvent),
+    /// An event without a typed variant, as received.
+    Unknown(Value),
+    #[serde(skip)]
+    Connection(ConnectionEvent),
+}
+
+impl OptionsWebsocketEvent {
+    fn from_value(value: Value) -> serde_json::Result<Self> {
+        let event = match (value.is_array(), event_type(&value)) {
+            (_, Some("markPrice")) => {
+                OptionsWebsocketEvent::MarkPrice(serde_json::from_value(value)?)
+            }
+            (_, Some("openInterest")) => {
+                OptionsWebsocketEvent::OpenInterest(serde_json::from_value(value)?)
+            }
+            (true, Some("24hrTicker")) => {
+                OptionsWebsocketEvent::TickerAll(serde_json::from_value(value)?)
+            }
+            (false, Some("24hrTicker")) => {
+                OptionsWebsocketEvent::Ticker(serde_json::from_value(value)?)
+            }
+            (false, Some("trade")) => OptionsWebsocketEvent::Trade(serde_json::from_value(value)?),
+            (false, Some("depth")) => OptionsWebsocketEvent::Depth(serde_json::from_value(value)?),
+            (false, Some("ACCOUNT_UPDATE")) => {
+                OptionsWebsocketEvent::AccountUpdate(serde_json::from_value(value)?)
+            }
+            (false, Some("ORDER_TRADE_UPDATE")) => {
+                OptionsWebsocketEvent::OrderTrade(serde_json::from_value(value)?)
+            }
+            (false, Some("listenKeyExpired")) => {
+                OptionsWebsocketEvent::UserDataStreamExpiredEvent(serde_json::from_value(value)?)
+            }
+            _ => OptionsWebsocketEvent::Unknown(value),
+        };
+        Ok(event)
+    }
+}
+
+impl<'de> Deserialize<'de> for OptionsWebsocketEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        OptionsWebsocketEvent::from_value(Value::deserialize(deserializer)?)
+            .map_err(de::Error::custom)
+    }
+}
+
+impl From<ConnectionEvent> for OptionsWebsocketEvent {
+    fn from(event: ConnectionEvent) -> Self {
+        OptionsWebsocketEvent::Connection(event)
+    }
+}
+
+type Handler<'a> = Box<
+    dyn FnMut(
+            StreamEvent<OptionsWebsocketEvent>,
+        ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>
+        + Send
+        + Sync
+        + 'a,
+>;
+
+pub struct OptionsWebSockets<'a> {
+    // Set for sockets built with `channel`, to report the error that ends `spawn`.
+    events: Option<EventSender<Result<StreamEvent<OptionsWebsocketEvent>>>>,
+    client: AsyncWebsocketClient<'a, OptionsWebsocketEvent, Handler<'a>>,
+}
+
+impl<'a> OptionsWebSockets<'a> {
+    pub fn new<Callback>(mut user_handler: Callback) -> OptionsWebSockets<'a>
+    where
+        Callback: FnMut(OptionsWebsocketEvent) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>
+            + Send
+            + Sync
+            + 'a,
+    {
+        Self::new_with_stream_events(move |event: StreamEvent<OptionsWebsocketEvent>| {
+            user_handler(event.event)
+        })
+    }
+
+    /// Like [`new`](Self::new), but the handler also gets the stream name and
+    /// the local receive time of each event.
+    pub fn new_with_stream_events<Callback>(user_handler: Callback) -> OptionsWebSockets<'a>
+    where
+        Callback: FnMut(
+                StreamEvent<OptionsWebsocketEvent>,
+            ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>
+            + Send
+            + Sync
+            + 'a,
+    {
+        OptionsWebSockets {
+            events: None,
+            client: AsyncWebsocketClient::new(Box::new(user_handler)),
+        }
+    }
+
+    /// Reconnect automatically when the connection drops. Lifecycle changes are
+    /// delivered to the handler as [`OptionsWebsocketEvent::Connection`].
+    pub fn set_reconnect_policy<P: Into<Option<ReconnectPolicy>>>(&mut self, policy: P) {
+        self.client.set_reconnect_policy(policy);
+    }
+
+    /// Connects to one stream, e.g. "BTC-250926-60000-C@ticker",
+    /// "ETH@markPrice" or a listen key.
+    pub async fn connect<S: Display>(&mut self, subscription: S) -> Result<()> {
+        self.client
+            .connect(&format!("{}/ws/{}", OPTIONS_WS_BASE, subscription))
+            .await
+    }
+
+    pub(crate) async fn connect_url(&mut self, wss_url: &str) -> Result<()> {
+        self.client.connect(wss_url).await
+    }
+
+    /// Connects to one stream under `config.options_ws_endpoint`.
+    pub async fn connect_with_config<S: Display>(
+        &mut self, subscription: S, config: &Config,
+    ) -> Result<()> {
+        self.client
+            .connect(&format!("{}/{}", config.options_ws_endpoint, subscription))
+            .await
+    }
+
+    pub async fn connect_multiple_streams<S: Display>(&mut self, endpoints: &[S]) -> Result<()> {
+        self.client
+            .connect(&format!(
+                "{}/stream?streams={}",
+                OPTIONS_WS_BASE,
+                join_streams(endpoints)
+            ))
+            .await
+    }
+
+    pub async fn disconnect(&mut self) -> Result<()> {
+        self.client.disconnect().await
+    }
+
+    pub async fn event_loop(&self, running: Arc<AtomicBool>) -> Result<()> {
+        self.client.event_loop(running).await
+    }
+
+    /// Adds streams to the open connection. Returns once the server has
+    /// acknowledged them, which requires `event_loop` to be running
+    /// concurrently.
+    pub async fn subscribe<S: Display>(&self, streams: &[S]) -> Result<()> {
+        self.client.subscribe(streams).await
+    }
+
+    pub async fn unsubscribe<S: Display>(&self, streams: &[S]) -> Result<()> {
+        self.client.unsubscribe(streams).await
+    }
+
+    pub async fn list_subscriptions(&self) -> Result<Vec<String>> {
+        self.client.list_subscriptions().await
+    }
+}
+
+impl OptionsWebSockets<'static> {
+    /// Builds a socket that queues its events, with their stream name and
+    /// receive time, for the returned receiver instead of calling a handler.
+    /// See [`event_channel`](crate::event_channel).
+    pub fn channel(
+        config: ChannelConfig,
+    ) -> (
+        OptionsWebSockets<'static>,
+        EventReceiver<Result<StreamEvent<OptionsWebsocketEvent>>>,
+    ) {
+        let (sender, receiver) = event_channel(config);
+        let handler_sender = sender.clone();
+        let mut web_socket = OptionsWebSockets::new_with_stream_events(
+            move |event: StreamEvent<OptionsWebsocketEvent>| {
+                let sender = handler_sender.clone();
+                Box::pin(async move { sender.send(Ok(event)).await })
+            },
+        );
+        web_socket.events = Some(sender);
+        (web_socket, receiver)
+    }
+
+    /// Runs [`event_loop`](Self::event_loop) on a new task until the
+    /// connection fails for good or, for a socket built with
+    /// [`channel`](Self::channel), the receiver is dropped. The returned
+    /// handle keeps `subscribe`, `unsubscribe` and `list_subscriptions`
+    /// usable while the loop runs. A channel socket sends the error that
+    /// ended the loop to the receiver instead of returning it.
+    pub fn spawn(self) -> SpawnedSocket<OptionsWebSockets<'static>> {
+        SpawnedSocket::spawn(self, |socket| async move {
+            let result = socket.event_loop(Arc::new(AtomicBool::new(true))).await;
+            match (&socket.events, result) {
+                (Some(events), result) => {
+                    if let Err(e) = result {
+                        if !events.is_closed() {
+                            let _ = events.send(Err(e)).await;
+                        }
+                    }
+                    // The handle keeps the socket, and with it the sender, alive.
+                    events.close();
+                    Ok(())
+                }
+                (None, result) => result,
+            }
+        })
+    }
+}
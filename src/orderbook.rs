@@ -0,0 +1,475 @@
+/*!
+Local order book maintained from the `<symbol>@depth` diff stream.
+
+Follows Binance's procedure for managing a local order book:
+
+1. Buffer `DepthOrderBookEvent`s received from the stream.
+2. Fetch a depth snapshot over REST.
+3. Drop buffered events older than the snapshot's `lastUpdateId`.
+4. Apply the first event that straddles the snapshot, then every following
+   event as long as the update ids stay continuous.
+5. On a gap, discard the book and start again from step 2.
+
+[`LocalOrderBook::update`] fetches at most one snapshot at a time. When a
+snapshot request fails or the snapshot cannot be bridged, the next attempt
+waits for an exponential backoff and events keep being buffered meanwhile.
+
+Spot and USD-M futures differ in how continuity is checked: spot events must
+satisfy `U == previous u + 1`, while futures events carry `pu` which must equal
+the previous `u`.
+
+```no_run
+use binance_rs_plus::api::Binance;
+use binance_rs_plus::market::Market;
+use binance_rs_plus::orderbook::{BookMarket, LocalOrderBook};
+use binance_rs_plus::websockets::WebsocketEvent;
+
+# async fn run(event: WebsocketEvent) -> binance_rs_plus::errors::Result<()> {
+let market: Market = Binance::new(None, None);
+let mut book = LocalOrderBook::new("BNBBTC", BookMarket::Spot);
+
+// Inside the websocket handler:
+if let WebsocketEvent::DepthOrderBook(depth) = event {
+    book.update(depth, &market).await?;
+    println!("best bid: {:?}, best ask: {:?}", book.best_bid(), book.best_ask());
+}
+# Ok(())
+# }
+```
+*/
+
+use crate::async_websocket_client::ReconnectPolicy;
+use crate::errors::{Error, Result};
+use crate::futures::market::FuturesMarket;
+use crate::market::Market;
+use crate::model::{Asks, Bids, DepthOrderBookEvent, Number};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, VecDeque};
+use std::time::Instant;
+
+/// Which exchange the depth stream comes from. This decides how update id
+/// continuity is checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookMarket {
+    Spot,
+    UsdMFutures,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncState {
+    /// No snapshot has been applied; events are buffered.
+    AwaitingSnapshot,
+    /// A snapshot has been applied and the first event that bridges it has
+    /// not been seen yet.
+    AwaitingFirstEvent,
+    /// The book is consistent with the exchange.
+    Synced,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceLevel {
+    pub price: Number,
+    pub qty: Number,
+}
+
+/// REST depth snapshot used to seed a [`LocalOrderBook`].
+#[derive(Debug, Clone)]
+pub struct DepthSnapshot {
+    pub last_update_id: u64,
+    pub bids: Vec<PriceLevel>,
+    pub asks: Vec<PriceLevel>,
+}
+
+impl From<crate::model::OrderBook> for DepthSnapshot {
+    fn from(book: crate::model::OrderBook) -> Self {
+        Self {
+            last_update_id: book.last_update_id,
+            bids: bid_levels(&book.bids),
+            asks: ask_levels(&book.asks),
+        }
+    }
+}
+
+impl From<crate::futures::model::OrderBook> for DepthSnapshot {
+    fn from(book: crate::futures::model::OrderBook) -> Self {
+        Self {
+            last_update_id: book.last_update_id,
+            bids: bid_levels(&book.bids),
+            asks: ask_levels(&book.asks),
+        }
+    }
+}
+
+fn bid_levels(bids: &[Bids]) -> Vec<PriceLevel> {
+    bids.iter()
+        .map(|b| PriceLevel {
+            price: b.price,
+            qty: b.qty,
+        })
+        .collect()
+}
+
+fn ask_levels(asks: &[Asks]) -> Vec<PriceLevel> {
+    asks.iter()
+        .map(|a| PriceLevel {
+            price: a.price,
+            qty: a.qty,
+        })
+        .collect()
+}
+
+/// Anything that can fetch a depth snapshot for a symbol.
+pub trait DepthSnapshotSource {
+    fn depth_snapshot(
+        &self, symbol: &str, limit: u64,
+    ) -> impl Future<Output = Result<DepthSnapshot>> + Send;
+}
+
+impl DepthSnapshotSource for Market {
+    async fn depth_snapshot(&self, symbol: &str, limit: u64) -> Result<DepthSnapshot> {
+        Ok(self.get_custom_depth(symbol, limit).await?.into())
+    }
+}
+
+impl DepthSnapshotSource for FuturesMarket {
+    async fn depth_snapshot(&self, symbol: &str, limit: u64) -> Result<DepthSnapshot> {
+        Ok(self.get_custom_depth(symbol, limit).await?.into())
+    }
+}
+
+// Prices ordered totally so they can key a BTreeMap.
+#[derive(Debug, Clone, Copy)]
+struct Price(Number);
+
+impl PartialEq for Price {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Price {}
+
+impl PartialOrd for Price {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Price {
+    #[cfg(not(feature = "decimal"))]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+
+    #[cfg(feature = "decimal")]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LocalOrderBook {
+    symbol: String,
+    market: BookMarket,
+    depth_limit: u64,
+    max_buffer: usize,
+    state: SyncState,
+    last_update_id: u64,
+    bids: BTreeMap<Price, Number>,
+    asks: BTreeMap<Price, Number>,
+    buffer: VecDeque<DepthOrderBookEvent>,
+    resyncs: u64,
+    snapshot_backoff: ReconnectPolicy,
+    snapshot_attempts: u32,
+    next_snapshot_at: Option<Instant>,
+}
+
+impl LocalOrderBook {
+    pub fn new<S: Into<String>>(symbol: S, market: BookMarket) -> Self {
+        Self {
+            symbol: symbol.into().to_uppercase(),
+            market,
+            depth_limit: 1000,
+            max_buffer: 1000,
+            state: SyncState::AwaitingSnapshot,
+            last_update_id: 0,
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+            buffer: VecDeque::new(),
+            resyncs: 0,
+            snapshot_backoff: ReconnectPolicy::default(),
+            snapshot_attempts: 0,
+            next_snapshot_at: None,
+        }
+    }
+
+    /// Number of levels requested when fetching a snapshot (default 1000).
+    pub fn set_depth_limit(mut self, depth_limit: u64) -> Self {
+        self.depth_limit = depth_limit;
+        self
+    }
+
+    /// Maximum number of events kept while waiting for a snapshot (default
+    /// 1000). The oldest events are dropped first.
+    pub fn set_max_buffer(mut self, max_buffer: usize) -> Self {
+        self.max_buffer = max_buffer.max(1);
+        self
+    }
+
+    /// Backoff between snapshot attempts made by [`update`](Self::update)
+    /// that did not leave the book synced. Its `max_attempts` is ignored.
+    pub fn set_snapshot_backoff(mut self, snapshot_backoff: ReconnectPolicy) -> Self {
+        self.snapshot_backoff = snapshot_backoff;
+        self
+    }
+
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    pub fn state(&self) -> SyncState {
+        self.state
+    }
+
+    pub fn is_synced(&self) -> bool {
+        self.state == SyncState::Synced
+    }
+
+    pub fn last_update_id(&self) -> u64 {
+        self.last_update_id
+    }
+
+    /// How many times the book was discarded because of a sequence gap.
+    pub fn resyncs(&self) -> u64 {
+        self.resyncs
+    }
+
+    /// Feed one event from the depth stream.
+    ///
+    /// Events for other symbols are ignored. If the event reveals a gap the
+    /// book is cleared and the state goes back to
+    /// [`SyncState::AwaitingSnapshot`]; a new snapshot must then be applied.
+    pub fn push(&mut self, event: DepthOrderBookEvent) -> SyncState {
+        if !event.symbol.eq_ignore_ascii_case(&self.symbol) {
+            return self.state;
+        }
+
+        match self.state {
+            SyncState::AwaitingSnapshot => {
+                if self.buffer.len() == self.max_buffer {
+                    self.buffer.pop_front();
+                }
+                self.buffer.push_back(event);
+            }
+            SyncState::AwaitingFirstEvent | SyncState::Synced => self.apply_event(event),
+        }
+        self.state
+    }
+
+    /// Seed the book from a REST snapshot and replay the buffered events.
+    pub fn apply_snapshot(&mut self, snapshot: DepthSnapshot) -> SyncState {
+        self.bids = snapshot
+            .bids
+            .iter()
+            .filter(|l| l.qty > Number::default())
+            .map(|l| (Price(l.price), l.qty))
+            .collect();
+        self.asks = snapshot
+            .asks
+            .iter()
+            .filter(|l| l.qty > Number::default())
+            .map(|l| (Price(l.price), l.qty))
+            .collect();
+        self.last_update_id = snapshot.last_update_id;
+        self.state = SyncState::AwaitingFirstEvent;
+
+        let buffered = std::mem::take(&mut self.buffer);
+        for event in buffered {
+            if self.state == SyncState::AwaitingSnapshot {
+                // A gap was found while replaying; keep the rest for the next
+                // snapshot.
+                self.buffer.push_back(event);
+            } else {
+                self.apply_event(event);
+            }
+        }
+        self.state
+    }
+
+    /// Fetch a snapshot from `source` and apply it.
+    pub async fn sync<S: DepthSnapshotSource>(&mut self, source: &S) -> Result<SyncState> {
+        let snapshot = source
+            .depth_snapshot(&self.symbol, self.depth_limit)
+            .await?;
+        Ok(self.apply_snapshot(snapshot))
+    }
+
+    /// Feed one event and fetch a new snapshot whenever the book needs one.
+    ///
+    /// After a failed snapshot request, or a snapshot the buffered events
+    /// cannot bridge, no new snapshot is requested until the snapshot backoff
+    /// has elapsed; events are only buffered until then.
+    pub async fn update<S: DepthSnapshotSource>(
+        &mut self, event: DepthOrderBookEvent, source: &S,
+    ) -> Result<SyncState> {
+        if self.push(event) != SyncState::AwaitingSnapshot {
+            return Ok(self.state);
+        }
+        if self.next_snapshot_at.is_some_and(|at| Instant::now() < at) {
+            return Ok(self.state);
+        }
+
+        let result = self.sync(source).await;
+        if !matches!(result, Ok(SyncState::Synced)) {
+            self.snapshot_attempts = self.snapshot_attempts.saturating_add(1);
+            self.next_snapshot_at =
+                Some(Instant::now() + self.snapshot_backoff.delay(self.snapshot_attempts));
+        }
+        result
+    }
+
+    fn apply_event(&mut self, event: DepthOrderBookEvent) {
+        let snapshot_id = self.last_update_id;
+        match self.state {
+            SyncState::AwaitingFirstEvent => {
+                let (stale, bridges) = match self.market {
+                    BookMarket::Spot => (
+                        event.final_update_id <= snapshot_id,
+                        event.first_update_id <= snapshot_id + 1
+                            && event.final_update_id > snapshot_id,
+                    ),
+                    BookMarket::UsdMFutures => (
+                        event.final_update_id < snapshot_id,
+                        event.first_update_id <= snapshot_id
+                            && event.final_update_id >= snapshot_id,
+                    ),
+                };
+                if stale {
+                    return;
+                }
+                if !bridges {
+                    // The snapshot is older than the stream can account for.
+                    self.reset(event);
+                    return;
+                }
+            }
+            SyncState::Synced => {
+                let continuous = match self.market {
+                    BookMarket::Spot => event.first_update_id == snapshot_id + 1,
+                    BookMarket::UsdMFutures => event.previous_final_update_id == Some(snapshot_id),
+                };
+                if !continuous {
+                    self.reset(event);
+                    return;
+                }
+            }
+            SyncState::AwaitingSnapshot => return,
+        }
+
+        for bid in &event.bids {
+            update_level(&mut self.bids, bid.price, bid.qty);
+        }
+        for ask in &event.asks {
+            update_level(&mut self.asks, ask.price, ask.qty);
+        }
+        self.last_update_id = event.final_update_id;
+        self.state = SyncState::Synced;
+        self.snapshot_attempts = 0;
+        self.next_snapshot_at = None;
+    }
+
+    fn reset(&mut self, pending: DepthOrderBookEvent) {
+        self.bids.clear();
+        self.asks.clear();
+        self.buffer.clear();
+        self.buffer.push_back(pending);
+        self.last_update_id = 0;
+        self.state = SyncState::AwaitingSnapshot;
+        self.resyncs += 1;
+    }
+
+    pub fn best_bid(&self) -> Option<PriceLevel> {
+        self.bids.iter().next_back().map(level)
+    }
+
+    pub fn best_ask(&self) -> Option<PriceLevel> {
+        self.asks.iter().next().map(level)
+    }
+
+    /// Difference between the best ask and best bid.
+    pub fn spread(&self) -> Option<Number> {
+        Some(self.best_ask()?.price - self.best_bid()?.price)
+    }
+
+    /// Top `n` bids, best first.
+    pub fn bids(&self, n: usize) -> Vec<PriceLevel> {
+        self.bids.iter().rev().take(n).map(level).collect()
+    }
+
+    /// Top `n` asks, best first.
+    pub fn asks(&self, n: usize) -> Vec<PriceLevel> {
+        self.asks.iter().take(n).map(level).collect()
+    }
+
+    /// Top `n` bids with `qty` holding the running total from the best bid.
+    pub fn cumulative_bids(&self, n: usize) -> Vec<PriceLevel> {
+        cumulative(self.bids(n))
+    }
+
+    /// Top `n` asks with `qty` holding the running total from the best ask.
+    pub fn cumulative_asks(&self, n: usize) -> Vec<PriceLevel> {
+        cumulative(self.asks(n))
+    }
+
+    /// Total bid quantity at or above `price`.
+    pub fn bid_depth_to(&self, price: Number) -> Number {
+        self.bids.range(Price(price)..).map(|(_, qty)| qty).sum()
+    }
+
+    /// Total ask quantity at or below `price`.
+    pub fn ask_depth_to(&self, price: Number) -> Number {
+        self.asks.range(..=Price(price)).map(|(_, qty)| qty).sum()
+    }
+
+    /// Fails unless the book is synced, for callers that must not act on a
+    /// stale book.
+    pub fn ensure_synced(&self) -> Result<()> {
+        if self.is_synced() {
+            Ok(())
+        } else {
+            Err(Error::Custom(format!(
+                "Order book for {} is not synced",
+                self.symbol
+            )))
+        }
+    }
+}
+
+fn update_level(side: &mut BTreeMap<Price, Number>, price: Number, qty: Number) {
+    if qty == Number::default() {
+        side.remove(&Price(price));
+    } else {
+        side.insert(Price(price), qty);
+    }
+}
+
+fn level((price, qty): (&Price, &Number)) -> PriceLevel {
+    PriceLevel {
+        price: price.0,
+        qty: *qty,
+    }
+}
+
+fn cumulative(levels: Vec<PriceLevel>) -> Vec<PriceLevel> {
+    let mut total = Number::default();
+    levels
+        .into_iter()
+        .map(|l| {
+            total += l.qty;
+            PriceLevel {
+                price: l.price,
+                qty: total,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,371 @@
+//! History endpoints as a [`Stream`] over a whole time range.
+//!
+//! Endpoints such as `aggTrades`, `myTrades` or `income` return one page of
+//! at most 500 to 1000 records, and only over a limited time window. The
+//! streams built here request the range window by window and page by page,
+//! and yield the records one at a time, oldest first. Each request goes
+//! through the client's [`RateLimiter`](crate::rate_limit::RateLimiter), so
+//! a long backfill waits for the weight budget instead of getting banned.
+//!
+//! There are two ways to walk a range:
+//!
+//! - [`by_id`] asks for each time window until one is full, then continues
+//!   with `fromId` (or `orderId`) from the last id seen. Ids increase with
+//!   time, so nothing is skipped or repeated.
+//! - [`by_time`] continues a full window from the time of its last record.
+//!   The records already seen at that millisecond are skipped.
+//!
+//! An error ends the stream after it is yielded.
+//!
+//!```no_run
+//! use binance_rs_plus::api::Binance;
+//! use binance_rs_plus::market::Market;
+//! use futures_util::{StreamExt, pin_mut};
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     let market: Market = Binance::new(None, None);
+//!
+//!     let trades = market.iter_agg_trades("BTCUSDT", 1_700_000_000_000, 1_700_086_400_000);
+//!     pin_mut!(trades);
+//!     while let Some(trade) = trades.next().await {
+//!         let trade = trade?;
+//!         println!("{} {} @ {}", trade.agg_id, trade.qty, trade.price);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use crate::errors::{Error, Result};
+use crate::futures::model::{Income, Order as FuturesOrder, TradeHistory as FuturesTradeHistory};
+use crate::model::{AggTrade, TradeHistory};
+use futures_util::{Stream, StreamExt, stream};
+use std::collections::HashSet;
+use std::future::Future;
+use std::hash::Hash;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const HOUR: u64 = 60 * 60 * 1000;
+pub const DAY: u64 = 24 * HOUR;
+
+/// A record of a paginated endpoint.
+pub trait Paged {
+    /// Identifies a record among those with the same time. For [`by_id`]
+    /// this is the id the next page starts after.
+    type Key: Eq + Hash;
+
+    fn key(&self) -> Self::Key;
+
+    /// Milliseconds since the epoch, as filtered by `startTime` and
+    /// `endTime`.
+    fn time(&self) -> u64;
+}
+
+/// One request for a page. Either `from_id` or the time range is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Page {
+    pub from_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: u16,
+}
+
+/// How to cover a range with one endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageLimits {
+    /// The widest `endTime - startTime` the endpoint accepts, in
+    /// milliseconds.
+    pub window: u64,
+    /// Records to ask for per page; a page this long may have more after it.
+    pub limit: u16,
+}
+
+impl PageLimits {
+    pub fn new(window: u64, limit: u16) -> Self {
+        Self { window, limit }
+    }
+}
+
+/// Records from `start_time` to `end_time` (inclusive), walking by id once a
+/// time window has more than one page. `fetch` sends one request.
+pub fn by_id<T, F, Fut>(
+    start_time: u64, end_time: u64, limits: PageLimits, fetch: F,
+) -> impl Stream<Item = Result<T>>
+where
+    T: Paged<Key = u64>,
+    F: FnMut(Page) -> Fut,
+    Fut: Future<Output = Result<Vec<T>>>,
+{
+    let state = IdWalk {
+        fetch,
+        limits,
+        next_start: start_time,
+        end_time,
+        from_id: None,
+        done: false,
+    };
+    flatten(stream::unfold(state, |mut state| async move {
+        let page = state.next_page().await;
+        page.map(|page| (page, state))
+    }))
+}
+
+/// Records from `start_time` to `end_time` (inclusive), walking by time
+/// only. For endpoints without an id to continue from.
+pub fn by_time<T, F, Fut>(
+    start_time: u64, end_time: u64, limits: PageLimits, fetch: F,
+) -> impl Stream<Item = Result<T>>
+where
+    T: Paged,
+    F: FnMut(Page) -> Fut,
+    Fut: Future<Output = Result<Vec<T>>>,
+{
+    let state = TimeWalk {
+        fetch,
+        limits,
+        next_start: start_time,
+        end_time,
+        seen_time: 0,
+        seen: HashSet::new(),
+        done: false,
+    };
+    flatten(stream::unfold(state, |mut state| async move {
+        let page = state.next_page().await;
+        page.map(|page| (page, state))
+    }))
+}
+
+/// `end_time`, or now.
+pub(crate) fn end_or_now(end_time: Option<u64>) -> u64 {
+    end_time.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default()
+    })
+}
+
+fn flatten<T>(pages: impl Stream<Item = Result<Vec<T>>>) -> impl Stream<Item = Result<T>> {
+    pages.flat_map(|page| {
+        stream::iter(match page {
+            Ok(records) => records.into_iter().map(Ok).collect::<Vec<_>>(),
+            Err(e) => vec![Err(e)],
+        })
+    })
+}
+
+struct IdWalk<F> {
+    fetch: F,
+    limits: PageLimits,
+    next_start: u64,
+    end_time: u64,
+    from_id: Option<u64>,
+    done: bool,
+}
+
+impl<F> IdWalk<F> {
+    async fn next_page<T, Fut>(&mut self) -> Option<Result<Vec<T>>>
+    where
+        T: Paged<Key = u64>,
+        F: FnMut(Page) -> Fut,
+        Fut: Future<Output = Result<Vec<T>>>,
+    {
+        let limit = self.limits.limit;
+        loop {
+            if self.done {
+                return None;
+            }
+            if let Some(from_id) = self.from_id {
+                let page = Page {
+                    from_id: Some(from_id),
+                    start_time: None,
+                    end_time: None,
+                    limit,
+                };
+                let mut records = match (self.fetch)(page).await {
+                    Ok(records) => records,
+                    Err(e) => return Some(self.fail(e)),
+                };
+                self.done = records.len() < usize::from(limit);
+                if let Some(last) = records.last() {
+                    self.from_id = Some(last.key() + 1);
+                }
+                if records.last().is_some_and(|r| r.time() > self.end_time) {
+                    records.retain(|r| r.time() <= self.end_time);
+                    self.done = true;
+                }
+                return Some(Ok(records));
+            }
+
+            if self.next_start > self.end_time {
+                return None;
+            }
+            let window_end = window_end(self.next_start, self.end_time, self.limits.window);
+            let page = Page {
+                from_id: None,
+                start_time: Some(self.next_start),
+                end_time: Some(window_end),
+                limit,
+            };
+            let records = match (self.fetch)(page).await {
+                Ok(records) => records,
+                Err(e) => return Some(self.fail(e)),
+            };
+            match records.last() {
+                Some(last) if records.len() >= usize::from(limit) => {
+                    self.from_id = Some(last.key() + 1);
+                }
+                _ => self.next_start = window_end + 1,
+            }
+            if !records.is_empty() {
+                return Some(Ok(records));
+            }
+        }
+    }
+
+    fn fail<T>(&mut self, error: Error) -> Result<Vec<T>> {
+        self.done = true;
+        Err(error)
+    }
+}
+
+struct TimeWalk<F, K> {
+    fetch: F,
+    limits: PageLimits,
+    next_start: u64,
+    end_time: u64,
+    // Keys of the records at `seen_time`, where the next page starts.
+    seen_time: u64,
+    seen: HashSet<K>,
+    done: bool,
+}
+
+impl<F, K: Eq + Hash> TimeWalk<F, K> {
+    async fn next_page<T, Fut>(&mut self) -> Option<Result<Vec<T>>>
+    where
+        T: Paged<Key = K>,
+        F: FnMut(Page) -> Fut,
+        Fut: Future<Output = Result<Vec<T>>>,
+    {
+        let limit = self.limits.limit;
+        loop {
+            if self.done || self.next_start > self.end_time {
+                return None;
+            }
+            let window_end = window_end(self.next_start, self.end_time, self.limits.window);
+            let page = Page {
+                from_id: None,
+                start_time: Some(self.next_start),
+                end_time: Some(window_end),
+                limit,
+            };
+            let mut records = match (self.fetch)(page).await {
+                Ok(records) => records,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            };
+
+            let full = records.len() >= usize::from(limit);
+            let last_time = records.last().map(Paged::time);
+            let seen_time = self.seen_time;
+            let seen = std::mem::take(&mut self.seen);
+            records.retain(|r| r.time() != seen_time || !seen.contains(&r.key()));
+
+            match last_time {
+                Some(last_time) if full => {
+                    if last_time <= self.next_start && records.is_empty() {
+                        self.done = true;
+                        return Some(Err(Error::Custom(format!(
+                            "more than {} records at {}, can't page by time",
+                            limit, last_time
+                        ))));
+                    }
+                    self.seen = if last_time == seen_time {
+                        seen
+                    } else {
+                        HashSet::new()
+                    };
+                    self.seen_time = last_time;
+                    self.seen.extend(
+                        records
+                            .iter()
+                            .filter(|r| r.time() == last_time)
+                            .map(Paged::key),
+                    );
+                    self.next_start = last_time;
+                }
+                _ => self.next_start = window_end + 1,
+            }
+            if !records.is_empty() {
+                return Some(Ok(records));
+            }
+        }
+    }
+}
+
+fn window_end(start: u64, end: u64, window: u64) -> u64 {
+    start.saturating_add(window.max(1) - 1).min(end)
+}
+
+impl Paged for AggTrade {
+    type Key = u64;
+
+    fn key(&self) -> u64 {
+        self.agg_id
+    }
+
+    fn time(&self) -> u64 {
+        self.time
+    }
+}
+
+impl Paged for TradeHistory {
+    type Key = u64;
+
+    fn key(&self) -> u64 {
+        self.id
+    }
+
+    fn time(&self) -> u64 {
+        self.time
+    }
+}
+
+impl Paged for FuturesTradeHistory {
+    type Key = u64;
+
+    fn key(&self) -> u64 {
+        self.id
+    }
+
+    fn time(&self) -> u64 {
+        self.time
+    }
+}
+
+impl Paged for FuturesOrder {
+    type Key = u64;
+
+    fn key(&self) -> u64 {
+        self.order_id
+    }
+
+    fn time(&self) -> u64 {
+        self.time
+    }
+}
+
+// One trade can book several incomes under the same transaction id.
+impl Paged for Income {
+    type Key = (u64, String, String);
+
+    fn key(&self) -> Self::Key {
+        (self.tran_id, self.income_type.clone(), self.asset.clone())
+    }
+
+    fn time(&self) -> u64 {
+        self.time
+    }
+}
@@ -0,0 +1,422 @@
+//! Client-side request weight and order-count governor.
+//!
+//! Binance bans an IP (HTTP 418) that keeps sending requests after being told
+//! to back off with HTTP 429. A [`RateLimiter`] counts the weight of every
+//! request and the orders placed in each rate-limit window, trusts the
+//! `X-MBX-USED-WEIGHT-*` and `X-MBX-ORDER-COUNT-*` response headers over its
+//! own estimate, and waits or fails locally before a limit would be crossed.
+//! A 429/418 response blocks all further requests until `Retry-After` has
+//! passed.
+//!
+//! Every service built from the same [`Config`] shares one limiter per API
+//! family. `Config::default()`, and so `Binance::new`, hands out the same
+//! process-wide limiters every time; a `Config` only needs its own with
+//! [`Config::set_rate_limiter`] for a different IP or account:
+//!
+//!```no_run
+//! use binance_rs_plus::api::Binance;
+//! use binance_rs_plus::account::Account;
+//! use binance_rs_plus::config::Config;
+//! use binance_rs_plus::general::General;
+//! use binance_rs_plus::market::Market;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     let config = Config::default();
+//!     let general: General = Binance::new_with_config(None, None, &config);
+//!     let market: Market = Binance::new_with_config(None, None, &config);
+//!     let account: Account =
+//!         Binance::new_with_config(Some("api_key".into()), Some("secret_key".into()), &config);
+//!
+//!     // Replaces the built-in limits with the ones the exchange reports.
+//!     general.exchange_info().await?;
+//!     market.get_price("BTCUSDT").await?;
+//!     account.get_account().await?;
+//!
+//!     for usage in config.rate_limiter.usage() {
+//!         println!("{:?}/{:?}: {} of {:?}", usage.kind, usage.interval, usage.used, usage.limit);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`Config`]: crate::config::Config
+
+use crate::errors::{Error, Result};
+use crate::model::RateLimit;
+use crate::time_sync::local_timestamp;
+use reqwest::header::HeaderMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+const USED_WEIGHT_HEADER: &str = "x-mbx-used-weight-";
+const ORDER_COUNT_HEADER: &str = "x-mbx-order-count-";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateLimitKind {
+    RequestWeight,
+    Orders,
+    RawRequests,
+}
+
+impl RateLimitKind {
+    fn from_binance(rate_limit_type: &str) -> Option<Self> {
+        match rate_limit_type {
+            "REQUEST_WEIGHT" => Some(RateLimitKind::RequestWeight),
+            "ORDERS" => Some(RateLimitKind::Orders),
+            "RAW_REQUESTS" => Some(RateLimitKind::RawRequests),
+            _ => None,
+        }
+    }
+}
+
+/// What to do when a request would cross a limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThrottleMode {
+    /// Sleep until the window resets, then send.
+    #[default]
+    Wait,
+    /// Fail with [`Error::RateLimited`] without sending.
+    Reject,
+}
+
+/// One limit, e.g. 6000 request weight per minute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limit {
+    pub kind: RateLimitKind,
+    pub interval: Duration,
+    pub limit: u64,
+}
+
+impl Limit {
+    pub fn new(kind: RateLimitKind, interval: Duration, limit: u64) -> Self {
+        Limit {
+            kind,
+            interval,
+            limit,
+        }
+    }
+}
+
+impl TryFrom<&RateLimit> for Limit {
+    type Error = Error;
+
+    fn try_from(rate_limit: &RateLimit) -> Result<Self> {
+        let kind = RateLimitKind::from_binance(&rate_limit.rate_limit_type).ok_or_else(|| {
+            Error::Custom(format!(
+                "Unknown rate limit type: {}",
+                rate_limit.rate_limit_type
+            ))
+        })?;
+        let unit = match rate_limit.interval.as_str() {
+            "SECOND" => 1,
+            "MINUTE" => 60,
+            "HOUR" => 3_600,
+            "DAY" => 86_400,
+            other => {
+                return Err(Error::Custom(format!(
+                    "Unknown rate limit interval: {}",
+                    other
+                )));
+            }
+        };
+        Ok(Limit::new(
+            kind,
+            Duration::from_secs(unit * u64::from(rate_limit.interval_num)),
+            rate_limit.limit,
+        ))
+    }
+}
+
+/// Usage of one window as last counted or reported by Binance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitUsage {
+    pub kind: RateLimitKind,
+    pub interval: Duration,
+    /// `None` when Binance reports a counter no limit is configured for.
+    pub limit: Option<u64>,
+    pub used: u64,
+}
+
+#[derive(Debug)]
+struct Counter {
+    kind: RateLimitKind,
+    interval: Duration,
+    limit: Option<u64>,
+    window: u64,
+    used: u64,
+}
+
+impl Counter {
+    fn new(kind: RateLimitKind, interval: Duration, limit: Option<u64>) -> Self {
+        Counter {
+            kind,
+            interval,
+            limit,
+            window: 0,
+            used: 0,
+        }
+    }
+
+    fn interval_ms(&self) -> u64 {
+        (self.interval.as_millis() as u64).max(1)
+    }
+
+    // Binance windows are aligned to the clock, so a new window starts at
+    // every multiple of the interval.
+    fn roll(&mut self, now_ms: u64) {
+        let window = now_ms / self.interval_ms();
+        if window != self.window {
+            self.window = window;
+            self.used = 0;
+        }
+    }
+
+    fn until_reset(&self, now_ms: u64) -> Duration {
+        Duration::from_millis(self.interval_ms() - now_ms % self.interval_ms())
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    counters: Vec<Counter>,
+    blocked_until: Option<Instant>,
+}
+
+impl State {
+    fn counter(&mut self, kind: RateLimitKind, interval: Duration) -> &mut Counter {
+        let index = self
+            .counters
+            .iter()
+            .position(|c| c.kind == kind && c.interval == interval);
+        let index = index.unwrap_or_else(|| {
+            self.counters.push(Counter::new(kind, interval, None));
+            self.counters.len() - 1
+        });
+        &mut self.counters[index]
+    }
+}
+
+/// Shared governor for one API family (spot or USD-M futures).
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    mode: ThrottleMode,
+    state: Mutex<State>,
+}
+
+impl RateLimiter {
+    pub fn new(limits: Vec<Limit>) -> Self {
+        let limiter = RateLimiter::default();
+        limiter.set_limits(limits);
+        limiter
+    }
+
+    /// Default spot limits: 6000 weight/min, 100 orders/10s, 200000 orders/day.
+    pub fn spot() -> Self {
+        RateLimiter::new(vec![
+            Limit::new(RateLimitKind::RequestWeight, Duration::from_secs(60), 6_000),
+            Limit::new(RateLimitKind::Orders, Duration::from_secs(10), 100),
+            Limit::new(RateLimitKind::Orders, Duration::from_secs(86_400), 200_000),
+            Limit::new(RateLimitKind::RawRequests, Duration::from_secs(300), 61_000),
+        ])
+    }
+
+    /// Default USD-M futures limits: 2400 weight/min, 300 orders/10s, 1200 orders/min.
+    pub fn futures() -> Self {
+        RateLimiter::new(vec![
+            Limit::new(RateLimitKind::RequestWeight, Duration::from_secs(60), 2_400),
+            Limit::new(RateLimitKind::Orders, Duration::from_secs(10), 300),
+            Limit::new(RateLimitKind::Orders, Duration::from_secs(60), 1_200),
+        ])
+    }
+
+    /// Default COIN-M futures limits: 2400 weight/min, 1200 orders/min.
+    pub fn coin_futures() -> Self {
+        RateLimiter::new(vec![
+            Limit::new(RateLimitKind::RequestWeight, Duration::from_secs(60), 2_400),
+            Limit::new(RateLimitKind::Orders, Duration::from_secs(60), 1_200),
+        ])
+    }
+
+    /// Default European options limits: 400 weight/min, 100 orders/10s, 1200 orders/min.
+    pub fn options() -> Self {
+        RateLimiter::new(vec![
+            Limit::new(RateLimitKind::RequestWeight, Duration::from_secs(60), 400),
+            Limit::new(RateLimitKind::Orders, Duration::from_secs(10), 100),
+            Limit::new(RateLimitKind::Orders, Duration::from_secs(60), 1_200),
+        ])
+    }
+
+    pub fn with_mode(mut self, mode: ThrottleMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn mode(&self) -> ThrottleMode {
+        self.mode
+    }
+
+    /// Replaces the configured limits, keeping what has been used so far.
+    pub fn set_limits(&self, limits: Vec<Limit>) {
+        let mut state = self.state.lock().unwrap();
+        for counter in state.counters.iter_mut() {
+            counter.limit = None;
+        }
+        for limit in limits {
+            state.counter(limit.kind, limit.interval).limit = Some(limit.limit);
+        }
+    }
+
+    /// Applies `ExchangeInformation.rate_limits`, skipping entries of unknown type.
+    pub fn set_exchange_limits(&self, rate_limits: &[RateLimit]) {
+        self.set_limits(
+            rate_limits
+                .iter()
+                .filter_map(|rate_limit| Limit::try_from(rate_limit).ok())
+                .collect(),
+        );
+    }
+
+    pub fn usage(&self) -> Vec<RateLimitUsage> {
+        let now_ms = local_timestamp();
+        let mut state = self.state.lock().unwrap();
+        state
+            .counters
+            .iter_mut()
+            .map(|counter| {
+                counter.roll(now_ms);
+                RateLimitUsage {
+                    kind: counter.kind,
+                    interval: counter.interval,
+                    limit: counter.limit,
+                    used: counter.used,
+                }
+            })
+            .collect()
+    }
+
+    /// Remaining time of a 429/418 ban, if one is in effect.
+    pub fn blocked_for(&self) -> Option<Duration> {
+        let state = self.state.lock().unwrap();
+        state
+            .blocked_until
+            .and_then(|until| until.checked_duration_since(Instant::now()))
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    /// Reserves `weight` (and one order if `order` is set) before a request,
+    /// waiting or failing according to the [`ThrottleMode`].
+    pub async fn acquire(&self, weight: u32, order: bool) -> Result<()> {
+        loop {
+            match self.try_acquire(weight, order) {
+                Ok(()) => return Ok(()),
+                Err(Error::RateLimited {
+                    retry_after,
+                    banned: false,
+                    ..
+                }) if self.mode == ThrottleMode::Wait => tokio::time::sleep(retry_after).await,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Reserves `weight` and an order without waiting.
+    pub fn try_acquire(&self, weight: u32, order: bool) -> Result<()> {
+        if let Some(retry_after) = self.blocked_for() {
+            return Err(Error::RateLimited {
+                reason: "IP is banned by Binance".to_string(),
+                retry_after,
+                banned: true,
+            });
+        }
+
+        let now_ms = local_timestamp();
+        let mut state = self.state.lock().unwrap();
+        for counter in state.counters.iter_mut() {
+            counter.roll(now_ms);
+            let cost = cost(counter.kind, weight, order);
+            if let Some(limit) = counter.limit {
+                // Waiting would never make room for it.
+                if cost > limit {
+                    return Err(Error::CostAboveLimit {
+                        kind: counter.kind,
+                        cost,
+                        limit,
+                        interval: counter.interval,
+                    });
+                }
+                if cost > 0 && counter.used + cost > limit {
+                    return Err(Error::RateLimited {
+                        reason: format!(
+                            "{:?} limit of {} per {:?} reached",
+                            counter.kind, limit, counter.interval
+                        ),
+                        retry_after: counter.until_reset(now_ms),
+                        banned: false,
+                    });
+                }
+            }
+        }
+        for counter in state.counters.iter_mut() {
+            counter.used += cost(counter.kind, weight, order);
+        }
+        Ok(())
+    }
+
+    /// Takes the used weight and order counts Binance reported as authoritative.
+    pub fn update_from_headers(&self, headers: &HeaderMap) {
+        let now_ms = local_timestamp();
+        let mut state = self.state.lock().unwrap();
+        for (name, value) in headers {
+            let name = name.as_str();
+            let (kind, interval) = if let Some(interval) = name.strip_prefix(USED_WEIGHT_HEADER) {
+                (RateLimitKind::RequestWeight, interval)
+            } else if let Some(interval) = name.strip_prefix(ORDER_COUNT_HEADER) {
+                (RateLimitKind::Orders, interval)
+            } else {
+                continue;
+            };
+            let (Some(interval), Some(used)) = (
+                parse_interval(interval),
+                value.to_str().ok().and_then(|v| v.parse::<u64>().ok()),
+            ) else {
+                continue;
+            };
+            let counter = state.counter(kind, interval);
+            counter.roll(now_ms);
+            counter.used = used;
+        }
+    }
+
+    /// Blocks every request for `retry_after` after a 429 or 418.
+    pub fn block_for(&self, retry_after: Duration) {
+        let until = Instant::now() + retry_after;
+        let mut state = self.state.lock().unwrap();
+        if state.blocked_until.is_none_or(|current| current < until) {
+            state.blocked_until = Some(until);
+        }
+    }
+}
+
+fn cost(kind: RateLimitKind, weight: u32, order: bool) -> u64 {
+    match kind {
+        RateLimitKind::RequestWeight => u64::from(weight),
+        RateLimitKind::Orders => u64::from(order),
+        RateLimitKind::RawRequests => 1,
+    }
+}
+
+// Header suffixes look like `1m`, `10s`, `1h` or `1d`.
+fn parse_interval(suffix: &str) -> Option<Duration> {
+    let split = suffix.len().checked_sub(1)?;
+    let (num, unit) = suffix.split_at(split);
+    let num: u64 = num.parse().ok()?;
+    let secs = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3_600,
+        "d" => 86_400,
+        _ => return None,
+    };
+    Some(Duration::from_secs(num * secs))
+}
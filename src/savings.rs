@@ -1,5 +1,7 @@
 use crate::util::build_signed_request;
-use crate::model::{AssetDetail, CoinInfo, DepositAddress, SpotFuturesTransferType, TransactionId};
+use crate::model::{
+    AssetDetail, CoinInfo, DepositAddress, Number, SpotFuturesTransferType, TransactionId,
+};
 use crate::client::Client;
 use crate::errors::Result; // Error struct is implicitly available via crate::errors::Error if needed
 use std::collections::BTreeMap;
@@ -65,7 +67,7 @@ impl Savings {
         // async added
         &self,
         asset: S,
-        amount: f64,
+        amount: Number,
         transfer_type: SpotFuturesTransferType,
     ) -> Result<TransactionId>
     where
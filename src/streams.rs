@@ -0,0 +1,401 @@
+//! Typed market stream names.
+//!
+//! [`Stream`] renders to the name Binance expects (`btcusdt@aggTrade`,
+//! `!ticker@arr`, `btcusdt@depth20@100ms`, ...) and is accepted by the
+//! `connect` methods of [`WebSockets`] and [`FuturesWebSockets`] wherever a
+//! stream name string is.
+//!
+//!```no_run
+//! use binance_rs_plus::streams::{DepthLevels, KlineInterval, Stream, UpdateSpeed};
+//! use binance_rs_plus::websockets::{WebSockets, WebsocketEvent};
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     let mut web_socket = WebSockets::new(|event: WebsocketEvent| {
+//!         Box::pin(async move {
+//!             println!("{:?}", event);
+//!             Ok(())
+//!         })
+//!     });
+//!     web_socket
+//!         .connect_multiple_streams(&[
+//!             Stream::kline("BTCUSDT", KlineInterval::Minutes1),
+//!             Stream::partial_depth("BTCUSDT", DepthLevels::Twenty, UpdateSpeed::Ms100),
+//!             Stream::AllTickers,
+//!         ])
+//!         .await?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`WebSockets`]: crate::websockets::WebSockets
+//! [`FuturesWebSockets`]: crate::futures::websockets::FuturesWebSockets
+
+use std::fmt::{self, Display};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum KlineInterval {
+    Seconds1,
+    Minutes1,
+    Minutes3,
+    Minutes5,
+    Minutes15,
+    Minutes30,
+    Hours1,
+    Hours2,
+    Hours4,
+    Hours6,
+    Hours8,
+    Hours12,
+    Days1,
+    Days3,
+    Weeks1,
+    Months1,
+}
+
+impl Display for KlineInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Seconds1 => write!(f, "1s"),
+            Self::Minutes1 => write!(f, "1m"),
+            Self::Minutes3 => write!(f, "3m"),
+            Self::Minutes5 => write!(f, "5m"),
+            Self::Minutes15 => write!(f, "15m"),
+            Self::Minutes30 => write!(f, "30m"),
+            Self::Hours1 => write!(f, "1h"),
+            Self::Hours2 => write!(f, "2h"),
+            Self::Hours4 => write!(f, "4h"),
+            Self::Hours6 => write!(f, "6h"),
+            Self::Hours8 => write!(f, "8h"),
+            Self::Hours12 => write!(f, "12h"),
+            Self::Days1 => write!(f, "1d"),
+            Self::Days3 => write!(f, "3d"),
+            Self::Weeks1 => write!(f, "1w"),
+            Self::Months1 => write!(f, "1M"),
+        }
+    }
+}
+
+/// Number of levels of a partial book depth stream.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum DepthLevels {
+    Five,
+    Ten,
+    Twenty,
+}
+
+impl Display for DepthLevels {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Five => write!(f, "5"),
+            Self::Ten => write!(f, "10"),
+            Self::Twenty => write!(f, "20"),
+        }
+    }
+}
+
+/// Push interval of depth streams. Spot supports 100ms and 1000ms (the
+/// default, named without a suffix); USD-M futures 100ms, 250ms (the default)
+/// and 500ms.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum UpdateSpeed {
+    Ms100,
+    Ms250,
+    Ms500,
+    Ms1000,
+}
+
+impl Display for UpdateSpeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ms100 => write!(f, "100ms"),
+            Self::Ms250 => write!(f, "250ms"),
+            Self::Ms500 => write!(f, "500ms"),
+            Self::Ms1000 => write!(f, "1000ms"),
+        }
+    }
+}
+
+/// Push interval of the futures mark price and index price streams.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, Default)]
+pub enum PriceSpeed {
+    Secs1,
+    #[default]
+    Secs3,
+}
+
+/// Rolling window of the spot window ticker streams.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum TickerWindow {
+    Hours1,
+    Hours4,
+    Days1,
+}
+
+impl Display for TickerWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hours1 => write!(f, "1h"),
+            Self::Hours4 => write!(f, "4h"),
+            Self::Days1 => write!(f, "1d"),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum ContractType {
+    Perpetual,
+    CurrentQuarter,
+    NextQuarter,
+}
+
+impl Display for ContractType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Perpetual => write!(f, "perpetual"),
+            Self::CurrentQuarter => write!(f, "current_quarter"),
+            Self::NextQuarter => write!(f, "next_quarter"),
+        }
+    }
+}
+
+/// A market data stream. Symbols may be given in any case.
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+pub enum Stream {
+    AggTrade {
+        symbol: String,
+    },
+    Trade {
+        symbol: String,
+    },
+    Kline {
+        symbol: String,
+        interval: KlineInterval,
+    },
+    /// Diff depth when `levels` is `None`, partial book depth otherwise.
+    /// `speed` of `None` uses the exchange default.
+    Depth {
+        symbol: String,
+        levels: Option<DepthLevels>,
+        speed: Option<UpdateSpeed>,
+    },
+    BookTicker {
+        symbol: String,
+    },
+    AllBookTickers,
+    MiniTicker {
+        symbol: String,
+    },
+    AllMiniTickers,
+    Ticker {
+        symbol: String,
+    },
+    AllTickers,
+    WindowTicker {
+        symbol: String,
+        window: TickerWindow,
+    },
+    AllWindowTickers {
+        window: TickerWindow,
+    },
+    /// Futures only.
+    MarkPrice {
+        symbol: String,
+        speed: PriceSpeed,
+    },
+    /// Futures only.
+    AllMarkPrices {
+        speed: PriceSpeed,
+    },
+    /// Futures only.
+    ContinuousKline {
+        pair: String,
+        contract_type: ContractType,
+        interval: KlineInterval,
+    },
+    /// COIN-M futures only.
+    IndexPrice {
+        pair: String,
+        speed: PriceSpeed,
+    },
+    /// Futures only.
+    ForceOrder {
+        symbol: String,
+    },
+    /// Futures only.
+    AllForceOrders,
+}
+
+impl Stream {
+    pub fn agg_trade<S: Into<String>>(symbol: S) -> Self {
+        Stream::AggTrade {
+            symbol: symbol.into(),
+        }
+    }
+
+    pub fn trade<S: Into<String>>(symbol: S) -> Self {
+        Stream::Trade {
+            symbol: symbol.into(),
+        }
+    }
+
+    pub fn kline<S: Into<String>>(symbol: S, interval: KlineInterval) -> Self {
+        Stream::Kline {
+            symbol: symbol.into(),
+            interval,
+        }
+    }
+
+    /// Diff depth stream at the given speed.
+    pub fn depth<S: Into<String>>(symbol: S, speed: UpdateSpeed) -> Self {
+        Stream::Depth {
+            symbol: symbol.into(),
+            levels: None,
+            speed: Some(speed),
+        }
+    }
+
+    pub fn partial_depth<S: Into<String>>(
+        symbol: S, levels: DepthLevels, speed: UpdateSpeed,
+    ) -> Self {
+        Stream::Depth {
+            symbol: symbol.into(),
+            levels: Some(levels),
+            speed: Some(speed),
+        }
+    }
+
+    pub fn book_ticker<S: Into<String>>(symbol: S) -> Self {
+        Stream::BookTicker {
+            symbol: symbol.into(),
+        }
+    }
+
+    pub fn mini_ticker<S: Into<String>>(symbol: S) -> Self {
+        Stream::MiniTicker {
+            symbol: symbol.into(),
+        }
+    }
+
+    pub fn ticker<S: Into<String>>(symbol: S) -> Self {
+        Stream::Ticker {
+            symbol: symbol.into(),
+        }
+    }
+
+    pub fn window_ticker<S: Into<String>>(symbol: S, window: TickerWindow) -> Self {
+        Stream::WindowTicker {
+            symbol: symbol.into(),
+            window,
+        }
+    }
+
+    pub fn mark_price<S: Into<String>>(symbol: S, speed: PriceSpeed) -> Self {
+        Stream::MarkPrice {
+            symbol: symbol.into(),
+            speed,
+        }
+    }
+
+    pub fn continuous_kline<S: Into<String>>(
+        pair: S, contract_type: ContractType, interval: KlineInterval,
+    ) -> Self {
+        Stream::ContinuousKline {
+            pair: pair.into(),
+            contract_type,
+            interval,
+        }
+    }
+
+    pub fn index_price<S: Into<String>>(pair: S, speed: PriceSpeed) -> Self {
+        Stream::IndexPrice {
+            pair: pair.into(),
+            speed,
+        }
+    }
+
+    pub fn force_order<S: Into<String>>(symbol: S) -> Self {
+        Stream::ForceOrder {
+            symbol: symbol.into(),
+        }
+    }
+}
+
+// Mark and index price streams name the 3s default by leaving the speed out.
+fn price_speed_suffix(speed: PriceSpeed) -> &'static str {
+    match speed {
+        PriceSpeed::Secs1 => "@1s",
+        PriceSpeed::Secs3 => "",
+    }
+}
+
+impl Display for Stream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AggTrade { symbol } => write!(f, "{}@aggTrade", symbol.to_lowercase()),
+            Self::Trade { symbol } => write!(f, "{}@trade", symbol.to_lowercase()),
+            Self::Kline { symbol, interval } => {
+                write!(f, "{}@kline_{}", symbol.to_lowercase(), interval)
+            }
+            Self::Depth {
+                symbol,
+                levels,
+                speed,
+            } => {
+                write!(f, "{}@depth", symbol.to_lowercase())?;
+                if let Some(levels) = levels {
+                    write!(f, "{}", levels)?;
+                }
+                // Spot names its 1000ms default by leaving the speed out.
+                if let Some(speed) = speed.filter(|s| *s != UpdateSpeed::Ms1000) {
+                    write!(f, "@{}", speed)?;
+                }
+                Ok(())
+            }
+            Self::BookTicker { symbol } => write!(f, "{}@bookTicker", symbol.to_lowercase()),
+            Self::AllBookTickers => write!(f, "!bookTicker"),
+            Self::MiniTicker { symbol } => write!(f, "{}@miniTicker", symbol.to_lowercase()),
+            Self::AllMiniTickers => write!(f, "!miniTicker@arr"),
+            Self::Ticker { symbol } => write!(f, "{}@ticker", symbol.to_lowercase()),
+            Self::AllTickers => write!(f, "!ticker@arr"),
+            Self::WindowTicker { symbol, window } => {
+                write!(f, "{}@ticker_{}", symbol.to_lowercase(), window)
+            }
+            Self::AllWindowTickers { window } => write!(f, "!ticker_{}@arr", window),
+            Self::MarkPrice { symbol, speed } => write!(
+                f,
+                "{}@markPrice{}",
+                symbol.to_lowercase(),
+                price_speed_suffix(*speed)
+            ),
+            Self::AllMarkPrices { speed } => {
+                write!(f, "!markPrice@arr{}", price_speed_suffix(*speed))
+            }
+            Self::ContinuousKline {
+                pair,
+                contract_type,
+                interval,
+            } => write!(
+                f,
+                "{}_{}@continuousKline_{}",
+                pair.to_lowercase(),
+                contract_type,
+                interval
+            ),
+            Self::IndexPrice { pair, speed } => write!(
+                f,
+                "{}@indexPrice{}",
+                pair.to_lowercase(),
+                price_speed_suffix(*speed)
+            ),
+            Self::ForceOrder { symbol } => write!(f, "{}@forceOrder", symbol.to_lowercase()),
+            Self::AllForceOrders => write!(f, "!forceOrder@arr"),
+        }
+    }
+}
+
+impl From<Stream> for String {
+    fn from(stream: Stream) -> Self {
+        stream.to_string()
+    }
+}
@@ -0,0 +1,454 @@
+//! Local checks against a symbol's exchange filters.
+//!
+//! Binance rejects orders that break a symbol's `PRICE_FILTER`, `LOT_SIZE`,
+//! `MARKET_LOT_SIZE`, `MIN_NOTIONAL`/`NOTIONAL` or `PERCENT_PRICE` filters
+//! with error -1013. [`SymbolRules`] parses those filters once, rounds prices
+//! and quantities onto the exchange grid with exact decimal arithmetic, and
+//! reports violations as [`FilterError`] before anything is sent.
+//!
+//!```no_run
+//! use binance_rs_plus::api::Binance;
+//! use binance_rs_plus::account::*;
+//! use binance_rs_plus::general::General;
+//! use binance_rs_plus::symbol_rules::SymbolRules;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     let general: General = Binance::new(None, None);
+//!     let rules = SymbolRules::try_from(&general.get_symbol_info("BNBBTC").await?)?;
+//!
+//!     let price = rules.round_price("0.0012345678".parse()?);
+//!     let qty = rules.round_qty("1.23456789".parse()?);
+//!
+//!     // Orders for BNBBTC placed through `account` are now checked locally.
+//!     let mut account: Account = Binance::new(Some("api_key".into()), Some("secret_key".into()));
+//!     account.set_symbol_rules(rules);
+//!     account.limit_buy("BNBBTC", qty, price).await?;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::errors::{Error, Result};
+use crate::coin_futures;
+use crate::futures;
+use crate::model::{Filters, Number, Symbol};
+use rust_decimal::Decimal;
+#[cfg(not(feature = "decimal"))]
+use rust_decimal::prelude::ToPrimitive;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceRule {
+    pub min_price: Decimal,
+    pub max_price: Decimal,
+    pub tick_size: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantityRule {
+    pub min_qty: Decimal,
+    pub max_qty: Decimal,
+    pub step_size: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotionalRule {
+    pub min_notional: Option<Decimal>,
+    pub max_notional: Option<Decimal>,
+    pub apply_to_market: bool,
+}
+
+/// Allowed price band around the reference (average or mark) price.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PercentPriceRule {
+    pub multiplier_up: Decimal,
+    pub multiplier_down: Decimal,
+}
+
+/// The trading filters of one symbol, parsed to exact decimals.
+///
+/// A zero bound (for example `maxPrice` of `"0"`) means the exchange does not
+/// enforce it, and is skipped here too.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SymbolRules {
+    pub symbol: String,
+    pub price: Option<PriceRule>,
+    pub lot_size: Option<QuantityRule>,
+    pub market_lot_size: Option<QuantityRule>,
+    pub notional: Option<NotionalRule>,
+    pub percent_price: Option<PercentPriceRule>,
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum FilterError {
+    #[error("{0} is not a valid decimal number")]
+    InvalidNumber(Number),
+
+    #[error("price {price} is below the minimum price {min}")]
+    PriceTooLow { price: Decimal, min: Decimal },
+
+    #[error("price {price} is above the maximum price {max}")]
+    PriceTooHigh { price: Decimal, max: Decimal },
+
+    #[error("price {price} is not a multiple of the tick size {tick_size}")]
+    PriceNotOnTick { price: Decimal, tick_size: Decimal },
+
+    #[error("quantity {qty} is below the minimum quantity {min}")]
+    QuantityTooLow { qty: Decimal, min: Decimal },
+
+    #[error("quantity {qty} is above the maximum quantity {max}")]
+    QuantityTooHigh { qty: Decimal, max: Decimal },
+
+    #[error("quantity {qty} is not a multiple of the step size {step_size}")]
+    QuantityNotOnStep { qty: Decimal, step_size: Decimal },
+
+    #[error("notional {notional} is below the minimum notional {min}")]
+    NotionalTooLow { notional: Decimal, min: Decimal },
+
+    #[error("notional {notional} is above the maximum notional {max}")]
+    NotionalTooHigh { notional: Decimal, max: Decimal },
+
+    #[error("price {price} is outside the allowed band {low} - {high}")]
+    PriceOutsideBand {
+        price: Decimal,
+        low: Decimal,
+        high: Decimal,
+    },
+}
+
+impl SymbolRules {
+    pub fn from_filters<S: Into<String>>(symbol: S, filters: &[Filters]) -> Result<Self> {
+        let mut rules = SymbolRules {
+            symbol: symbol.into(),
+            ..Default::default()
+        };
+
+        for filter in filters {
+            match filter {
+                Filters::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => {
+                    rules.price = Some(PriceRule {
+                        min_price: parse(min_price)?,
+                        max_price: parse(max_price)?,
+                        tick_size: parse(tick_size)?,
+                    });
+                }
+                Filters::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    rules.lot_size = Some(QuantityRule {
+                        min_qty: parse(min_qty)?,
+                        max_qty: parse(max_qty)?,
+                        step_size: parse(step_size)?,
+                    });
+                }
+                Filters::MarketLotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    rules.market_lot_size = Some(QuantityRule {
+                        min_qty: parse(min_qty)?,
+                        max_qty: parse(max_qty)?,
+                        step_size: parse(step_size)?,
+                    });
+                }
+                Filters::MinNotional {
+                    notional,
+                    min_notional,
+                    apply_to_market,
+                    ..
+                } => {
+                    // Spot sends `minNotional`, USD-M futures send `notional`.
+                    rules.notional = Some(NotionalRule {
+                        min_notional: min_notional
+                            .as_ref()
+                            .or(notional.as_ref())
+                            .map(|v| parse(v))
+                            .transpose()?,
+                        max_notional: None,
+                        apply_to_market: apply_to_market.unwrap_or(true),
+                    });
+                }
+                Filters::Notional {
+                    notional,
+                    min_notional,
+                    apply_to_market,
+                    apply_min_to_market,
+                    max_notional,
+                    ..
+                } => {
+                    rules.notional = Some(NotionalRule {
+                        min_notional: min_notional
+                            .as_ref()
+                            .or(notional.as_ref())
+                            .map(|v| parse(v))
+                            .transpose()?,
+                        max_notional: max_notional.as_ref().map(|v| parse(v)).transpose()?,
+                        apply_to_market: apply_min_to_market.or(*apply_to_market).unwrap_or(true),
+                    });
+                }
+                Filters::PercentPrice {
+                    multiplier_up,
+                    multiplier_down,
+                    ..
+                } => {
+                    rules.percent_price = Some(PercentPriceRule {
+                        multiplier_up: parse(multiplier_up)?,
+                        multiplier_down: parse(multiplier_down)?,
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        Ok(rules)
+    }
+
+    /// Round a price to the nearest valid tick.
+    pub fn round_price(&self, price: Number) -> Number {
+        match (&self.price, to_decimal(price)) {
+            (Some(rule), Ok(p)) => to_number(round_to_step(
+                p,
+                rule.min_price,
+                rule.tick_size,
+                Rounding::Nearest,
+            )),
+            _ => price,
+        }
+    }
+
+    /// Round a quantity down to the `LOT_SIZE` step, so the order never
+    /// exceeds the amount asked for.
+    pub fn round_qty(&self, qty: Number) -> Number {
+        round_qty_with(self.lot_size.as_ref(), qty)
+    }
+
+    /// Round a MARKET order quantity down to the `MARKET_LOT_SIZE` step,
+    /// falling back to `LOT_SIZE`.
+    pub fn round_market_qty(&self, qty: Number) -> Number {
+        round_qty_with(
+            self.market_lot_size.as_ref().or(self.lot_size.as_ref()),
+            qty,
+        )
+    }
+
+    pub fn check_price(&self, price: Number) -> std::result::Result<(), FilterError> {
+        let price = to_decimal(price)?;
+        let Some(rule) = &self.price else {
+            return Ok(());
+        };
+        if !rule.min_price.is_zero() && price < rule.min_price {
+            return Err(FilterError::PriceTooLow {
+                price,
+                min: rule.min_price,
+            });
+        }
+        if !rule.max_price.is_zero() && price > rule.max_price {
+            return Err(FilterError::PriceTooHigh {
+                price,
+                max: rule.max_price,
+            });
+        }
+        if !rule.tick_size.is_zero() && !((price - rule.min_price) % rule.tick_size).is_zero() {
+            return Err(FilterError::PriceNotOnTick {
+                price,
+                tick_size: rule.tick_size,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn check_qty(&self, qty: Number, is_market: bool) -> std::result::Result<(), FilterError> {
+        let qty = to_decimal(qty)?;
+        let rules = if is_market {
+            [self.lot_size.as_ref(), self.market_lot_size.as_ref()]
+        } else {
+            [self.lot_size.as_ref(), None]
+        };
+        for rule in rules.into_iter().flatten() {
+            if qty < rule.min_qty {
+                return Err(FilterError::QuantityTooLow {
+                    qty,
+                    min: rule.min_qty,
+                });
+            }
+            if !rule.max_qty.is_zero() && qty > rule.max_qty {
+                return Err(FilterError::QuantityTooHigh {
+                    qty,
+                    max: rule.max_qty,
+                });
+            }
+            if !rule.step_size.is_zero() && !((qty - rule.min_qty) % rule.step_size).is_zero() {
+                return Err(FilterError::QuantityNotOnStep {
+                    qty,
+                    step_size: rule.step_size,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Check `price * qty` against the notional filter. For MARKET orders
+    /// `price` is the expected fill price, and the check only runs when the
+    /// filter applies to market orders.
+    pub fn check_notional(
+        &self, price: Number, qty: Number, is_market: bool,
+    ) -> std::result::Result<(), FilterError> {
+        self.check_quote_notional(to_decimal(price)? * to_decimal(qty)?, is_market)
+    }
+
+    fn check_quote_notional(
+        &self, notional: Decimal, is_market: bool,
+    ) -> std::result::Result<(), FilterError> {
+        let Some(rule) = &self.notional else {
+            return Ok(());
+        };
+        if is_market && !rule.apply_to_market {
+            return Ok(());
+        }
+        if let Some(min) = rule.min_notional {
+            if notional < min {
+                return Err(FilterError::NotionalTooLow { notional, min });
+            }
+        }
+        if let Some(max) = rule.max_notional {
+            if !max.is_zero() && notional > max {
+                return Err(FilterError::NotionalTooHigh { notional, max });
+            }
+        }
+        Ok(())
+    }
+
+    /// Check a price against the `PERCENT_PRICE` band around
+    /// `reference_price`, the symbol's average price (spot) or mark price
+    /// (futures).
+    pub fn check_percent_price(
+        &self, price: Number, reference_price: Number,
+    ) -> std::result::Result<(), FilterError> {
+        let Some(rule) = &self.percent_price else {
+            return Ok(());
+        };
+        let price = to_decimal(price)?;
+        let reference = to_decimal(reference_price)?;
+        let low = reference * rule.multiplier_down;
+        let high = reference * rule.multiplier_up;
+        if price < low || price > high {
+            return Err(FilterError::PriceOutsideBand { price, low, high });
+        }
+        Ok(())
+    }
+
+    /// Check an order's price, quantity and notional in one go.
+    ///
+    /// `price` is the limit price, or `None` for MARKET orders. The
+    /// percent-price band needs a live reference price and is not part of
+    /// this check; see [`SymbolRules::check_percent_price`].
+    pub fn validate(
+        &self, price: Option<Number>, qty: Option<Number>, is_market: bool,
+    ) -> std::result::Result<(), FilterError> {
+        if let Some(price) = price {
+            self.check_price(price)?;
+        }
+        if let Some(qty) = qty {
+            self.check_qty(qty, is_market)?;
+        }
+        if let (Some(price), Some(qty)) = (price, qty) {
+            self.check_notional(price, qty, is_market)?;
+        }
+        Ok(())
+    }
+
+    /// Check the quote amount of a MARKET order placed with `quoteOrderQty`.
+    pub fn check_quote_qty(&self, quote_qty: Number) -> std::result::Result<(), FilterError> {
+        self.check_quote_notional(to_decimal(quote_qty)?, true)
+    }
+}
+
+impl TryFrom<&Symbol> for SymbolRules {
+    type Error = Error;
+
+    fn try_from(symbol: &Symbol) -> Result<Self> {
+        SymbolRules::from_filters(symbol.symbol.clone(), &symbol.filters)
+    }
+}
+
+impl TryFrom<&futures::model::Symbol> for SymbolRules {
+    type Error = Error;
+
+    fn try_from(symbol: &futures::model::Symbol) -> Result<Self> {
+        SymbolRules::from_filters(symbol.symbol.clone(), &symbol.filters)
+    }
+}
+
+impl TryFrom<&coin_futures::model::Symbol> for SymbolRules {
+    type Error = Error;
+
+    fn try_from(symbol: &coin_futures::model::Symbol) -> Result<Self> {
+        SymbolRules::from_filters(symbol.symbol.clone(), &symbol.filters)
+    }
+}
+
+enum Rounding {
+    Nearest,
+    Down,
+}
+
+fn round_to_step(value: Decimal, min: Decimal, step: Decimal, rounding: Rounding) -> Decimal {
+    if step.is_zero() {
+        return value;
+    }
+    let steps = (value - min) / step;
+    let steps = match rounding {
+        Rounding::Nearest => steps.round(),
+        Rounding::Down => steps.floor(),
+    };
+    (min + steps * step).normalize()
+}
+
+fn round_qty_with(rule: Option<&QuantityRule>, qty: Number) -> Number {
+    match (rule, to_decimal(qty)) {
+        (Some(rule), Ok(q)) => to_number(round_to_step(
+            q,
+            rule.min_qty,
+            rule.step_size,
+            Rounding::Down,
+        )),
+        _ => qty,
+    }
+}
+
+fn parse(value: &str) -> Result<Decimal> {
+    Decimal::from_str(value)
+        .map_err(|e| Error::Custom(format!("Invalid filter value {}: {}", value, e)))
+}
+
+// `f64` formats to its shortest round-trip representation, which is the
+// decimal the caller wrote, not the binary approximation.
+#[cfg(not(feature = "decimal"))]
+fn to_decimal(value: Number) -> std::result::Result<Decimal, FilterError> {
+    if !value.is_finite() {
+        return Err(FilterError::InvalidNumber(value));
+    }
+    Decimal::from_str(&value.to_string()).map_err(|_| FilterError::InvalidNumber(value))
+}
+
+#[cfg(feature = "decimal")]
+fn to_decimal(value: Number) -> std::result::Result<Decimal, FilterError> {
+    Ok(value)
+}
+
+#[cfg(not(feature = "decimal"))]
+fn to_number(value: Decimal) -> Number {
+    value.to_f64().unwrap_or(f64::NAN)
+}
+
+#[cfg(feature = "decimal")]
+fn to_number(value: Decimal) -> Number {
+    value
+}
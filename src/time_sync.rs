@@ -0,0 +1,126 @@
+//! Server clock offset applied to signed requests.
+//!
+//! Binance rejects signed requests whose `timestamp` is outside `recvWindow`
+//! of its own clock with error -1021. Every REST client keeps a [`TimeSync`]
+//! with the last measured offset between the local clock and
+//! `GET /api/v3/time` (or `/fapi/v1/time` for futures) and stamps signed
+//! requests with the corrected time once an offset is known.
+//!
+//! The offset is measured when a request fails with -1021 (the request is then
+//! retried once), when `client.sync_time()` is called, and before a signed
+//! request whenever it is older than [`Config::time_sync_interval`].
+//!
+//!```no_run
+//! use binance_rs_plus::api::Binance;
+//! use binance_rs_plus::account::Account;
+//! use binance_rs_plus::config::Config;
+//! use std::time::Duration;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     let config = Config::default().set_time_sync_interval(Duration::from_secs(60));
+//!     let account: Account =
+//!         Binance::new_with_config(Some("api_key".into()), Some("secret_key".into()), &config);
+//!
+//!     account.get_account().await?;
+//!     if let Some(offset) = account.client.time_offset() {
+//!         println!("offset: {}ms, round trip: {:?}", offset.offset_ms, offset.round_trip);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`Config::time_sync_interval`]: crate::config::Config::time_sync_interval
+
+use std::sync::RwLock;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Difference between the server clock and the local clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TimeOffset {
+    /// Server time minus local time, in milliseconds.
+    pub offset_ms: i64,
+    /// Round trip of the server time request the offset was measured with.
+    pub round_trip: Duration,
+}
+
+impl TimeOffset {
+    /// Estimates the offset from the local time just before sending a server
+    /// time request, the server time it returned and the local time the
+    /// response arrived, assuming the server answered halfway through.
+    pub fn measure(sent_ms: u64, server_time_ms: u64, received_ms: u64) -> Self {
+        let round_trip_ms = received_ms.saturating_sub(sent_ms);
+        let local_ms = sent_ms + round_trip_ms / 2;
+        TimeOffset {
+            offset_ms: server_time_ms as i64 - local_ms as i64,
+            round_trip: Duration::from_millis(round_trip_ms),
+        }
+    }
+
+    /// Shifts a local millisecond timestamp onto the server clock.
+    pub fn apply(&self, local_ms: u64) -> u64 {
+        local_ms.saturating_add_signed(self.offset_ms)
+    }
+}
+
+/// The offset shared by a client and its clones.
+#[derive(Debug, Default)]
+pub struct TimeSync {
+    refresh_interval: Option<Duration>,
+    last: RwLock<Option<(TimeOffset, Instant)>>,
+}
+
+impl TimeSync {
+    /// `refresh_interval` of `None` only measures on demand and after -1021.
+    pub fn new(refresh_interval: Option<Duration>) -> Self {
+        TimeSync {
+            refresh_interval,
+            last: RwLock::new(None),
+        }
+    }
+
+    pub fn refresh_interval(&self) -> Option<Duration> {
+        self.refresh_interval
+    }
+
+    /// The last measured offset, if any.
+    pub fn offset(&self) -> Option<TimeOffset> {
+        self.last.read().unwrap().map(|(offset, _)| offset)
+    }
+
+    /// When the last offset was measured.
+    pub fn last_sync(&self) -> Option<Instant> {
+        self.last.read().unwrap().map(|(_, at)| at)
+    }
+
+    pub fn update(&self, offset: TimeOffset) {
+        *self.last.write().unwrap() = Some((offset, Instant::now()));
+    }
+
+    /// Whether a periodic refresh is due.
+    pub fn is_stale(&self) -> bool {
+        match (self.refresh_interval, self.last_sync()) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(interval), Some(at)) => at.elapsed() >= interval,
+        }
+    }
+
+    /// Current time in milliseconds on the server clock, or the local clock
+    /// while no offset is known.
+    pub fn timestamp(&self) -> u64 {
+        let local = local_timestamp();
+        match self.offset() {
+            Some(offset) => offset.apply(local),
+            None => local,
+        }
+    }
+}
+
+/// Local time in milliseconds since the Unix epoch.
+pub fn local_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|since_epoch| since_epoch.as_millis() as u64)
+        .unwrap_or_default()
+}
@@ -0,0 +1,465 @@
+//! Managed user data streams.
+//!
+//! [`UserDataStreamManager`] takes care of the listen key behind a user data
+//! stream. It creates the key and connects to its websocket, then keeps the
+//! key alive every [`keep_alive_interval`]. When the key expires, a keepalive
+//! fails or the connection drops, it asks for a key again and reconnects,
+//! backing off between attempts as the [`ReconnectPolicy`] says. On
+//! [`shutdown`](UserDataStreamManager::shutdown) it closes the key.
+//!
+//! Binance hands the same spot or margin listen key to every caller while it
+//! is valid, so other consumers of the account may share it. The key is
+//! therefore only closed once it has expired or on shutdown, never because
+//! of a dropped connection.
+//!
+//! Only account, balance and order events are delivered, as a
+//! [`Stream`](futures_util::Stream) of [`UserDataEvent`] for spot (from a
+//! [`UserStream`]), [`FuturesUserDataEvent`] for USD-M futures (from a
+//! [`FuturesUserStream`]) or [`OptionsUserDataEvent`] for options (from an
+//! [`OptionsUserStream`]). If the stream cannot be re-established, the error
+//! is the last item.
+//!
+//!```no_run
+//! use binance_rs_plus::api::Binance;
+//! use binance_rs_plus::config::Config;
+//! use binance_rs_plus::user_data_stream::{UserDataEvent, UserDataStreamConfig, UserDataStreamManager};
+//! use binance_rs_plus::userstream::UserStream;
+//! use futures_util::StreamExt;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     let config = Config::default();
+//!     let user_stream: UserStream = Binance::new_with_config(Some("api_key".into()), None, &config);
+//!     let (manager, mut events) =
+//!         UserDataStreamManager::start(user_stream, &config, UserDataStreamConfig::default()).await?;
+//!
+//!     while let Some(event) = events.next().await {
+//!         match event? {
+//!             UserDataEvent::OrderTrade(order) => println!("{} {}", order.symbol, order.order_status),
+//!             other => println!("{:?}", other),
+//!         }
+//!     }
+//!     manager.shutdown().await?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`keep_alive_interval`]: UserDataStreamConfig::keep_alive_interval
+
+use crate::async_websocket_client::{ReconnectPolicy, StreamEvent};
+use crate::config::Config;
+use crate::errors::{Error, Result};
+use crate::event_channel::{ChannelConfig, EventReceiver, EventSender, event_channel};
+use crate::futures::model as futures_model;
+use crate::futures::userstream::FuturesUserStream;
+use crate::futures::websockets::{FuturesWebSockets, FuturesWebsocketEvent};
+use crate::options::model as options_model;
+use crate::options::userstream::OptionsUserStream;
+use crate::options::websockets::{OptionsWebSockets, OptionsWebsocketEvent};
+use crate::model::{AccountPositionEvent, AccountUpdateEvent, BalanceUpdateEvent, OrderTradeEvent};
+use crate::userstream::UserStream;
+use crate::websockets::{WebSockets, WebsocketEvent};
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::task::{AbortHandle, JoinHandle};
+
+/// Spot user data events.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum UserDataEvent {
+    AccountPosition(AccountPositionEvent),
+    BalanceUpdate(BalanceUpdateEvent),
+    OrderTrade(OrderTradeEvent),
+}
+
+/// USD-M futures user data events.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum FuturesUserDataEvent {
+    AccountUpdate(AccountUpdateEvent),
+    OrderTrade(futures_model::OrderTradeEvent),
+    MarginCall(futures_model::MarginCallEvent),
+    AccountConfigUpdate(futures_model::AccountConfigUpdateEvent),
+    TradeLite(futures_model::TradeLiteEvent),
+    GridUpdate(futures_model::GridUpdateEvent),
+    StrategyUpdate(futures_model::StrategyUpdateEvent),
+    ConditionalOrderTriggerReject(futures_model::ConditionalOrderTriggerRejectEvent),
+}
+
+/// Options user data events.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum OptionsUserDataEvent {
+    AccountUpdate(options_model::AccountUpdateEvent),
+    OrderTrade(options_model::OrderTradeEvent),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserDataStreamConfig {
+    /// Binance expires a listen key 60 minutes after it was created or last
+    /// kept alive.
+    pub keep_alive_interval: Duration,
+    /// Backoff between attempts to get a new listen key and reconnect.
+    pub reconnect_policy: ReconnectPolicy,
+    pub channel: ChannelConfig,
+}
+
+impl Default for UserDataStreamConfig {
+    fn default() -> Self {
+        Self {
+            keep_alive_interval: Duration::from_secs(30 * 60),
+            reconnect_policy: ReconnectPolicy::default(),
+            channel: ChannelConfig::default(),
+        }
+    }
+}
+
+impl UserDataStreamConfig {
+    pub fn set_keep_alive_interval(mut self, keep_alive_interval: Duration) -> Self {
+        self.keep_alive_interval = keep_alive_interval;
+        self
+    }
+
+    pub fn set_reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = reconnect_policy;
+        self
+    }
+
+    pub fn set_channel(mut self, channel: ChannelConfig) -> Self {
+        self.channel = channel;
+        self
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::userstream::UserStream {}
+    impl Sealed for crate::futures::userstream::FuturesUserStream {}
+    impl Sealed for crate::options::userstream::OptionsUserStream {}
+}
+
+// A spawned websocket event loop and its events.
+type Socket<E> = (AbortHandle, EventReceiver<Result<StreamEvent<E>>>);
+
+// Why the session needs a new socket.
+enum Reconnect {
+    // The listen key expired; close it before asking for another.
+    Expired,
+    // The connection or a keepalive failed; the key may still be valid.
+    Dropped,
+}
+
+// What the manager does with an event read from the socket.
+#[doc(hidden)]
+pub enum Received<E> {
+    Deliver(E),
+    Expired,
+    Ignore,
+}
+
+/// The listen key REST calls and websocket of one market: [`UserStream`]
+/// or [`FuturesUserStream`].
+pub trait UserDataSource: private::Sealed + Clone + Send + Sync + 'static {
+    type Event: Send + 'static;
+    #[doc(hidden)]
+    type SocketEvent: Send + 'static;
+
+    #[doc(hidden)]
+    fn create_listen_key(&self) -> impl Future<Output = Result<String>> + Send;
+    #[doc(hidden)]
+    fn keep_alive_listen_key(&self, listen_key: &str) -> impl Future<Output = Result<()>> + Send;
+    #[doc(hidden)]
+    fn close_listen_key(&self, listen_key: &str) -> impl Future<Output = Result<()>> + Send;
+    /// Connects to the listen key's stream and runs its event loop on a task.
+    #[doc(hidden)]
+    fn connect(
+        &self, config: &Config, listen_key: &str,
+    ) -> impl Future<Output = Result<Socket<Self::SocketEvent>>> + Send;
+    #[doc(hidden)]
+    fn receive(event: Self::SocketEvent) -> Received<Self::Event>;
+}
+
+impl UserDataSource for UserStream {
+    type Event = UserDataEvent;
+    type SocketEvent = WebsocketEvent;
+
+    async fn create_listen_key(&self) -> Result<String> {
+        Ok(self.start().await?.listen_key)
+    }
+
+    async fn keep_alive_listen_key(&self, listen_key: &str) -> Result<()> {
+        self.keep_alive(listen_key).await.map(|_| ())
+    }
+
+    async fn close_listen_key(&self, listen_key: &str) -> Result<()> {
+        self.close(listen_key).await.map(|_| ())
+    }
+
+    async fn connect(&self, config: &Config, listen_key: &str) -> Result<Socket<WebsocketEvent>> {
+        let (mut web_socket, events) = WebSockets::channel(ChannelConfig::default());
+        web_socket.connect_with_config(listen_key, config).await?;
+        Ok((web_socket.spawn().abort_handle(), events))
+    }
+
+    fn receive(event: WebsocketEvent) -> Received<UserDataEvent> {
+        match event {
+            WebsocketEvent::AccountPosition(v) => {
+                Received::Deliver(UserDataEvent::AccountPosition(v))
+            }
+            WebsocketEvent::BalanceUpdate(v) => Received::Deliver(UserDataEvent::BalanceUpdate(v)),
+            WebsocketEvent::OrderTrade(v) => Received::Deliver(UserDataEvent::OrderTrade(v)),
+            WebsocketEvent::UserDataStreamExpiredEvent(_) => Received::Expired,
+            _ => Received::Ignore,
+        }
+    }
+}
+
+impl UserDataSource for FuturesUserStream {
+    type Event = FuturesUserDataEvent;
+    type SocketEvent = FuturesWebsocketEvent;
+
+    async fn create_listen_key(&self) -> Result<String> {
+        Ok(self.start().await?.listen_key)
+    }
+
+    async fn keep_alive_listen_key(&self, listen_key: &str) -> Result<()> {
+        self.keep_alive(listen_key).await.map(|_| ())
+    }
+
+    async fn close_listen_key(&self, listen_key: &str) -> Result<()> {
+        self.close(listen_key).await.map(|_| ())
+    }
+
+    async fn connect(
+        &self, config: &Config, listen_key: &str,
+    ) -> Result<Socket<FuturesWebsocketEvent>> {
+        let (mut web_socket, events) = FuturesWebSockets::channel(ChannelConfig::default());
+        web_socket
+            .connect_url(&format!("{}/{}", config.futures_ws_endpoint, listen_key))
+            .await?;
+        Ok((web_socket.spawn().abort_handle(), events))
+    }
+
+    fn receive(event: FuturesWebsocketEvent) -> Received<FuturesUserDataEvent> {
+        match event {
+            FuturesWebsocketEvent::AccountUpdate(v) => {
+                Received::Deliver(FuturesUserDataEvent::AccountUpdate(v))
+            }
+            FuturesWebsocketEvent::OrderTrade(v) => {
+                Received::Deliver(FuturesUserDataEvent::OrderTrade(v))
+            }
+            FuturesWebsocketEvent::MarginCall(v) => {
+                Received::Deliver(FuturesUserDataEvent::MarginCall(v))
+            }
+            FuturesWebsocketEvent::AccountConfigUpdate(v) => {
+                Received::Deliver(FuturesUserDataEvent::AccountConfigUpdate(v))
+            }
+            FuturesWebsocketEvent::TradeLite(v) => {
+                Received::Deliver(FuturesUserDataEvent::TradeLite(v))
+            }
+            FuturesWebsocketEvent::GridUpdate(v) => {
+                Received::Deliver(FuturesUserDataEvent::GridUpdate(v))
+            }
+            FuturesWebsocketEvent::StrategyUpdate(v) => {
+                Received::Deliver(FuturesUserDataEvent::StrategyUpdate(v))
+            }
+            FuturesWebsocketEvent::ConditionalOrderTriggerReject(v) => {
+                Received::Deliver(FuturesUserDataEvent::ConditionalOrderTriggerReject(v))
+            }
+            FuturesWebsocketEvent::UserDataStreamExpiredEvent(_) => Received::Expired,
+            _ => Received::Ignore,
+        }
+    }
+}
+
+impl UserDataSource for OptionsUserStream {
+    type Event = OptionsUserDataEvent;
+    type SocketEvent = OptionsWebsocketEvent;
+
+    async fn create_listen_key(&self) -> Result<String> {
+        Ok(self.start().await?.listen_key)
+    }
+
+    async fn keep_alive_listen_key(&self, listen_key: &str) -> Result<()> {
+        self.keep_alive(listen_key).await.map(|_| ())
+    }
+
+    async fn close_listen_key(&self, listen_key: &str) -> Result<()> {
+        self.close(listen_key).await.map(|_| ())
+    }
+
+    async fn connect(
+        &self, config: &Config, listen_key: &str,
+    ) -> Result<Socket<OptionsWebsocketEvent>> {
+        let (mut web_socket, events) = OptionsWebSockets::channel(ChannelConfig::default());
+        web_socket
+            .connect_url(&format!("{}/{}", config.options_ws_endpoint, listen_key))
+            .await?;
+        Ok((web_socket.spawn().abort_handle(), events))
+    }
+
+    fn receive(event: OptionsWebsocketEvent) -> Received<OptionsUserDataEvent> {
+        match event {
+            OptionsWebsocketEvent::AccountUpdate(v) => {
+                Received::Deliver(OptionsUserDataEvent::AccountUpdate(v))
+            }
+            OptionsWebsocketEvent::OrderTrade(v) => {
+                Received::Deliver(OptionsUserDataEvent::OrderTrade(v))
+            }
+            OptionsWebsocketEvent::UserDataStreamExpiredEvent(_) => Received::Expired,
+            _ => Received::Ignore,
+        }
+    }
+}
+
+/// A running user data stream; see the [module documentation](self).
+pub struct UserDataStreamManager {
+    listen_key: Arc<RwLock<String>>,
+    shutdown: Arc<Notify>,
+    task: JoinHandle<Result<()>>,
+}
+
+impl UserDataStreamManager {
+    /// Creates a listen key, connects to it and starts keeping it alive. The
+    /// stream keeps running when the manager is dropped, until the receiver
+    /// is dropped too.
+    pub async fn start<S: UserDataSource>(
+        source: S, config: &Config, options: UserDataStreamConfig,
+    ) -> Result<(UserDataStreamManager, EventReceiver<Result<S::Event>>)> {
+        let listen_key = source.create_listen_key().await?;
+        let socket = source.connect(config, &listen_key).await?;
+        let (sender, receiver) = event_channel(options.channel);
+        let session = Session {
+            source,
+            config: config.clone(),
+            options,
+            listen_key: Arc::new(RwLock::new(listen_key)),
+            shutdown: Arc::new(Notify::new()),
+            events: sender,
+        };
+        let manager = UserDataStreamManager {
+            listen_key: session.listen_key.clone(),
+            shutdown: session.shutdown.clone(),
+            task: tokio::spawn(session.run(socket)),
+        };
+        Ok((manager, receiver))
+    }
+
+    /// The listen key in use.
+    pub fn listen_key(&self) -> String {
+        self.listen_key.read().unwrap().clone()
+    }
+
+    /// Stops the stream and closes the listen key.
+    pub async fn shutdown(self) -> Result<()> {
+        self.shutdown.notify_one();
+        self.task
+            .await
+            .map_err(|e| Error::Custom(format!("User data stream task failed: {}", e)))?
+    }
+}
+
+struct Session<S: UserDataSource> {
+    source: S,
+    config: Config,
+    options: UserDataStreamConfig,
+    listen_key: Arc<RwLock<String>>,
+    shutdown: Arc<Notify>,
+    events: EventSender<Result<S::Event>>,
+}
+
+impl<S: UserDataSource> Session<S> {
+    async fn run(self, mut socket: Socket<S::SocketEvent>) -> Result<()> {
+        let interval = self.options.keep_alive_interval;
+        let mut keep_alive =
+            tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+        loop {
+            let listen_key = self.listen_key.read().unwrap().clone();
+            let reconnect = tokio::select! {
+                _ = self.shutdown.notified() => {
+                    socket.0.abort();
+                    return self.source.close_listen_key(&listen_key).await;
+                }
+                _ = keep_alive.tick() => {
+                    if self.events.is_closed() {
+                        socket.0.abort();
+                        return self.source.close_listen_key(&listen_key).await;
+                    }
+                    match self.source.keep_alive_listen_key(&listen_key).await {
+                        Ok(()) => None,
+                        Err(_) => Some(Reconnect::Dropped),
+                    }
+                }
+                event = socket.1.next() => match event.map(|e| e.map(|e| S::receive(e.event))) {
+                    Some(Ok(Received::Deliver(event))) => {
+                        if self.events.send(Ok(event)).await.is_err() {
+                            socket.0.abort();
+                            return self.source.close_listen_key(&listen_key).await;
+                        }
+                        None
+                    }
+                    Some(Ok(Received::Ignore)) => None,
+                    Some(Ok(Received::Expired)) => Some(Reconnect::Expired),
+                    Some(Err(_)) | None => Some(Reconnect::Dropped),
+                },
+            };
+            if let Some(reconnect) = reconnect {
+                socket.0.abort();
+                if let Reconnect::Expired = reconnect {
+                    let _ = self.source.close_listen_key(&listen_key).await;
+                }
+                match self.reopen().await {
+                    Ok(Some(reopened)) => socket = reopened,
+                    // Shut down while reconnecting; an expired key is closed already.
+                    Ok(None) => {
+                        return match reconnect {
+                            Reconnect::Dropped => self.source.close_listen_key(&listen_key).await,
+                            Reconnect::Expired => Ok(()),
+                        };
+                    }
+                    Err(e) => {
+                        let _ = self.events.send(Err(e)).await;
+                        return Ok(());
+                    }
+                }
+                keep_alive.reset();
+            }
+        }
+    }
+
+    // Asks for a listen key, which is the current one while it is valid, and
+    // connects to it. `None` if shut down meanwhile.
+    async fn reopen(&self) -> Result<Option<Socket<S::SocketEvent>>> {
+        let policy = &self.options.reconnect_policy;
+        let mut attempt = 1;
+        loop {
+            tokio::select! {
+                _ = self.shutdown.notified() => return Ok(None),
+                _ = tokio::time::sleep(policy.delay(attempt)) => {}
+            }
+            let listen_key = match self.source.create_listen_key().await {
+                Ok(listen_key) => listen_key,
+                Err(e) if !policy.allows(attempt + 1) => return Err(e),
+                Err(_) => {
+                    attempt += 1;
+                    continue;
+                }
+            };
+            match self.source.connect(&self.config, &listen_key).await {
+                Ok(socket) => {
+                    *self.listen_key.write().unwrap() = listen_key;
+                    return Ok(Some(socket));
+                }
+                Err(e) => {
+                    if !policy.allows(attempt + 1) {
+                        return Err(e);
+                    }
+                    attempt += 1;
+                }
+            }
+        }
+    }
+}
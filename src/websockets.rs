@@ -1,14 +1,21 @@
-use crate::async_websocket_client::AsyncWebsocketClient;
+use crate::async_websocket_client::{
+    AsyncWebsocketClient, ConnectionEvent, ReconnectPolicy, SpawnedSocket, StreamEvent,
+};
 use crate::config::Config;
 use crate::errors::Result;
+use crate::event_channel::{ChannelConfig, EventReceiver, EventSender, event_channel};
 use crate::model::{
-    AccountUpdateEvent, AggrTradesEvent, BalanceUpdateEvent, BookTickerEvent, DayTickerEvent,
-    DepthOrderBookEvent, KlineEvent, OrderBook, OrderTradeEvent, TradeEvent, WindowTickerEvent,
+    AccountPositionEvent, AccountUpdateEvent, AggrTradesEvent, BalanceUpdateEvent, BookTickerEvent,
+    DayTickerEvent, DepthOrderBookEvent, KlineEvent, OrderBook, OrderTradeEvent, TradeEvent,
+    UserDataStreamExpiredEvent, WindowTickerEvent,
 };
 // New
 
+use serde::de::{self, Deserializer};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
+use std::fmt::Display;
 use std::future::Future;
 // New
 use std::pin::Pin;
@@ -45,6 +52,7 @@ impl WebsocketAPI {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum WebsocketEvent {
     AccountUpdate(AccountUpdateEvent),
+    AccountPosition(AccountPositionEvent),
     BalanceUpdate(BalanceUpdateEvent),
     OrderTrade(OrderTradeEvent),
     AggrTrades(AggrTradesEvent),
@@ -57,12 +65,14 @@ pub enum WebsocketEvent {
     Kline(KlineEvent),
     DepthOrderBook(DepthOrderBookEvent),
     BookTicker(BookTickerEvent),
+    UserDataStreamExpiredEvent(UserDataStreamExpiredEvent),
+    /// An event without a typed variant, as received.
+    Unknown(Value),
+    Connection(ConnectionEvent),
 }
 
 // Events enum is what AsyncWebsocketClient will deserialize into (as E)
-// This needs to be DeserializeOwned + Send + 'a
-#[derive(Serialize, Deserialize, Debug, Clone)] // Added Clone for potential use, ensure it's Send + 'a compatible
-#[serde(untagged)]
+#[derive(Serialize, Debug, Clone)]
 enum Events {
     DayTickerEventAll(Vec<DayTickerEvent>),
     WindowTickerEventAll(Vec<WindowTickerEvent>),
@@ -71,76 +81,186 @@ enum Events {
     WindowTickerEvent(WindowTickerEvent),
     BookTickerEvent(BookTickerEvent),
     AccountUpdateEvent(AccountUpdateEvent),
+    AccountPositionEvent(AccountPositionEvent),
     OrderTradeEvent(OrderTradeEvent),
     AggrTradesEvent(AggrTradesEvent),
     TradeEvent(TradeEvent),
     KlineEvent(KlineEvent),
     OrderBook(OrderBook),
     DepthOrderBookEvent(DepthOrderBookEvent),
+    UserDataStreamExpiredEvent(UserDataStreamExpiredEvent),
+    Unknown(Value),
+    #[serde(skip)]
+    Connection(ConnectionEvent),
+}
+
+impl Events {
+    fn from_value(value: Value) -> serde_json::Result<Self> {
+        let event = match (value.is_array(), event_type(&value)) {
+            (true, Some("24hrTicker")) => Events::DayTickerEventAll(serde_json::from_value(value)?),
+            (true, Some("1hTicker" | "4hTicker" | "1dTicker")) => {
+                Events::WindowTickerEventAll(serde_json::from_value(value)?)
+            }
+            (false, Some("24hrTicker")) => Events::DayTickerEvent(serde_json::from_value(value)?),
+            (false, Some("1hTicker" | "4hTicker" | "1dTicker")) => {
+                Events::WindowTickerEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("balanceUpdate")) => {
+                Events::BalanceUpdateEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("ACCOUNT_UPDATE")) => {
+                Events::AccountUpdateEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("outboundAccountPosition")) => {
+                Events::AccountPositionEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("executionReport")) => {
+                Events::OrderTradeEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("aggTrade")) => Events::AggrTradesEvent(serde_json::from_value(value)?),
+            (false, Some("trade")) => Events::TradeEvent(serde_json::from_value(value)?),
+            (false, Some("kline")) => Events::KlineEvent(serde_json::from_value(value)?),
+            (false, Some("depthUpdate")) => {
+                Events::DepthOrderBookEvent(serde_json::from_value(value)?)
+            }
+            (false, Some("listenKeyExpired")) => {
+                Events::UserDataStreamExpiredEvent(serde_json::from_value(value)?)
+            }
+            (false, None) => match untyped_payload(&value) {
+                Some(UntypedPayload::PartialDepth) => {
+                    Events::OrderBook(serde_json::from_value(value)?)
+                }
+                Some(UntypedPayload::BookTicker) => {
+                    Events::BookTickerEvent(serde_json::from_value(value)?)
+                }
+                None => Events::Unknown(value),
+            },
+            _ => Events::Unknown(value),
+        };
+        Ok(event)
+    }
+}
+
+impl<'de> Deserialize<'de> for Events {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Events::from_value(Value::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+impl From<ConnectionEvent> for Events {
+    fn from(event: ConnectionEvent) -> Self {
+        Events::Connection(event)
+    }
+}
+
+impl From<Events> for WebsocketEvent {
+    fn from(events_obj: Events) -> Self {
+        match events_obj {
+            Events::DayTickerEventAll(v) => WebsocketEvent::DayTickerAll(v),
+            Events::WindowTickerEventAll(v) => WebsocketEvent::WindowTickerAll(v),
+            Events::BalanceUpdateEvent(v) => WebsocketEvent::BalanceUpdate(v),
+            Events::DayTickerEvent(v) => WebsocketEvent::DayTicker(v),
+            Events::WindowTickerEvent(v) => WebsocketEvent::WindowTicker(v),
+            Events::BookTickerEvent(v) => WebsocketEvent::BookTicker(v),
+            Events::AccountUpdateEvent(v) => WebsocketEvent::AccountUpdate(v),
+            Events::AccountPositionEvent(v) => WebsocketEvent::AccountPosition(v),
+            Events::OrderTradeEvent(v) => WebsocketEvent::OrderTrade(v),
+            Events::AggrTradesEvent(v) => WebsocketEvent::AggrTrades(v),
+            Events::TradeEvent(v) => WebsocketEvent::Trade(v),
+            Events::KlineEvent(v) => WebsocketEvent::Kline(v),
+            Events::OrderBook(v) => WebsocketEvent::OrderBook(v),
+            Events::DepthOrderBookEvent(v) => WebsocketEvent::DepthOrderBook(v),
+            Events::UserDataStreamExpiredEvent(v) => WebsocketEvent::UserDataStreamExpiredEvent(v),
+            Events::Unknown(v) => WebsocketEvent::Unknown(v),
+            Events::Connection(v) => WebsocketEvent::Connection(v),
+        }
+    }
 }
 
 // Define the type for the adapter handler passed to AsyncWebsocketClient
 type AdapterHandler<'a> = Box<
-    dyn FnMut(Events) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> + Send + Sync + 'a,
+    dyn FnMut(StreamEvent<Events>) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>
+        + Send
+        + Sync
+        + 'a,
 >;
 
 pub struct WebSockets<'a> {
+    // Set for sockets built with `channel`, to report the error that ends `spawn`.
+    events: Option<EventSender<Result<StreamEvent<WebsocketEvent>>>>,
     client: AsyncWebsocketClient<'a, Events, AdapterHandler<'a>>,
 }
 
 impl<'a> WebSockets<'a> {
-    pub fn new<Callback>(user_handler: Callback) -> WebSockets<'a>
+    pub fn new<Callback>(mut user_handler: Callback) -> WebSockets<'a>
     where
         Callback: FnMut(WebsocketEvent) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>
             + Send
             + Sync
             + 'a,
+    {
+        Self::new_with_stream_events(move |event: StreamEvent<WebsocketEvent>| {
+            user_handler(event.event)
+        })
+    }
+
+    /// Like [`new`](Self::new), but the handler also gets the stream name and
+    /// the local receive time of each event, so that one combined-stream
+    /// connection can feed a consumer per stream.
+    pub fn new_with_stream_events<Callback>(user_handler: Callback) -> WebSockets<'a>
+    where
+        Callback: FnMut(
+                StreamEvent<WebsocketEvent>,
+            ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>
+            + Send
+            + Sync
+            + 'a,
     {
         let shared_user_handler = Arc::new(TokioMutex::new(user_handler));
 
-        let adapter_handler: AdapterHandler<'a> = Box::new(move |events_obj: Events| {
-            let user_handler_clone = Arc::clone(&shared_user_handler);
-            Box::pin(async move {
-                let action = match events_obj {
-                    Events::DayTickerEventAll(v) => WebsocketEvent::DayTickerAll(v),
-                    Events::WindowTickerEventAll(v) => WebsocketEvent::WindowTickerAll(v),
-                    Events::BalanceUpdateEvent(v) => WebsocketEvent::BalanceUpdate(v),
-                    Events::DayTickerEvent(v) => WebsocketEvent::DayTicker(v),
-                    Events::WindowTickerEvent(v) => WebsocketEvent::WindowTicker(v),
-                    Events::BookTickerEvent(v) => WebsocketEvent::BookTicker(v),
-                    Events::AccountUpdateEvent(v) => WebsocketEvent::AccountUpdate(v),
-                    Events::OrderTradeEvent(v) => WebsocketEvent::OrderTrade(v),
-                    Events::AggrTradesEvent(v) => WebsocketEvent::AggrTrades(v),
-                    Events::TradeEvent(v) => WebsocketEvent::Trade(v),
-                    Events::KlineEvent(v) => WebsocketEvent::Kline(v),
-                    Events::OrderBook(v) => WebsocketEvent::OrderBook(v),
-                    Events::DepthOrderBookEvent(v) => WebsocketEvent::DepthOrderBook(v),
-                };
-                let mut handler_guard = user_handler_clone.lock().await;
-                (handler_guard)(action).await
-            })
-        });
+        let adapter_handler: AdapterHandler<'a> =
+            Box::new(move |events_obj: StreamEvent<Events>| {
+                let user_handler_clone = Arc::clone(&shared_user_handler);
+                Box::pin(async move {
+                    let action = events_obj.map(WebsocketEvent::from);
+                    let mut handler_guard = user_handler_clone.lock().await;
+                    (handler_guard)(action).await
+                })
+            });
 
         WebSockets {
+            events: None,
             client: AsyncWebsocketClient::new(adapter_handler),
         }
     }
 
-    pub async fn connect(&mut self, subscription: &str) -> Result<()> {
+    /// Reconnect automatically when the connection drops. Lifecycle changes are
+    /// delivered to the handler as [`WebsocketEvent::Connection`].
+    pub fn set_reconnect_policy<P: Into<Option<ReconnectPolicy>>>(&mut self, policy: P) {
+        self.client.set_reconnect_policy(policy);
+    }
+
+    /// Connects to one stream: a [`Stream`](crate::streams::Stream), a raw
+    /// stream name or a listen key.
+    pub async fn connect<S: Display>(&mut self, subscription: S) -> Result<()> {
         self.client
-            .connect(&WebsocketAPI::Default.params(subscription))
+            .connect(&WebsocketAPI::Default.params(&subscription.to_string()))
             .await
     }
 
-    pub async fn connect_with_config(&mut self, subscription: &str, config: &Config) -> Result<()> {
+    pub async fn connect_with_config<S: Display>(
+        &mut self, subscription: S, config: &Config,
+    ) -> Result<()> {
         self.client
-            .connect(&WebsocketAPI::Custom(config.ws_endpoint.clone()).params(subscription))
+            .connect(
+                &WebsocketAPI::Custom(config.ws_endpoint.clone()).params(&subscription.to_string()),
+            )
             .await
     }
 
-    pub async fn connect_multiple_streams(&mut self, endpoints: &[String]) -> Result<()> {
+    pub async fn connect_multiple_streams<S: Display>(&mut self, endpoints: &[S]) -> Result<()> {
         self.client
-            .connect(&WebsocketAPI::MultiStream.params(&endpoints.join("/")))
+            .connect(&WebsocketAPI::MultiStream.params(&join_streams(endpoints)))
             .await
     }
 
@@ -149,7 +269,102 @@ impl<'a> WebSockets<'a> {
     }
 
     // event_loop now takes Arc<AtomicBool>
-    pub async fn event_loop(&mut self, running: Arc<AtomicBool>) -> Result<()> {
+    pub async fn event_loop(&self, running: Arc<AtomicBool>) -> Result<()> {
         self.client.event_loop(running).await
     }
+
+    /// Adds streams to the open connection. Returns once the server has
+    /// acknowledged them, which requires `event_loop` to be running
+    /// concurrently, e.g. `tokio::join!(ws.event_loop(running), ws.subscribe(&streams))`.
+    pub async fn subscribe<S: Display>(&self, streams: &[S]) -> Result<()> {
+        self.client.subscribe(streams).await
+    }
+
+    pub async fn unsubscribe<S: Display>(&self, streams: &[S]) -> Result<()> {
+        self.client.unsubscribe(streams).await
+    }
+
+    pub async fn list_subscriptions(&self) -> Result<Vec<String>> {
+        self.client.list_subscriptions().await
+    }
+}
+
+impl WebSockets<'static> {
+    /// Builds a socket that queues its events, with their stream name and
+    /// receive time, for the returned receiver instead of calling a handler.
+    /// See [`event_channel`](crate::event_channel).
+    pub fn channel(
+        config: ChannelConfig,
+    ) -> (
+        WebSockets<'static>,
+        EventReceiver<Result<StreamEvent<WebsocketEvent>>>,
+    ) {
+        let (sender, receiver) = event_channel(config);
+        let handler_sender = sender.clone();
+        let mut web_socket =
+            WebSockets::new_with_stream_events(move |event: StreamEvent<WebsocketEvent>| {
+                let sender = handler_sender.clone();
+                Box::pin(async move { sender.send(Ok(event)).await })
+            });
+        web_socket.events = Some(sender);
+        (web_socket, receiver)
+    }
+
+    /// Runs [`event_loop`](Self::event_loop) on a new task until the
+    /// connection fails for good or, for a socket built with
+    /// [`channel`](Self::channel), the receiver is dropped. The returned
+    /// handle keeps `subscribe`, `unsubscribe` and `list_subscriptions`
+    /// usable while the loop runs. A channel socket sends the error that
+    /// ended the loop to the receiver instead of returning it.
+    pub fn spawn(self) -> SpawnedSocket<WebSockets<'static>> {
+        SpawnedSocket::spawn(self, |socket| async move {
+            let result = socket.event_loop(Arc::new(AtomicBool::new(true))).await;
+            match (&socket.events, result) {
+                (Some(events), result) => {
+                    if let Err(e) = result {
+                        if !events.is_closed() {
+                            let _ = events.send(Err(e)).await;
+                        }
+                    }
+                    // The handle keeps the socket, and with it the sender, alive.
+                    events.close();
+                    Ok(())
+                }
+                (None, result) => result,
+            }
+        })
+    }
+}
+
+pub(crate) fn join_streams<S: Display>(streams: &[S]) -> String {
+    streams
+        .iter()
+        .map(|stream| stream.to_string())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// The `e` field of an event, or of the first event of an array of them.
+pub(crate) fn event_type(value: &Value) -> Option<&str> {
+    let event = match value {
+        Value::Array(events) => events.first()?,
+        event => event,
+    };
+    event.get("e")?.as_str()
+}
+
+// Payloads that carry no `e` field.
+pub(crate) enum UntypedPayload {
+    PartialDepth,
+    BookTicker,
+}
+
+pub(crate) fn untyped_payload(value: &Value) -> Option<UntypedPayload> {
+    if value.get("lastUpdateId").is_some() {
+        Some(UntypedPayload::PartialDepth)
+    } else if value.get("u").is_some() && value.get("b").is_some() && value.get("a").is_some() {
+        Some(UntypedPayload::BookTicker)
+    } else {
+        None
+    }
 }
@@ -0,0 +1,146 @@
+use binance_rs_plus::api::*;
+use binance_rs_plus::coin_futures::account::*;
+use binance_rs_plus::coin_futures::general::*;
+use binance_rs_plus::coin_futures::market::*;
+use binance_rs_plus::config::*;
+
+mod common;
+use common::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use binance_rs_plus::futures::account::TimeInForce;
+    use binance_rs_plus::symbol_rules::SymbolRules;
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn exchange_info() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/dapi/v1/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/coin_futures/exchange_info.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_coin_futures_rest_api_endpoint(server.url());
+        let general: CoinFuturesGeneral = Binance::new_with_config(None, None, &config);
+        let symbol = general.get_symbol_info("btcusd_perp").await.unwrap();
+        mock.assert();
+
+        assert_eq!(symbol.pair, "BTCUSD");
+        assert_eq!(symbol.contract_type, "PERPETUAL");
+        assert_eq!(symbol.contract_size, 100);
+        assert_eq!(symbol.margin_asset, "BTC");
+        assert!(
+            symbol
+                .order_types
+                .contains(&"TRAILING_STOP_MARKET".to_string())
+        );
+        let rules = SymbolRules::try_from(&symbol).unwrap();
+        assert_eq!(rules.symbol, "BTCUSD_PERP");
+    }
+
+    #[tokio::test]
+    async fn pair_prices() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/dapi/v1/ticker/price")
+            .match_query(Matcher::UrlEncoded("pair".into(), "BTCUSD".into()))
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/coin_futures/ticker_price.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_coin_futures_rest_api_endpoint(server.url());
+        let market: CoinFuturesMarket = Binance::new_with_config(None, None, &config);
+        let prices = market.get_pair_prices("BTCUSD").await.unwrap();
+        mock.assert();
+
+        assert_eq!(prices.len(), 2);
+        assert_eq!(prices[1].symbol, "BTCUSD_250926");
+        assert_eq!(prices[1].pair, "BTCUSD");
+    }
+
+    #[tokio::test]
+    async fn limit_buy() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/dapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "price=9000&quantity=10&recvWindow=1234&side=BUY&symbol=BTCUSD_PERP&timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/coin_futures/new_order.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_coin_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: CoinFuturesAccount = Binance::new_with_config(None, None, &config);
+        let order = account
+            .limit_buy("BTCUSD_PERP", 10, 9000.into(), TimeInForce::GTC)
+            .await
+            .unwrap();
+        mock.assert();
+
+        assert_eq!(order.order_id, 22542179);
+        assert_eq!(order.pair, "BTCUSD");
+        assert_eq!(order.status, "NEW");
+        assert!(order.cum_qty.is_some());
+        assert!(order.time.is_none());
+    }
+
+    #[tokio::test]
+    async fn position_information() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/dapi/v1/positionRisk")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "pair=BTCUSD&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/coin_futures/position_risk.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_coin_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: CoinFuturesAccount = Binance::new_with_config(None, None, &config);
+        let positions = account.position_information("BTCUSD").await.unwrap();
+        mock.assert();
+
+        assert_eq!(positions.len(), 2);
+        assert_eq!(positions[1].symbol, "BTCUSD_PERP");
+        assert_eq!(positions[1].position_side, "SHORT");
+        assert!(positions[1].is_auto_add_margin);
+        assert_eq!(positions[1].max_qty, num("250.0"));
+    }
+
+    #[tokio::test]
+    async fn account_balance() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/dapi/v1/balance")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/coin_futures/balance.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_coin_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: CoinFuturesAccount = Binance::new_with_config(None, None, &config);
+        let balances = account.account_balance().await.unwrap();
+        mock.assert();
+
+        assert_eq!(balances[0].asset, "BTC");
+        assert_eq!(balances[0].withdraw_available, num("0.0025"));
+    }
+}
@@ -0,0 +1,15 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use binance_rs_plus::model::{Number, NumberString};
+
+/// Builds a `Number` from its decimal text, so that the same assertions hold
+/// with and without the `decimal` feature.
+pub fn num(value: &str) -> Number {
+    value.parse().unwrap()
+}
+
+/// Like [`num`], for fields that stay strings without the `decimal` feature.
+pub fn num_string(value: &str) -> NumberString {
+    value.parse().unwrap()
+}
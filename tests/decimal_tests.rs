@@ -0,0 +1,59 @@
+#![cfg(feature = "decimal")]
+
+use binance_rs_plus::account::*;
+use binance_rs_plus::api::*;
+use binance_rs_plus::config::*;
+use binance_rs_plus::model::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn models_keep_exact_values() {
+        let balance: Balance = serde_json::from_str(
+            r#"{"asset":"BTC","free":"4723846.89208129","locked":"0.00000000"}"#,
+        )
+        .unwrap();
+        assert_eq!(balance.free, dec("4723846.89208129"));
+        assert!(balance.locked.is_zero());
+
+        let bids: Bids = serde_json::from_str(r#"["0.30000000", 0.1]"#).unwrap();
+        assert_eq!(bids.price, dec("0.3"));
+        assert_eq!(bids.qty, dec("0.1"));
+    }
+
+    #[tokio::test]
+    async fn orders_are_sent_without_float_artifacts() {
+        let mut server = Server::new_async().await;
+        let mock_limit_buy = server.mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("price=0.3&quantity=1.1&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*".into()))
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        // 0.1 + 0.2 is 0.30000000000000004 as f64.
+        let transaction = account
+            .limit_buy("LTCBTC", dec("1.1"), dec("0.1") + dec("0.2"))
+            .await
+            .unwrap();
+
+        mock_limit_buy.assert();
+
+        assert_eq!(transaction.price, dec("0.1"));
+        assert_eq!(transaction.orig_qty, dec("1.0"));
+        assert!(transaction.cummulative_quote_qty.is_zero());
+    }
+}
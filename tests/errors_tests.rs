@@ -0,0 +1,133 @@
+use binance_rs_plus::account::*;
+use binance_rs_plus::api::*;
+use binance_rs_plus::config::*;
+use binance_rs_plus::errors::*;
+use binance_rs_plus::general::*;
+use binance_rs_plus::rate_limit::RateLimiter;
+
+mod common;
+use common::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn error_codes_round_trip() {
+        assert_eq!(
+            BinanceErrorCode::from(-1003),
+            BinanceErrorCode::TooManyRequests
+        );
+        assert_eq!(
+            BinanceErrorCode::from(-2010),
+            BinanceErrorCode::NewOrderRejected
+        );
+        assert_eq!(i32::from(BinanceErrorCode::InvalidTimestamp), -1021);
+        assert_eq!(
+            BinanceErrorCode::from(-2021),
+            BinanceErrorCode::CancelReplaceOrTriggerRejected
+        );
+        assert_eq!(
+            i32::from(BinanceErrorCode::CancelReplaceOrReduceOnlyRejected),
+            -2022
+        );
+        assert_eq!(
+            BinanceErrorCode::from(-40000),
+            BinanceErrorCode::Other(-40000)
+        );
+        assert_eq!(i32::from(BinanceErrorCode::Other(-40000)), -40000);
+    }
+
+    #[tokio::test]
+    async fn bad_request_carries_code() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("POST", "/api/v3/order")
+            .match_query(Matcher::Any)
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(
+                r#"{"code":-2010,"msg":"Account has insufficient balance for requested action."}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let err = account
+            .limit_buy("LTCBTC", num("1"), num("0.1"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.binance_code(), Some(BinanceErrorCode::NewOrderRejected));
+        match err {
+            Error::Http(http) => {
+                assert_eq!(http.status.as_u16(), 400);
+                assert_eq!(http.content.unwrap().code, -2010);
+            }
+            other => panic!("expected Http, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn other_statuses_keep_body_and_retry_after() {
+        let mut server = Server::new_async().await;
+        let _unauthorized = server
+            .mock("GET", "/api/v3/time")
+            .with_status(401)
+            .with_body(r#"{"code":-2015,"msg":"Invalid API-key, IP, or permissions for action."}"#)
+            .create_async()
+            .await;
+        let _teapot = server
+            .mock("GET", "/api/v3/ping")
+            .with_status(418)
+            .with_header("retry-after", "120")
+            .with_body(r#"{"code":-1003,"msg":"Way too many requests; IP banned."}"#)
+            .create_async()
+            .await;
+
+        // The 418 blocks the limiter; keep it away from the process-wide one.
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_rate_limiter(Arc::new(RateLimiter::spot()));
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        let err = general.get_server_time().await.err().unwrap();
+        assert_eq!(err.binance_code(), Some(BinanceErrorCode::RejectedApiKey));
+
+        match general.ping().await {
+            Err(Error::Http(http)) => {
+                assert_eq!(http.status.as_u16(), 418);
+                assert_eq!(http.code(), Some(BinanceErrorCode::TooManyRequests));
+                assert_eq!(http.retry_after, Some(Duration::from_secs(120)));
+            }
+            other => panic!("expected Http, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn non_json_body_is_kept() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/api/v3/ping")
+            .with_status(503)
+            .with_body("Service Unavailable")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        match general.ping().await {
+            Err(Error::Http(http)) => {
+                assert_eq!(http.status.as_u16(), 503);
+                assert!(http.content.is_none());
+                assert_eq!(http.body, "Service Unavailable");
+            }
+            other => panic!("expected Http, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use binance_rs_plus::event_channel::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+    use std::time::Duration;
+
+    async fn fill(config: ChannelConfig) -> (EventSender<u32>, EventReceiver<u32>) {
+        let (sender, receiver) = event_channel(config.set_capacity(2));
+        for n in 1..=2 {
+            sender.send(n).await.unwrap();
+        }
+        (sender, receiver)
+    }
+
+    #[tokio::test]
+    async fn drop_oldest_keeps_latest() {
+        let (sender, mut receiver) =
+            fill(ChannelConfig::default().set_backpressure(Backpressure::DropOldest)).await;
+        sender.send(3).await.unwrap();
+        drop(sender);
+
+        assert_eq!(receiver.dropped(), 1);
+        assert_eq!(receiver.by_ref().collect::<Vec<_>>().await, vec![2, 3]);
+    }
+
+    #[tokio::test]
+    async fn drop_newest_keeps_queue() {
+        let (sender, mut receiver) =
+            fill(ChannelConfig::default().set_backpressure(Backpressure::DropNewest)).await;
+        sender.send(3).await.unwrap();
+        drop(sender);
+
+        assert_eq!(receiver.dropped(), 1);
+        assert_eq!(receiver.by_ref().collect::<Vec<_>>().await, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn close_ends_stream_while_senders_live() {
+        let (sender, receiver) = fill(ChannelConfig::default()).await;
+        let clone = sender.clone();
+        sender.close();
+
+        assert_eq!(receiver.collect::<Vec<_>>().await, vec![1, 2]);
+        drop(clone);
+    }
+
+    #[tokio::test]
+    async fn block_waits_for_room() {
+        let (sender, mut receiver) = fill(ChannelConfig::default()).await;
+        let blocked = tokio::spawn(async move {
+            sender.send(3).await.unwrap();
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!blocked.is_finished());
+        assert_eq!(receiver.len(), 2);
+
+        assert_eq!(receiver.recv().await, Some(1));
+        blocked.await.unwrap();
+        assert_eq!(receiver.collect::<Vec<_>>().await, vec![2, 3]);
+    }
+
+    #[tokio::test]
+    async fn send_fails_once_receiver_is_gone() {
+        let (sender, receiver) = fill(ChannelConfig::default()).await;
+        let blocked = tokio::spawn(async move { sender.send(3).await });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        drop(receiver);
+
+        assert!(blocked.await.unwrap().is_err());
+    }
+}
@@ -2,13 +2,17 @@ use binance_rs_plus::api::*;
 use binance_rs_plus::config::*;
 use binance_rs_plus::futures::account::*;
 
+mod common;
+use common::*;
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use mockito::{Server, Matcher};
-    use float_cmp::*;
     use binance_rs_plus::account::OrderSide; // Assuming this OrderSide is compatible or aliased correctly
     use binance_rs_plus::futures::model::{Transaction, Income}; // Added Income
+    use binance_rs_plus::errors::Error;
+    use binance_rs_plus::model::Number;
 
     #[tokio::test] // Changed
     async fn change_initial_leverage() {
@@ -35,12 +39,8 @@ mod tests {
 
         assert_eq!(response.leverage, 2);
         assert_eq!(response.symbol, "LTCUSDT");
-        assert!(approx_eq!(
-            f64,
-            response.max_notional_value,
-            9223372036854776000.0, // This looks like a very large number, ensure it's correct from API
-            ulps = 2
-        ));
+        // This looks like a very large number, ensure it's correct from API
+        assert_eq!(response.max_notional_value, num("9223372036854776000.0"));
     }
 
     #[tokio::test] // Changed
@@ -94,7 +94,7 @@ mod tests {
         let account: FuturesAccount = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
         account
-            .change_position_margin("BTCUSDT", 100.0, 1) // Assuming 1 means ADD_MARGIN
+            .change_position_margin("BTCUSDT", num("100"), 1) // Assuming 1 means ADD_MARGIN
             .await // .await added
             .unwrap();
 
@@ -166,7 +166,7 @@ mod tests {
         let account: FuturesAccount = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
         let transaction: Transaction = account
-            .stop_market_close_buy("SRMUSDT", 10.5)
+            .stop_market_close_buy("SRMUSDT", num("10.5"))
             .await
             .unwrap(); // .await added
 
@@ -176,7 +176,7 @@ mod tests {
         assert_eq!(transaction.side, "BUY"); // From the perspective of the executed order after stop is triggered
         assert_eq!(transaction.orig_type, "STOP_MARKET");
         assert!(transaction.close_position);
-        assert!(approx_eq!(f64, transaction.stop_price, 10.5, ulps = 2));
+        assert_eq!(transaction.stop_price, num("10.5"));
     }
 
     #[tokio::test] // Changed
@@ -195,7 +195,7 @@ mod tests {
         let account: FuturesAccount = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
         let transaction: Transaction = account
-            .stop_market_close_sell("SRMUSDT", 7.4)
+            .stop_market_close_sell("SRMUSDT", num("7.4"))
             .await
             .unwrap(); // .await added
 
@@ -205,7 +205,7 @@ mod tests {
         assert_eq!(transaction.side, "SELL");
         assert_eq!(transaction.orig_type, "STOP_MARKET");
         assert!(transaction.close_position);
-        assert!(approx_eq!(f64, transaction.stop_price, 7.4, ulps = 2));
+        assert_eq!(transaction.stop_price, num("7.4"));
     }
 
     #[tokio::test] // Changed
@@ -235,7 +235,7 @@ mod tests {
             reduce_only: None,
             price: None,
             new_client_order_id: None,
-            stop_price: Some(7.4),
+            stop_price: Some(num("7.4")),
             close_position: Some(true),
             activation_price: None,
             callback_rate: None,
@@ -250,7 +250,7 @@ mod tests {
         assert_eq!(transaction.side, "SELL");
         assert_eq!(transaction.orig_type, "STOP_MARKET");
         assert!(transaction.close_position);
-        assert!(approx_eq!(f64, transaction.stop_price, 7.4, ulps = 2));
+        assert_eq!(transaction.stop_price, num("7.4"));
     }
 
     #[tokio::test] // Changed
@@ -287,4 +287,155 @@ mod tests {
         assert!(!income_history.is_empty());
         // Add more specific assertions for income history if needed
     }
+    fn limit_order(side: OrderSide, price: Number, client_id: &str) -> CustomOrderRequest {
+        CustomOrderRequest {
+            symbol: "BTCUSDT".into(),
+            side,
+            position_side: None,
+            order_type: binance_rs_plus::futures::account::OrderType::Limit,
+            time_in_force: Some(TimeInForce::GTC),
+            quantity: Some(num("0.01")),
+            reduce_only: None,
+            price: Some(price),
+            new_client_order_id: Some(client_id.into()),
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn custom_batch_orders() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/fapi/v1/batchOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "batchOrders=%5B%7B%22newClientOrderId%22%3A%22ladder-1%22%2C%22price%22%3A%2230000%22\
+                %2C%22quantity%22%3A%220.01%22%2C%22side%22%3A%22BUY%22%2C%22symbol%22%3A%22BTCUSDT%22\
+                %2C%22timeInForce%22%3A%22GTC%22%2C%22type%22%3A%22LIMIT%22%7D%2C.*%5D\
+                &recvWindow=1234&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/batch_orders.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let results = account
+            .custom_batch_orders(vec![
+                limit_order(OrderSide::Buy, num("30000"), "ladder-1"),
+                limit_order(OrderSide::Buy, num("29900"), "ladder-2"),
+            ])
+            .await
+            .unwrap();
+
+        mock.assert();
+
+        assert_eq!(results.len(), 2);
+        let placed = results[0].as_ref().unwrap();
+        assert_eq!(placed.client_order_id, "ladder-1");
+        assert_eq!(placed.orig_qty, num("0.01"));
+        let rejected = results[1].as_ref().unwrap_err();
+        assert_eq!(rejected.code, -2019);
+        assert_eq!(rejected.msg, "Margin is insufficient.");
+    }
+
+    #[tokio::test]
+    async fn cancel_batch_orders() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("DELETE", "/fapi/v1/batchOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderIdList=%5B22542179%2C1%5D&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/cancel_batch_orders.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let results = account
+            .cancel_batch_orders("BTCUSDT", vec![22542179, 1])
+            .await
+            .unwrap();
+
+        mock.assert();
+
+        assert_eq!(results[0].as_ref().unwrap().status, "CANCELED");
+        assert_eq!(results[1].as_ref().unwrap_err().code, -2011);
+    }
+
+    #[tokio::test]
+    async fn modify_batch_orders() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("PUT", "/fapi/v1/batchOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "batchOrders=%5B%7B%22orderId%22%3A%2222542179%22%2C%22price%22%3A%2230000%22\
+                %2C%22quantity%22%3A%220.01%22%2C%22side%22%3A%22BUY%22%2C%22symbol%22%3A%22BTCUSDT%22%7D\
+                %2C%7B%22origClientOrderId%22%3A%22ladder-2%22.*%5D&recvWindow=1234&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/batch_orders.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let results = account
+            .modify_batch_orders(vec![
+                ModifyOrderRequest {
+                    symbol: "BTCUSDT".into(),
+                    side: OrderSide::Buy,
+                    order_id: Some(22542179),
+                    orig_client_order_id: None,
+                    quantity: num("0.01"),
+                    price: num("30000"),
+                },
+                ModifyOrderRequest {
+                    symbol: "BTCUSDT".into(),
+                    side: OrderSide::Buy,
+                    order_id: None,
+                    orig_client_order_id: Some("ladder-2".into()),
+                    quantity: num("0.01"),
+                    price: num("29950"),
+                },
+            ])
+            .await
+            .unwrap();
+
+        mock.assert();
+
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+    }
+
+    #[tokio::test]
+    async fn batch_orders_are_limited_to_five() {
+        let account: FuturesAccount = Binance::new(None, None);
+        let orders = (0..6)
+            .map(|n| limit_order(OrderSide::Sell, num("31000") + Number::from(n), "ladder"))
+            .collect();
+
+        match account.custom_batch_orders(orders).await {
+            Err(Error::InvalidOrder(_)) => {}
+            other => panic!("Expected InvalidOrder, got {:?}", other),
+        }
+        assert!(account.custom_batch_orders(vec![]).await.is_err());
+    }
 }
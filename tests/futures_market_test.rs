@@ -1,7 +1,11 @@
 use binance_rs_plus::api::*;
 use binance_rs_plus::config::*;
+use binance_rs_plus::futures::account::ContractType;
 use binance_rs_plus::futures::market::FuturesMarket;
-use binance_rs_plus::futures::model::OpenInterestHist;
+use binance_rs_plus::futures::model::{KlineSummaries, OpenInterestHist};
+
+mod common;
+use common::*;
 
 #[cfg(test)]
 mod tests {
@@ -34,18 +38,203 @@ mod tests {
         let expectation = vec![
             OpenInterestHist {
                 symbol: "BTCUSDT".into(),
-                sum_open_interest: "20403.63700000".into(),
-                sum_open_interest_value: "150570784.07809979".into(),
+                sum_open_interest: num_string("20403.63700000"),
+                sum_open_interest_value: num_string("150570784.07809979"),
                 timestamp: 1583127900000,
             },
             OpenInterestHist {
                 symbol: "BTCUSDT".into(),
-                sum_open_interest: "20401.36700000".into(),
-                sum_open_interest_value: "149940752.14464448".into(),
+                sum_open_interest: num_string("20401.36700000"),
+                sum_open_interest_value: num_string("149940752.14464448"),
                 timestamp: 1583128200000,
             },
         ];
 
         assert_eq!(open_interest_hists, expectation);
     }
+
+    #[tokio::test]
+    async fn funding_rate_history() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/fapi/v1/fundingRate")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "limit=2&startTime=1570608000000&symbol=BTCUSDT".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/funding_rate.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let rates = market
+            .get_funding_rate_history(Some("BTCUSDT".into()), 1570608000000, None, 2)
+            .await
+            .unwrap();
+        mock.assert();
+
+        assert_eq!(rates.len(), 2);
+        assert_eq!(rates[0].funding_rate, num("-0.0375"));
+        assert!(rates[0].mark_price.is_none());
+        assert_eq!(rates[1].mark_price.unwrap(), num("34287.54619963"));
+    }
+
+    #[tokio::test]
+    async fn continuous_klines() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/fapi/v1/continuousKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "contractType=PERPETUAL&interval=1m&limit=1&pair=BTCUSDT".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/continuous_klines.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let KlineSummaries::AllKlineSummaries(klines) = market
+            .get_continuous_klines("BTCUSDT", ContractType::Perpetual, "1m", 1, None, None)
+            .await
+            .unwrap();
+        mock.assert();
+
+        assert_eq!(klines.len(), 1);
+        assert_eq!(klines[0].open_time, 1607444700000);
+        assert_eq!(klines[0].close, num_string("18896.13"));
+        assert_eq!(klines[0].number_of_trades, 1874);
+    }
+
+    #[tokio::test]
+    async fn mark_price() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/fapi/v1/premiumIndex")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/futures/market/mark_price.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let mark_price = market.get_mark_price("BTCUSDT").await.unwrap();
+        mock.assert();
+
+        assert_eq!(mark_price.mark_price, num("11793.63104562"));
+        assert_eq!(mark_price.index_price.unwrap(), num("11781.8049597"));
+        assert_eq!(mark_price.interest_rate.unwrap(), num("0.0001"));
+    }
+
+    #[tokio::test]
+    async fn top_long_short_account_ratio() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/futures/data/topLongShortAccountRatio")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=2&period=5m&symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/futures/market/long_short_ratio.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let ratios = market
+            .top_long_short_account_ratio("BTCUSDT", "5m", 2, None, None)
+            .await
+            .unwrap();
+        mock.assert();
+
+        assert_eq!(ratios.len(), 2);
+        assert_eq!(ratios[0].long_short_ratio, num("1.8105"));
+        assert_eq!(ratios[1].short_account, num("0.642"));
+        assert_eq!(ratios[1].timestamp, 1583139900000);
+    }
+
+    #[tokio::test]
+    async fn taker_buy_sell_volume() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/futures/data/takerlongshortRatio")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("period=1h&symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/futures/market/taker_buy_sell_volume.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let volumes = market
+            .taker_buy_sell_volume("BTCUSDT", "1h", None, None, None)
+            .await
+            .unwrap();
+        mock.assert();
+
+        assert_eq!(volumes[0].buy_sell_ratio, num("1.5586"));
+        assert_eq!(volumes[0].sell_vol, num("248.503"));
+    }
+
+    #[tokio::test]
+    async fn basis() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/futures/data/basis")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "contractType=PERPETUAL&pair=BTCUSDT&period=1h".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/basis.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let basis = market
+            .basis("BTCUSDT", ContractType::Perpetual, "1h", None, None, None)
+            .await
+            .unwrap();
+        mock.assert();
+
+        assert_eq!(basis[0].contract_type, "PERPETUAL");
+        assert_eq!(basis[0].basis, num("13.94054945"));
+        assert!(basis[0].annualized_basis_rate.is_none());
+        assert_eq!(basis[1].annualized_basis_rate.unwrap(), num("0.0412"));
+    }
+
+    #[tokio::test]
+    async fn index_info() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/fapi/v1/indexInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=DEFIUSDT".into()))
+            .with_body_from_file("tests/mocks/futures/market/index_info.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let infos = market
+            .get_index_info(Some("DEFIUSDT".into()))
+            .await
+            .unwrap();
+        mock.assert();
+
+        assert_eq!(infos.len(), 1);
+        assert_eq!(infos[0].component, "baseAsset");
+        assert_eq!(infos[0].base_asset_list[1].base_asset, "BAND");
+        assert_eq!(
+            infos[0].base_asset_list[0].weight_in_percentage,
+            num("0.027839")
+        );
+    }
 }
@@ -0,0 +1,336 @@
+use binance_rs_plus::api::*;
+use binance_rs_plus::config::*;
+use binance_rs_plus::kline_cache::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use binance_rs_plus::errors::Result;
+    use binance_rs_plus::market::Market;
+    use binance_rs_plus::model::KlineSummary;
+    use mockito::{Matcher, Server};
+    use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+
+    const MINUTE: u64 = 60_000;
+    const T0: u64 = 1_699_999_980_000;
+
+    // Serves the given candles, `limit` per request at most, and records
+    // every requested range.
+    #[derive(Clone)]
+    struct FakeSource {
+        klines: Vec<KlineSummary>,
+        limit: u16,
+        requests: Arc<Mutex<Vec<(u64, u64)>>>,
+    }
+
+    impl FakeSource {
+        fn new(klines: Vec<KlineSummary>, limit: u16) -> Self {
+            Self {
+                klines,
+                limit,
+                requests: Arc::new(Mutex::new(Vec::new())),
+            }
+        }
+
+        fn requests(&self) -> Vec<(u64, u64)> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    impl KlineSource for FakeSource {
+        fn market(&self) -> &str {
+            "fake"
+        }
+
+        fn max_limit(&self) -> u16 {
+            self.limit
+        }
+
+        async fn klines(
+            &self, _symbol: &str, _interval: &str, start_time: u64, end_time: u64, limit: u16,
+        ) -> Result<Vec<KlineSummary>> {
+            self.requests.lock().unwrap().push((start_time, end_time));
+            Ok(self
+                .klines
+                .iter()
+                .filter(|k| k.open_time as u64 >= start_time && k.open_time as u64 <= end_time)
+                .take(usize::from(limit))
+                .cloned()
+                .collect())
+        }
+    }
+
+    // One-minute candles at the given minutes after `T0`.
+    fn minutes(minutes: impl IntoIterator<Item = u64>) -> Vec<KlineSummary> {
+        minutes
+            .into_iter()
+            .map(|m| kline(T0 + m * MINUTE))
+            .collect()
+    }
+
+    fn kline(open_time: u64) -> KlineSummary {
+        candle(open_time, open_time + MINUTE - 1)
+    }
+
+    fn candle(open_time: u64, close_time: u64) -> KlineSummary {
+        KlineSummary {
+            open_time: open_time as i64,
+            open: "1.5".parse().unwrap(),
+            high: "2.25".parse().unwrap(),
+            low: "1.25".parse().unwrap(),
+            close: "2.0".parse().unwrap(),
+            volume: "10".parse().unwrap(),
+            close_time: close_time as i64,
+            quote_asset_volume: "17.5".parse().unwrap(),
+            number_of_trades: 4,
+            taker_buy_base_asset_volume: "6".parse().unwrap(),
+            taker_buy_quote_asset_volume: "10.5".parse().unwrap(),
+        }
+    }
+
+    fn opens(klines: &[KlineSummary]) -> Vec<u64> {
+        klines
+            .iter()
+            .map(|k| (k.open_time as u64 - T0) / MINUTE)
+            .collect()
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "binance-kline-cache-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn interval_lengths() {
+        assert_eq!(interval_millis("1s").unwrap(), 1000);
+        assert_eq!(interval_millis("15m").unwrap(), 15 * MINUTE);
+        assert_eq!(interval_millis("4h").unwrap(), 240 * MINUTE);
+        assert_eq!(interval_millis("1w").unwrap(), 7 * 24 * 60 * MINUTE);
+        assert!(interval_millis("1M").is_err());
+        assert!(interval_millis("m").is_err());
+        assert!(interval_millis("1é").is_err());
+        assert!(interval_millis("").is_err());
+    }
+
+    #[tokio::test]
+    async fn sync_downloads_in_chunks_and_records_exchange_gaps() {
+        let dir = cache_dir("chunks");
+        let source = FakeSource::new(minutes((0..10).filter(|m| *m != 4 && *m != 5)), 3);
+        let downloader = KlineDownloader::new(source.clone(), KlineCache::new(&dir));
+
+        let report = downloader
+            .sync("BTCUSDT", "1m", T0, T0 + 9 * MINUTE)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            source.requests(),
+            vec![
+                (T0, T0 + 9 * MINUTE),
+                (T0 + 3 * MINUTE, T0 + 9 * MINUTE),
+                (T0 + 8 * MINUTE, T0 + 9 * MINUTE),
+            ]
+        );
+        assert_eq!(report.downloaded, 8);
+        assert_eq!(
+            report.gaps,
+            vec![Gap {
+                start: T0 + 4 * MINUTE,
+                end: T0 + 6 * MINUTE - 1,
+                kind: GapKind::Exchange,
+            }]
+        );
+        let cached = downloader
+            .cache()
+            .load("fake", "BTCUSDT", "1m")
+            .await
+            .unwrap();
+        assert_eq!(opens(&cached), vec![0, 1, 2, 3, 6, 7, 8, 9]);
+        assert_eq!(cached[0].close, kline(T0).close);
+        assert_eq!(cached[0].number_of_trades, 4);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn sync_resumes_after_last_close_time() {
+        let dir = cache_dir("resume");
+        let source = FakeSource::new(minutes((0..13).filter(|m| *m != 4 && *m != 5)), 3);
+        let downloader = KlineDownloader::new(source.clone(), KlineCache::new(&dir));
+        downloader
+            .sync("BTCUSDT", "1m", T0, T0 + 9 * MINUTE)
+            .await
+            .unwrap();
+        let last_close = downloader
+            .cache()
+            .last_close_time("fake", "BTCUSDT", "1m")
+            .await
+            .unwrap()
+            .unwrap();
+        let before = source.requests().len();
+
+        let report = downloader
+            .sync("BTCUSDT", "1m", T0, T0 + 12 * MINUTE)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            source.requests()[before..],
+            [(last_close + 1, T0 + 12 * MINUTE)]
+        );
+        assert_eq!(report.downloaded, 3);
+        assert_eq!(report.gaps.len(), 1);
+        assert_eq!(report.gaps[0].kind, GapKind::Exchange);
+
+        let again = downloader
+            .sync("BTCUSDT", "1m", T0, T0 + 12 * MINUTE)
+            .await
+            .unwrap();
+        assert_eq!(again.downloaded, 0);
+        assert_eq!(source.requests().len(), before + 1);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn sync_fills_missing_gaps_in_cache() {
+        let dir = cache_dir("fill");
+        let cache = KlineCache::new(&dir);
+        let stored: Vec<KlineSummary> = [0, 1, 4, 5]
+            .iter()
+            .map(|m| kline(T0 + m * MINUTE))
+            .collect();
+        cache.store("fake", "ETHUSDT", "1m", &stored).await.unwrap();
+
+        let gaps = cache
+            .gaps("fake", "ETHUSDT", "1m", T0, T0 + 5 * MINUTE)
+            .await
+            .unwrap();
+        assert_eq!(
+            gaps,
+            vec![Gap {
+                start: T0 + 2 * MINUTE,
+                end: T0 + 4 * MINUTE - 1,
+                kind: GapKind::Missing,
+            }]
+        );
+
+        let source = FakeSource::new(minutes(0..6), 1000);
+        let downloader = KlineDownloader::new(source.clone(), cache);
+        let klines = downloader
+            .klines("ETHUSDT", "1m", T0, T0 + 5 * MINUTE)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            source.requests(),
+            vec![(T0 + 2 * MINUTE, T0 + 4 * MINUTE - 1)]
+        );
+        assert_eq!(opens(&klines), vec![0, 1, 2, 3, 4, 5]);
+        let cached = downloader
+            .cache()
+            .load("fake", "ETHUSDT", "1m")
+            .await
+            .unwrap();
+        assert_eq!(opens(&cached), vec![0, 1, 2, 3, 4, 5]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn sync_steps_monthly_candles_by_calendar_month() {
+        const JAN: u64 = 1_672_531_200_000;
+        const FEB: u64 = 1_675_209_600_000;
+        const MAR: u64 = 1_677_628_800_000;
+        const APR: u64 = 1_680_307_200_000;
+        const MAY: u64 = 1_682_899_200_000;
+        const JUN: u64 = 1_685_577_600_000;
+
+        let dir = cache_dir("monthly");
+        let source = FakeSource::new(
+            vec![
+                candle(JAN, FEB - 1),
+                candle(FEB, MAR - 1),
+                candle(APR, MAY - 1),
+                candle(MAY, JUN - 1),
+            ],
+            2,
+        );
+        let downloader = KlineDownloader::new(source.clone(), KlineCache::new(&dir));
+
+        let report = downloader.sync("BTCUSDT", "1M", JAN, MAY).await.unwrap();
+
+        assert_eq!(source.requests(), vec![(JAN, MAY), (MAR, MAY)]);
+        assert_eq!(report.downloaded, 4);
+        assert_eq!(
+            report.gaps,
+            vec![Gap {
+                start: MAR,
+                end: APR - 1,
+                kind: GapKind::Exchange,
+            }]
+        );
+
+        let again = downloader.sync("BTCUSDT", "1M", JAN, MAY).await.unwrap();
+        assert_eq!(again.downloaded, 0);
+        assert_eq!(source.requests().len(), 2);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn sync_spot_klines() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/api/v3/klines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1700006400000&interval=1h&limit=1000&startTime=1699999200000\
+                &symbol=BTCUSDT"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_klines_1h.json")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let dir = cache_dir("spot");
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+        let downloader = KlineDownloader::new(market, KlineCache::new(&dir));
+
+        let report = downloader
+            .sync("BTCUSDT", "1h", 1699999200000, 1700006400000)
+            .await
+            .unwrap();
+        let again = downloader
+            .sync("BTCUSDT", "1h", 1699999200000, 1700006400000)
+            .await
+            .unwrap();
+
+        mock.assert();
+        assert_eq!(report.downloaded, 3);
+        assert!(report.gaps.is_empty());
+        assert_eq!(again.downloaded, 0);
+
+        let path = downloader.cache().path("spot", "BTCUSDT", "1h");
+        assert_eq!(path, dir.join("spot").join("BTCUSDT").join("1h.csv"));
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("open_time,open,high,low,close"));
+        assert_eq!(
+            lines[1],
+            "1699999200000,37000.10,37150.00,36980.00,37100.50,812.43100000,1700002799999,\
+             30084541.39261470,25310,401.90200000,14885361.70012390"
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,244 @@
+use binance_rs_plus::account::*;
+use binance_rs_plus::api::*;
+use binance_rs_plus::config::*;
+use binance_rs_plus::margin::*;
+
+mod common;
+use common::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn borrow_isolated() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/sapi/v1/margin/borrow-repay")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "amount=400.5&asset=USDT&isIsolated=TRUE&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&type=BORROW&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/borrow.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let transaction = margin
+            .borrow("USDT", num("400.5"), Some("BTCUSDT".into()))
+            .await
+            .unwrap();
+        mock.assert();
+
+        assert_eq!(transaction.tran_id, 100000001);
+    }
+
+    #[tokio::test]
+    async fn place_with_side_effect() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/sapi/v1/margin/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "isIsolated=TRUE&price=60000&quantity=0.01&recvWindow=1234&side=BUY&sideEffectType=MARGIN_BUY&symbol=BTCUSDT&timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/new_order.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let order = SpotOrderRequest::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+            .set_quantity(num("0.01"))
+            .set_price(num("60000"))
+            .set_time_in_force(TimeInForce::GTC);
+        let order = MarginOrderRequest::new(order)
+            .set_isolated(true)
+            .set_side_effect_type(SideEffectType::MarginBuy);
+        let transaction = margin.place(order).await.unwrap();
+        mock.assert();
+
+        assert_eq!(transaction.transaction.order_id, 28);
+        assert_eq!(transaction.transaction.status, "NEW");
+        assert!(transaction.is_isolated);
+        assert_eq!(transaction.margin_buy_borrow_amount.unwrap(), num("400.5"));
+        assert_eq!(transaction.margin_buy_borrow_asset.as_deref(), Some("USDT"));
+    }
+
+    #[tokio::test]
+    async fn place_invalid_order() {
+        let margin: Margin = Binance::new(None, None);
+        let order = SpotOrderRequest::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+            .set_quantity(num("0.01"));
+        let result = margin.place(order).await;
+
+        assert!(matches!(
+            result,
+            Err(binance_rs_plus::errors::Error::InvalidOrder(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn place_oco() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/sapi/v1/margin/order/oco")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "isIsolated=FALSE&price=0.036435&quantity=0.624363&recvWindow=1234&side=BUY&sideEffectType=AUTO_REPAY&stopPrice=0.960664&symbol=LTCBTC&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/oco.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let order = OcoOrderRequest::new(
+            "LTCBTC",
+            OrderSide::Buy,
+            num("0.624363"),
+            num("0.036435"),
+            num("0.960664"),
+        );
+        let order = MarginOcoRequest::new(order).set_side_effect_type(SideEffectType::AutoRepay);
+        let order_list = margin.place_oco(order).await.unwrap();
+        mock.assert();
+
+        assert_eq!(order_list.order_list.contingency_type, "OCO");
+        assert_eq!(order_list.order_list.orders.len(), 2);
+        let reports = &order_list.order_list.order_reports;
+        assert_eq!(reports[0].type_name, "STOP_LOSS");
+        assert_eq!(reports[0].stop_price.unwrap(), num("0.960664"));
+        assert!(reports[1].stop_price.is_none());
+        assert!(!order_list.is_isolated);
+    }
+
+    #[tokio::test]
+    async fn account_details() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/sapi/v1/margin/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/account.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let account = margin.account_details().await.unwrap();
+        mock.assert();
+
+        assert_eq!(account.margin_level, num("11.64405625"));
+        assert_eq!(account.account_type.as_deref(), Some("MARGIN_1"));
+        assert_eq!(account.user_assets[1].asset, "USDT");
+        assert_eq!(account.user_assets[1].borrowed, num("400.5"));
+    }
+
+    #[tokio::test]
+    async fn isolated_account_details() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/sapi/v1/margin/isolated/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&symbols=BTCUSDT,ETHUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/isolated_account.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let info = margin
+            .isolated_account_details(&["BTCUSDT", "ETHUSDT"])
+            .await
+            .unwrap();
+        mock.assert();
+
+        let pair = &info.assets[0];
+        assert_eq!(pair.symbol, "BTCUSDT");
+        assert_eq!(pair.quote_asset.asset, "USDT");
+        assert_eq!(pair.margin_level_status, "EXCESSIVE");
+        assert!(info.total_asset_of_btc.is_none());
+    }
+
+    #[tokio::test]
+    async fn interest_history() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/sapi/v1/margin/interestHistory")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "asset=USDT&recvWindow=1234&size=10&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/interest_history.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let history = margin
+            .interest_history(InterestHistoryRequest {
+                asset: Some("USDT".into()),
+                size: Some(10),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        mock.assert();
+
+        assert_eq!(history.total, 1);
+        assert_eq!(history.rows[0].interest_type, "ON_BORROW");
+        assert_eq!(history.rows[0].isolated_symbol.as_deref(), Some("BNBUSDT"));
+    }
+
+    #[tokio::test]
+    async fn isolated_user_stream() {
+        let mut server = Server::new_async().await;
+        let start = server
+            .mock("POST", "/sapi/v1/userDataStream/isolated")
+            .match_body("symbol=BTCUSDT")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(
+                r#"{"listenKey":"T3ee22BIYuWqmvne0HNq2A2WsFlEtLhvWCtItw6ffhhdmjifQ2tRbuKkTHhr"}"#,
+            )
+            .create_async()
+            .await;
+        let keep_alive = server
+            .mock("PUT", "/sapi/v1/userDataStream/isolated")
+            .match_body(
+                "listenKey=T3ee22BIYuWqmvne0HNq2A2WsFlEtLhvWCtItw6ffhhdmjifQ2tRbuKkTHhr&symbol=BTCUSDT",
+            )
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let margin: Margin = Binance::new_with_config(Some("api_key".into()), None, &config);
+        let stream = margin.start_isolated_user_stream("BTCUSDT").await.unwrap();
+        margin
+            .keep_alive_isolated_user_stream("BTCUSDT", &stream.listen_key)
+            .await
+            .unwrap();
+        start.assert();
+        keep_alive.assert();
+    }
+}
@@ -3,11 +3,13 @@ use binance_rs_plus::config::*;
 use binance_rs_plus::market::*;
 use binance_rs_plus::model::*;
 
+mod common;
+use common::*;
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use mockito::{Server, Matcher};
-    use float_cmp::*;
 
     #[tokio::test] // Changed
     async fn get_depth() {
@@ -28,7 +30,7 @@ mod tests {
         mock_get_depth.assert();
 
         assert_eq!(order_book.last_update_id, 1027024);
-        assert_eq!(order_book.bids[0], Bids::new(4.00000000, 431.00000000));
+        assert_eq!(order_book.bids[0], Bids::new(num("4"), num("431")));
     }
 
     #[tokio::test] // Changed
@@ -50,7 +52,7 @@ mod tests {
         mock_get_custom_depth.assert();
 
         assert_eq!(order_book.last_update_id, 1027024);
-        assert_eq!(order_book.bids[0], Bids::new(4.00000000, 431.00000000));
+        assert_eq!(order_book.bids[0], Bids::new(num("4"), num("431")));
     }
 
     #[tokio::test] // Changed
@@ -75,10 +77,10 @@ mod tests {
                 assert!(!symbols.is_empty());
                 let first_symbol = symbols[0].clone();
                 assert_eq!(first_symbol.symbol, "LTCBTC");
-                assert!(approx_eq!(f64, first_symbol.price, 4.00000200, ulps = 2));
+                assert_eq!(first_symbol.price, num("4.00000200"));
                 let second_symbol = symbols[1].clone();
                 assert_eq!(second_symbol.symbol, "ETHBTC");
-                assert!(approx_eq!(f64, second_symbol.price, 0.07946600, ulps = 2));
+                assert_eq!(second_symbol.price, num("0.07946600"));
             }
         }
     }
@@ -102,7 +104,7 @@ mod tests {
         mock_get_price.assert();
 
         assert_eq!(symbol.symbol, "LTCBTC");
-        assert!(approx_eq!(f64, symbol.price, 4.00000200, ulps = 2));
+        assert_eq!(symbol.price, num("4.00000200"));
     }
 
     #[tokio::test] // Changed
@@ -124,7 +126,7 @@ mod tests {
         mock_get_average_price.assert();
 
         assert_eq!(symbol.mins, 5);
-        assert!(approx_eq!(f64, symbol.price, 9.35751834, ulps = 2));
+        assert_eq!(symbol.price, num("9.35751834"));
     }
 
     #[tokio::test] // Changed
@@ -149,46 +151,16 @@ mod tests {
                 assert!(!tickers.is_empty());
                 let first_ticker = tickers[0].clone();
                 assert_eq!(first_ticker.symbol, "LTCBTC");
-                assert!(approx_eq!(
-                    f64,
-                    first_ticker.bid_price,
-                    4.00000000,
-                    ulps = 2
-                ));
-                assert!(approx_eq!(
-                    f64,
-                    first_ticker.bid_qty,
-                    431.00000000,
-                    ulps = 2
-                ));
-                assert!(approx_eq!(
-                    f64,
-                    first_ticker.ask_price,
-                    4.00000200,
-                    ulps = 2
-                ));
-                assert!(approx_eq!(f64, first_ticker.ask_qty, 9.00000000, ulps = 2));
+                assert_eq!(first_ticker.bid_price, num("4.00000000"));
+                assert_eq!(first_ticker.bid_qty, num("431.00000000"));
+                assert_eq!(first_ticker.ask_price, num("4.00000200"));
+                assert_eq!(first_ticker.ask_qty, num("9.00000000"));
                 let second_ticker = tickers[1].clone();
                 assert_eq!(second_ticker.symbol, "ETHBTC");
-                assert!(approx_eq!(
-                    f64,
-                    second_ticker.bid_price,
-                    0.07946700,
-                    ulps = 2
-                ));
-                assert!(approx_eq!(f64, second_ticker.bid_qty, 9.00000000, ulps = 2));
-                assert!(approx_eq!(
-                    f64,
-                    second_ticker.ask_price,
-                    100000.00000000,
-                    ulps = 2
-                ));
-                assert!(approx_eq!(
-                    f64,
-                    second_ticker.ask_qty,
-                    1000.00000000,
-                    ulps = 2
-                ));
+                assert_eq!(second_ticker.bid_price, num("0.07946700"));
+                assert_eq!(second_ticker.bid_qty, num("9.00000000"));
+                assert_eq!(second_ticker.ask_price, num("100000.00000000"));
+                assert_eq!(second_ticker.ask_qty, num("1000.00000000"));
             }
         }
     }
@@ -212,10 +184,10 @@ mod tests {
         mock_get_book_ticker.assert();
 
         assert_eq!(book_ticker.symbol, "LTCBTC");
-        assert!(approx_eq!(f64, book_ticker.bid_price, 4.00000000, ulps = 2));
-        assert!(approx_eq!(f64, book_ticker.bid_qty, 431.00000000, ulps = 2));
-        assert!(approx_eq!(f64, book_ticker.ask_price, 4.00000200, ulps = 2));
-        assert!(approx_eq!(f64, book_ticker.ask_qty, 9.00000000, ulps = 2));
+        assert_eq!(book_ticker.bid_price, num("4.00000000"));
+        assert_eq!(book_ticker.bid_qty, num("431.00000000"));
+        assert_eq!(book_ticker.ask_price, num("4.00000200"));
+        assert_eq!(book_ticker.ask_qty, num("9.00000000"));
     }
 
     #[tokio::test] // Changed
@@ -237,37 +209,17 @@ mod tests {
         mock_get_24h_price_stats.assert();
 
         assert_eq!(price_stats.symbol, "BNBBTC");
-        assert_eq!(price_stats.price_change, "-94.99999800");
-        assert_eq!(price_stats.price_change_percent, "-95.960");
-        assert_eq!(price_stats.weighted_avg_price, "0.29628482");
-        assert!(approx_eq!(
-            f64,
-            price_stats.prev_close_price,
-            0.10002000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(
-            f64,
-            price_stats.last_price,
-            4.00000200,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, price_stats.bid_price, 4.00000000, ulps = 2));
-        assert!(approx_eq!(f64, price_stats.ask_price, 4.00000200, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            price_stats.open_price,
-            99.00000000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(
-            f64,
-            price_stats.high_price,
-            100.00000000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, price_stats.low_price, 0.10000000, ulps = 2));
-        assert!(approx_eq!(f64, price_stats.volume, 8913.30000000, ulps = 2));
+        assert_eq!(price_stats.price_change, num_string("-94.99999800"));
+        assert_eq!(price_stats.price_change_percent, num_string("-95.960"));
+        assert_eq!(price_stats.weighted_avg_price, num_string("0.29628482"));
+        assert_eq!(price_stats.prev_close_price, num("0.10002000"));
+        assert_eq!(price_stats.last_price, num("4.00000200"));
+        assert_eq!(price_stats.bid_price, num("4.00000000"));
+        assert_eq!(price_stats.ask_price, num("4.00000200"));
+        assert_eq!(price_stats.open_price, num("99.00000000"));
+        assert_eq!(price_stats.high_price, num("100.00000000"));
+        assert_eq!(price_stats.low_price, num("0.10000000"));
+        assert_eq!(price_stats.volume, num("8913.30000000"));
         assert_eq!(price_stats.open_time, 1499783499040);
         assert_eq!(price_stats.close_time, 1499869899040);
         assert_eq!(price_stats.first_id, 28385);
@@ -298,37 +250,17 @@ mod tests {
 
         assert_eq!(price_stats.symbol, "BNBBTC");
         // ... (rest of assertions remain the same)
-        assert_eq!(price_stats.price_change, "-94.99999800");
-        assert_eq!(price_stats.price_change_percent, "-95.960");
-        assert_eq!(price_stats.weighted_avg_price, "0.29628482");
-        assert!(approx_eq!(
-            f64,
-            price_stats.prev_close_price,
-            0.10002000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(
-            f64,
-            price_stats.last_price,
-            4.00000200,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, price_stats.bid_price, 4.00000000, ulps = 2));
-        assert!(approx_eq!(f64, price_stats.ask_price, 4.00000200, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            price_stats.open_price,
-            99.00000000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(
-            f64,
-            price_stats.high_price,
-            100.00000000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, price_stats.low_price, 0.10000000, ulps = 2));
-        assert!(approx_eq!(f64, price_stats.volume, 8913.30000000, ulps = 2));
+        assert_eq!(price_stats.price_change, num_string("-94.99999800"));
+        assert_eq!(price_stats.price_change_percent, num_string("-95.960"));
+        assert_eq!(price_stats.weighted_avg_price, num_string("0.29628482"));
+        assert_eq!(price_stats.prev_close_price, num("0.10002000"));
+        assert_eq!(price_stats.last_price, num("4.00000200"));
+        assert_eq!(price_stats.bid_price, num("4.00000000"));
+        assert_eq!(price_stats.ask_price, num("4.00000200"));
+        assert_eq!(price_stats.open_price, num("99.00000000"));
+        assert_eq!(price_stats.high_price, num("100.00000000"));
+        assert_eq!(price_stats.low_price, num("0.10000000"));
+        assert_eq!(price_stats.volume, num("8913.30000000"));
         assert_eq!(price_stats.open_time, 1499783499040);
         assert_eq!(price_stats.close_time, 1499869899040);
         assert_eq!(price_stats.first_id, 28385);
@@ -364,17 +296,72 @@ mod tests {
                 let kline: KlineSummary = klines_vec[0].clone();
 
                 assert_eq!(kline.open_time, 1499040000000);
-                assert_eq!(kline.open, "0.01634790");
-                assert_eq!(kline.high, "0.80000000");
-                assert_eq!(kline.low, "0.01575800");
-                assert_eq!(kline.close, "0.01577100");
-                assert_eq!(kline.volume, "148976.11427815");
+                assert_eq!(kline.open, num_string("0.01634790"));
+                assert_eq!(kline.high, num_string("0.80000000"));
+                assert_eq!(kline.low, num_string("0.01575800"));
+                assert_eq!(kline.close, num_string("0.01577100"));
+                assert_eq!(kline.volume, num_string("148976.11427815"));
                 assert_eq!(kline.close_time, 1499644799999);
-                assert_eq!(kline.quote_asset_volume, "2434.19055334");
+                assert_eq!(kline.quote_asset_volume, num_string("2434.19055334"));
                 assert_eq!(kline.number_of_trades, 308);
-                assert_eq!(kline.taker_buy_base_asset_volume, "1756.87402397");
-                assert_eq!(kline.taker_buy_quote_asset_volume, "28.46694368");
+                assert_eq!(
+                    kline.taker_buy_base_asset_volume,
+                    num_string("1756.87402397")
+                );
+                assert_eq!(
+                    kline.taker_buy_quote_asset_volume,
+                    num_string("28.46694368")
+                );
             }
         }
     }
+
+    #[tokio::test]
+    async fn get_recent_trades() {
+        let mut server = Server::new_async().await;
+        let mock_get_trades = server
+            .mock("GET", "/api/v3/trades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=2&symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_trades.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let trades = market.get_recent_trades("LTCBTC", 2).await.unwrap();
+        mock_get_trades.assert();
+
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].id, 28457);
+        assert_eq!(trades[0].price, num("4.000001"));
+        assert_eq!(trades[0].quote_qty, num("48.000012"));
+        assert!(trades[0].is_buyer_maker);
+        assert!(!trades[1].is_buyer_maker);
+    }
+
+    #[tokio::test]
+    async fn get_historical_trades() {
+        let mut server = Server::new_async().await;
+        let mock_get_trades = server
+            .mock("GET", "/api/v3/historicalTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("fromId=28457&symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_trades.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let trades = market
+            .get_historical_trades("LTCBTC", 28457, None)
+            .await
+            .unwrap();
+        mock_get_trades.assert();
+
+        assert_eq!(trades[1].id, 28458);
+        assert_eq!(trades[1].time, 1499865549612);
+    }
 }
@@ -0,0 +1,149 @@
+use binance_rs_plus::api::*;
+use binance_rs_plus::config::*;
+use binance_rs_plus::options::account::*;
+use binance_rs_plus::options::general::*;
+use binance_rs_plus::options::market::*;
+use binance_rs_plus::options::model::OptionSide;
+
+mod common;
+use common::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn exchange_info() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/eapi/v1/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/options/exchange_info.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_options_rest_api_endpoint(server.url());
+        let general: OptionsGeneral = Binance::new_with_config(None, None, &config);
+        let symbol = general.get_symbol_info("btc-250926-60000-p").await.unwrap();
+        mock.assert();
+
+        assert_eq!(symbol.symbol, "BTC-250926-60000-P");
+        assert_eq!(symbol.side, OptionSide::Put);
+        assert_eq!(symbol.underlying, "BTCUSDT");
+        assert_eq!(symbol.expiry_date, 1758873600000);
+        assert_eq!(symbol.strike_price, num("60000.0"));
+        assert_eq!(symbol.quantity_scale, 2);
+    }
+
+    #[tokio::test]
+    async fn mark_price() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/eapi/v1/mark")
+            .match_query(Matcher::UrlEncoded(
+                "symbol".into(),
+                "BTC-250926-60000-C".into(),
+            ))
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/options/mark.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_options_rest_api_endpoint(server.url());
+        let market: OptionsMarket = Binance::new_with_config(None, None, &config);
+        let marks = market.get_mark_price("BTC-250926-60000-C").await.unwrap();
+        mock.assert();
+
+        let mark = &marks[0];
+        assert_eq!(mark.mark_price, num("55760.0"));
+        assert_eq!(mark.mark_iv, num("0.4744"));
+        assert_eq!(mark.delta, num("0.99412003"));
+        assert_eq!(mark.theta, num("-12.87650034"));
+        assert_eq!(mark.gamma, num("0.00000112"));
+        assert_eq!(mark.vega, num("3.45006512"));
+    }
+
+    #[tokio::test]
+    async fn limit_buy() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/eapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "newOrderRespType=RESULT&price=55000&quantity=0.5&recvWindow=1234&side=BUY&symbol=BTC-250926-60000-C&timestamp=\\d+&type=LIMIT&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/options/new_order.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_options_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: OptionsAccount = Binance::new_with_config(None, None, &config);
+        let order = account
+            .limit_buy("BTC-250926-60000-C", num("0.5"), 55000.into())
+            .await
+            .unwrap();
+        mock.assert();
+
+        assert_eq!(order.order_id, 4729002318381056);
+        assert_eq!(order.status, "ACCEPTED");
+        assert_eq!(order.option_side, OptionSide::Call);
+        assert!(!order.mmp);
+    }
+
+    #[tokio::test]
+    async fn position_information() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/eapi/v1/position")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/options/position.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_options_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: OptionsAccount = Binance::new_with_config(None, None, &config);
+        let positions = account.position_information(None).await.unwrap();
+        mock.assert();
+
+        assert_eq!(positions[0].symbol, "ETH-250926-4000-P");
+        assert_eq!(positions[0].side, "SHORT");
+        assert_eq!(positions[0].option_side, OptionSide::Put);
+        assert_eq!(positions[0].quantity, num("-2.0"));
+        assert_eq!(positions[0].unrealized_pnl, num("-10.5"));
+    }
+
+    #[tokio::test]
+    async fn account_information() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/eapi/v1/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/options/account.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_options_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: OptionsAccount = Binance::new_with_config(None, None, &config);
+        let info = account.account_information().await.unwrap();
+        mock.assert();
+
+        assert_eq!(info.asset[0].asset, "USDT");
+        assert_eq!(info.asset[0].available, num("8725.92524"));
+        assert_eq!(info.greek[0].underlying, "BTCUSDT");
+        assert_eq!(info.greek[0].delta, num("-0.05"));
+        assert_eq!(info.risk_level.as_deref(), Some("NORMAL"));
+    }
+}
@@ -0,0 +1,307 @@
+use binance_rs_plus::api::*;
+use binance_rs_plus::config::*;
+use binance_rs_plus::market::*;
+use binance_rs_plus::model::DepthOrderBookEvent;
+use binance_rs_plus::orderbook::*;
+
+mod common;
+use common::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use binance_rs_plus::async_websocket_client::ReconnectPolicy;
+    use binance_rs_plus::errors::{Error, Result};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    // Counts snapshot requests; answers with `last_update_id` or fails when
+    // it is `None`.
+    struct FakeSource {
+        last_update_id: Option<u64>,
+        calls: AtomicUsize,
+    }
+
+    impl FakeSource {
+        fn new(last_update_id: Option<u64>) -> Self {
+            Self {
+                last_update_id,
+                calls: AtomicUsize::new(0),
+            }
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    impl DepthSnapshotSource for FakeSource {
+        async fn depth_snapshot(&self, _symbol: &str, _limit: u64) -> Result<DepthSnapshot> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            match self.last_update_id {
+                Some(id) => Ok(snapshot(id)),
+                None => Err(Error::Custom("snapshot unavailable".into())),
+            }
+        }
+    }
+
+    fn backoff(initial: Duration) -> ReconnectPolicy {
+        ReconnectPolicy::default()
+            .set_initial_backoff(initial)
+            .set_jitter(0.0)
+    }
+
+    fn depth_event(
+        first: u64, last: u64, prev: Option<u64>, bids: &[(&str, &str)], asks: &[(&str, &str)],
+    ) -> DepthOrderBookEvent {
+        let mut event = serde_json::json!({
+            "e": "depthUpdate",
+            "E": 1672515782136u64,
+            "s": "BNBBTC",
+            "U": first,
+            "u": last,
+            "b": bids.iter().map(|(p, q)| [p, q]).collect::<Vec<_>>(),
+            "a": asks.iter().map(|(p, q)| [p, q]).collect::<Vec<_>>(),
+        });
+        if let Some(pu) = prev {
+            event["pu"] = pu.into();
+        }
+        serde_json::from_value(event).unwrap()
+    }
+
+    fn snapshot(last_update_id: u64) -> DepthSnapshot {
+        DepthSnapshot {
+            last_update_id,
+            bids: vec![
+                PriceLevel {
+                    price: num("10"),
+                    qty: num("1"),
+                },
+                PriceLevel {
+                    price: num("9"),
+                    qty: num("2"),
+                },
+            ],
+            asks: vec![
+                PriceLevel {
+                    price: num("11"),
+                    qty: num("1.5"),
+                },
+                PriceLevel {
+                    price: num("12"),
+                    qty: num("3"),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn spot_buffers_until_snapshot_and_drops_stale_events() {
+        let mut book = LocalOrderBook::new("bnbbtc", BookMarket::Spot);
+
+        assert_eq!(
+            book.push(depth_event(90, 95, None, &[("10.0", "5.0")], &[])),
+            SyncState::AwaitingSnapshot
+        );
+        book.push(depth_event(
+            96,
+            102,
+            None,
+            &[("9.5", "1.0")],
+            &[("11.0", "0")],
+        ));
+        book.push(depth_event(103, 104, None, &[("10.0", "0")], &[]));
+
+        assert_eq!(book.apply_snapshot(snapshot(100)), SyncState::Synced);
+        assert_eq!(book.last_update_id(), 104);
+
+        // The first event was stale and must not have touched the book.
+        let best_bid = book.best_bid().unwrap();
+        assert_eq!(best_bid.price, num("9.5"));
+        let best_ask = book.best_ask().unwrap();
+        assert_eq!(best_ask.price, num("12.0"));
+        assert_eq!(book.bids(10).len(), 2);
+    }
+
+    #[test]
+    fn spot_gap_resets_book() {
+        let mut book = LocalOrderBook::new("BNBBTC", BookMarket::Spot);
+        book.apply_snapshot(snapshot(100));
+
+        assert_eq!(
+            book.push(depth_event(101, 105, None, &[], &[])),
+            SyncState::Synced
+        );
+        assert_eq!(
+            book.push(depth_event(107, 110, None, &[], &[])),
+            SyncState::AwaitingSnapshot
+        );
+        assert_eq!(book.resyncs(), 1);
+        assert!(book.best_bid().is_none());
+        assert!(book.ensure_synced().is_err());
+
+        // The event that revealed the gap is kept for the next snapshot.
+        assert_eq!(book.apply_snapshot(snapshot(108)), SyncState::Synced);
+        assert_eq!(book.last_update_id(), 110);
+    }
+
+    #[test]
+    fn spot_snapshot_older_than_stream_requires_resync() {
+        let mut book = LocalOrderBook::new("BNBBTC", BookMarket::Spot);
+        book.push(depth_event(150, 160, None, &[], &[]));
+
+        assert_eq!(
+            book.apply_snapshot(snapshot(100)),
+            SyncState::AwaitingSnapshot
+        );
+    }
+
+    #[test]
+    fn futures_uses_previous_final_update_id() {
+        let mut book = LocalOrderBook::new("BNBBTC", BookMarket::UsdMFutures);
+        book.push(depth_event(95, 100, Some(94), &[("10.5", "1.0")], &[]));
+        book.push(depth_event(101, 110, Some(100), &[], &[("11.0", "4.0")]));
+
+        assert_eq!(book.apply_snapshot(snapshot(100)), SyncState::Synced);
+        assert_eq!(book.last_update_id(), 110);
+        assert_eq!(book.best_bid().unwrap().price, num("10.5"));
+        assert_eq!(book.best_ask().unwrap().qty, num("4.0"));
+
+        // `U` does not need to follow `u`, only `pu` does.
+        assert_eq!(
+            book.push(depth_event(115, 120, Some(110), &[], &[])),
+            SyncState::Synced
+        );
+        assert_eq!(
+            book.push(depth_event(121, 125, Some(119), &[], &[])),
+            SyncState::AwaitingSnapshot
+        );
+    }
+
+    #[test]
+    fn top_levels_and_cumulative_depth() {
+        let mut book = LocalOrderBook::new("BNBBTC", BookMarket::Spot);
+        book.apply_snapshot(snapshot(100));
+
+        let bids = book.cumulative_bids(2);
+        assert_eq!(bids[0].qty, num("1.0"));
+        assert_eq!(bids[1].qty, num("3.0"));
+        let asks = book.cumulative_asks(5);
+        assert_eq!(asks.len(), 2);
+        assert_eq!(asks[1].qty, num("4.5"));
+
+        assert_eq!(book.bid_depth_to(num("9")), num("3.0"));
+        assert_eq!(book.ask_depth_to(num("11.5")), num("1.5"));
+        assert_eq!(book.spread().unwrap(), num("1.0"));
+    }
+
+    #[test]
+    fn ignores_other_symbols() {
+        let mut book = LocalOrderBook::new("ETHBTC", BookMarket::Spot);
+        book.push(depth_event(1, 2, None, &[], &[]));
+        assert_eq!(
+            book.apply_snapshot(snapshot(0)),
+            SyncState::AwaitingFirstEvent
+        );
+    }
+
+    #[tokio::test]
+    async fn update_fetches_snapshot() {
+        let mut server = Server::new_async().await;
+        let mock_get_depth = server
+            .mock("GET", "/api/v3/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=100&symbol=BNBBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_depth.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+        let mut book = LocalOrderBook::new("BNBBTC", BookMarket::Spot).set_depth_limit(100);
+
+        let state = book
+            .update(
+                depth_event(1027020, 1027030, None, &[("4.00000100", "1.0")], &[]),
+                &market,
+            )
+            .await
+            .unwrap();
+        mock_get_depth.assert();
+
+        assert_eq!(state, SyncState::Synced);
+        assert_eq!(book.last_update_id(), 1027030);
+        assert_eq!(book.best_bid().unwrap().price, num("4.000001"));
+        assert_eq!(book.best_ask().unwrap().price, num("4.000002"));
+    }
+
+    #[tokio::test]
+    async fn update_backs_off_after_failed_snapshot() {
+        let source = FakeSource::new(None);
+        let mut book = LocalOrderBook::new("BNBBTC", BookMarket::Spot)
+            .set_snapshot_backoff(backoff(Duration::from_secs(60)));
+
+        assert!(
+            book.update(depth_event(1, 2, None, &[], &[]), &source)
+                .await
+                .is_err()
+        );
+        for id in 3..10 {
+            let state = book
+                .update(depth_event(id, id, None, &[], &[]), &source)
+                .await
+                .unwrap();
+            assert_eq!(state, SyncState::AwaitingSnapshot);
+        }
+        assert_eq!(source.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn update_retries_snapshot_after_backoff() {
+        let source = FakeSource::new(Some(0));
+        let mut book = LocalOrderBook::new("BNBBTC", BookMarket::Spot)
+            .set_snapshot_backoff(backoff(Duration::from_millis(50)));
+
+        // The snapshot is older than the stream, so the first event resets the
+        // book and buffering continues until the backoff elapses.
+        let state = book
+            .update(depth_event(100, 101, None, &[], &[]), &source)
+            .await
+            .unwrap();
+        assert_eq!(state, SyncState::AwaitingSnapshot);
+        book.update(depth_event(102, 102, None, &[], &[]), &source)
+            .await
+            .unwrap();
+        assert_eq!(source.calls(), 1);
+
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        book.update(depth_event(103, 103, None, &[], &[]), &source)
+            .await
+            .unwrap();
+        assert_eq!(source.calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn update_resyncs_immediately_after_gap_in_synced_book() {
+        let source = FakeSource::new(Some(100));
+        let mut book = LocalOrderBook::new("BNBBTC", BookMarket::Spot)
+            .set_snapshot_backoff(backoff(Duration::from_secs(60)));
+
+        let state = book
+            .update(depth_event(99, 101, None, &[], &[]), &source)
+            .await
+            .unwrap();
+        assert_eq!(state, SyncState::Synced);
+
+        // The gap is not backed off; the stale snapshot that follows is.
+        book.update(depth_event(105, 106, None, &[], &[]), &source)
+            .await
+            .unwrap();
+        book.update(depth_event(107, 107, None, &[], &[]), &source)
+            .await
+            .unwrap();
+        assert_eq!(source.calls(), 2);
+        assert_eq!(book.resyncs(), 2);
+    }
+}
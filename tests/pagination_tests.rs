@@ -0,0 +1,270 @@
+use binance_rs_plus::api::*;
+use binance_rs_plus::config::*;
+use binance_rs_plus::pagination::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use binance_rs_plus::errors::{Error, Result};
+    use binance_rs_plus::futures::account::{FuturesAccount, IncomeRequest};
+    use binance_rs_plus::market::Market;
+    use futures_util::StreamExt;
+    use mockito::{Matcher, Server};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Record {
+        id: u64,
+        time: u64,
+    }
+
+    impl Paged for Record {
+        type Key = u64;
+
+        fn key(&self) -> u64 {
+            self.id
+        }
+
+        fn time(&self) -> u64 {
+            self.time
+        }
+    }
+
+    fn records(times: &[u64]) -> Vec<Record> {
+        times
+            .iter()
+            .enumerate()
+            .map(|(i, &time)| Record {
+                id: i as u64 + 1,
+                time,
+            })
+            .collect()
+    }
+
+    // Answers a page the way the exchange does: by id when `from_id` is set,
+    // by time range otherwise, oldest first and at most `limit` long.
+    fn serve(data: &[Record], page: Page) -> Vec<Record> {
+        data.iter()
+            .filter(|r| match page.from_id {
+                Some(from_id) => r.id >= from_id,
+                None => r.time >= page.start_time.unwrap() && r.time <= page.end_time.unwrap(),
+            })
+            .take(usize::from(page.limit))
+            .cloned()
+            .collect()
+    }
+
+    async fn collect_by_id(
+        data: Vec<Record>, start: u64, end: u64, limits: PageLimits,
+    ) -> (Vec<Result<Record>>, Vec<Page>) {
+        let pages = Arc::new(Mutex::new(Vec::new()));
+        let seen = pages.clone();
+        let stream = by_id(start, end, limits, move |page| {
+            seen.lock().unwrap().push(page);
+            let result = serve(&data, page);
+            async move { Ok(result) }
+        });
+        let items = stream.collect::<Vec<_>>().await;
+        let pages = pages.lock().unwrap().clone();
+        (items, pages)
+    }
+
+    async fn collect_by_time(
+        data: Vec<Record>, start: u64, end: u64, limits: PageLimits,
+    ) -> (Vec<Result<Record>>, Vec<Page>) {
+        let pages = Arc::new(Mutex::new(Vec::new()));
+        let seen = pages.clone();
+        let stream = by_time(start, end, limits, move |page| {
+            seen.lock().unwrap().push(page);
+            let result = serve(&data, page);
+            async move { Ok(result) }
+        });
+        let items = stream.collect::<Vec<_>>().await;
+        let pages = pages.lock().unwrap().clone();
+        (items, pages)
+    }
+
+    fn ids(items: Vec<Result<Record>>) -> Vec<u64> {
+        items.into_iter().map(|r| r.unwrap().id).collect()
+    }
+
+    fn time_page(start: u64, end: u64, limit: u16) -> Page {
+        Page {
+            from_id: None,
+            start_time: Some(start),
+            end_time: Some(end),
+            limit,
+        }
+    }
+
+    fn id_page(from_id: u64, limit: u16) -> Page {
+        Page {
+            from_id: Some(from_id),
+            start_time: None,
+            end_time: None,
+            limit,
+        }
+    }
+
+    #[tokio::test]
+    async fn by_id_switches_to_from_id_on_full_window() {
+        let data = records(&[10, 10, 20, 30, 40, 50, 60]);
+        let (items, pages) = collect_by_id(data, 0, 55, PageLimits::new(100, 3)).await;
+
+        assert_eq!(ids(items), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(
+            pages,
+            vec![time_page(0, 55, 3), id_page(4, 3), id_page(7, 3)]
+        );
+    }
+
+    #[tokio::test]
+    async fn by_id_splits_range_into_windows() {
+        let data = records(&[5, 150]);
+        let (items, pages) = collect_by_id(data, 0, 299, PageLimits::new(100, 3)).await;
+
+        assert_eq!(ids(items), vec![1, 2]);
+        assert_eq!(
+            pages,
+            vec![
+                time_page(0, 99, 3),
+                time_page(100, 199, 3),
+                time_page(200, 299, 3)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn by_time_skips_records_seen_at_page_boundary() {
+        let data = records(&[10, 20, 20, 30, 40]);
+        let (items, pages) = collect_by_time(data, 0, 100, PageLimits::new(1000, 3)).await;
+
+        assert_eq!(ids(items), vec![1, 2, 3, 4, 5]);
+        assert_eq!(
+            pages,
+            vec![
+                time_page(0, 100, 3),
+                time_page(20, 100, 3),
+                time_page(30, 100, 3)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn by_time_fails_when_one_millisecond_exceeds_limit() {
+        let data = records(&[10, 20, 20, 20]);
+        let (items, _) = collect_by_time(data, 0, 100, PageLimits::new(1000, 2)).await;
+
+        assert_eq!(items.len(), 4);
+        assert!(items[..3].iter().all(Result::is_ok));
+        match &items[3] {
+            Err(Error::Custom(msg)) => assert!(msg.contains("more than 2 records")),
+            other => panic!("Expected Error::Custom, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn error_ends_stream() {
+        let calls = Arc::new(Mutex::new(0));
+        let counted = calls.clone();
+        let stream = by_id(0, 1000, PageLimits::new(100, 3), move |_| {
+            *counted.lock().unwrap() += 1;
+            async { Err::<Vec<Record>, _>(Error::Custom("boom".into())) }
+        });
+        let items = stream.collect::<Vec<_>>().await;
+
+        assert_eq!(items.len(), 1);
+        assert!(items[0].is_err());
+        assert_eq!(*calls.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn iter_agg_trades() {
+        let mut server = Server::new_async().await;
+        let first_hour = server
+            .mock("GET", "/api/v3/aggTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1700003599999&limit=1000&startTime=1700000000000&symbol=LTCBTC".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_agg_trades.json")
+            .create_async()
+            .await;
+        let second_hour = server
+            .mock("GET", "/api/v3/aggTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1700005000000&limit=1000&startTime=1700003600000&symbol=LTCBTC".into(),
+            ))
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+        let trades = market
+            .iter_agg_trades("LTCBTC", 1700000000000, 1700005000000)
+            .collect::<Vec<_>>()
+            .await;
+
+        first_hour.assert();
+        second_hour.assert();
+        let agg_ids: Vec<u64> = trades.into_iter().map(|t| t.unwrap().agg_id).collect();
+        assert_eq!(agg_ids, vec![26129, 26130]);
+    }
+
+    #[tokio::test]
+    async fn iter_income() {
+        let mut server = Server::new_async().await;
+        let mut mocks = Vec::new();
+        for (page, start_time) in [
+            (1, 1570608000000u64),
+            (2, 1570636800000),
+            (3, 1570665600000),
+        ] {
+            let mock = server
+                .mock("GET", "/fapi/v1/income")
+                .with_header("content-type", "application/json;charset=UTF-8")
+                .match_query(Matcher::Regex(format!(
+                    "endTime=1570700000000&limit=3&recvWindow=1234&startTime={}\
+                    &symbol=BTCUSDT&timestamp=\\d+&signature=.*",
+                    start_time
+                )))
+                .with_body_from_file(format!(
+                    "tests/mocks/futures/account/income_page_{}.json",
+                    page
+                ))
+                .create_async()
+                .await;
+            mocks.push(mock);
+        }
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let income_request = IncomeRequest {
+            symbol: Some("BTCUSDT".into()),
+            income_type: None,
+            start_time: Some(1570608000000),
+            end_time: Some(1570700000000),
+            limit: Some(3),
+        };
+        let incomes = account
+            .iter_income(income_request)
+            .collect::<Vec<_>>()
+            .await;
+
+        for mock in &mocks {
+            mock.assert();
+        }
+        let types: Vec<String> = incomes
+            .into_iter()
+            .map(|i| i.unwrap().income_type)
+            .collect();
+        assert_eq!(
+            types,
+            vec!["TRANSFER", "COMMISSION", "REALIZED_PNL", "FUNDING_FEE"]
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use binance_rs_plus::api::*;
+use binance_rs_plus::config::*;
+use binance_rs_plus::errors::Error;
+use binance_rs_plus::general::*;
+use binance_rs_plus::market::*;
+use binance_rs_plus::rate_limit::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn weight_usage(limiter: &RateLimiter) -> RateLimitUsage {
+        limiter
+            .usage()
+            .into_iter()
+            .find(|u| {
+                u.kind == RateLimitKind::RequestWeight && u.interval == Duration::from_secs(60)
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn endpoint_weights() {
+        assert_eq!(API::Spot(Spot::Account).weight(None), 20);
+        assert_eq!(API::Futures(Futures::Income).weight(None), 30);
+        assert_eq!(API::Spot(Spot::Depth).weight(None), 5);
+        assert_eq!(API::Spot(Spot::Depth).weight(Some(1000)), 50);
+        assert_eq!(API::Spot(Spot::Depth).weight(Some(5000)), 250);
+        assert_eq!(API::Futures(Futures::Depth).weight(None), 10);
+        assert_eq!(API::Futures(Futures::Depth).weight(Some(1000)), 20);
+        assert_eq!(API::Futures(Futures::Klines).weight(Some(1500)), 10);
+        assert!(API::Spot(Spot::Order).counts_as_order());
+        assert!(!API::Spot(Spot::OrderTest).counts_as_order());
+    }
+
+    #[test]
+    fn reject_mode_fails_before_limit() {
+        let limiter = RateLimiter::new(vec![
+            Limit::new(RateLimitKind::RequestWeight, Duration::from_secs(60), 10),
+            Limit::new(RateLimitKind::Orders, Duration::from_secs(10), 1),
+        ])
+        .with_mode(ThrottleMode::Reject);
+
+        limiter.try_acquire(6, true).unwrap();
+        match limiter.try_acquire(1, true) {
+            Err(Error::RateLimited {
+                retry_after,
+                banned,
+                ..
+            }) => {
+                assert!(!banned);
+                assert!(retry_after <= Duration::from_secs(10));
+            }
+            other => panic!("expected RateLimited, got {:?}", other),
+        }
+        limiter.try_acquire(4, false).unwrap();
+        assert!(limiter.try_acquire(1, false).is_err());
+        assert_eq!(weight_usage(&limiter).used, 10);
+    }
+
+    #[tokio::test]
+    async fn wait_mode_rejects_cost_above_limit() {
+        let limiter = RateLimiter::new(vec![Limit::new(
+            RateLimitKind::RequestWeight,
+            Duration::from_secs(60),
+            10,
+        )]);
+
+        let acquired = tokio::time::timeout(Duration::from_secs(1), limiter.acquire(11, false))
+            .await
+            .expect("acquire must not wait for a cost that can never fit");
+        match acquired {
+            Err(Error::CostAboveLimit {
+                kind, cost, limit, ..
+            }) => {
+                assert_eq!(kind, RateLimitKind::RequestWeight);
+                assert_eq!((cost, limit), (11, 10));
+            }
+            other => panic!("expected CostAboveLimit, got {:?}", other),
+        }
+        assert_eq!(weight_usage(&limiter).used, 0);
+        limiter.acquire(10, false).await.unwrap();
+    }
+
+    #[test]
+    fn default_services_share_one_budget() {
+        let general: General = Binance::new(None, None);
+        let market: Market = Binance::new(None, None);
+
+        let before = weight_usage(market.client.rate_limiter()).used;
+        general.client.rate_limiter().try_acquire(7, false).unwrap();
+        assert_eq!(weight_usage(market.client.rate_limiter()).used, before + 7);
+    }
+
+    #[tokio::test]
+    async fn usage_follows_response_headers() {
+        let mut server = Server::new_async().await;
+        let mock_price = server
+            .mock("GET", "/api/v3/ticker/price")
+            .match_query(mockito::Matcher::Any)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_header("x-mbx-used-weight-1m", "1234")
+            .with_header("x-mbx-order-count-10s", "3")
+            .with_body(r#"{"symbol":"LTCBTC","price":"4.00000200"}"#)
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_rate_limiter(Arc::new(RateLimiter::spot()));
+        let market: Market = Binance::new_with_config(None, None, &config);
+        market.get_price("LTCBTC").await.unwrap();
+        mock_price.assert();
+
+        let usage = config.rate_limiter.usage();
+        assert_eq!(weight_usage(&config.rate_limiter).used, 1234);
+        assert!(usage.iter().any(|u| u.kind == RateLimitKind::Orders
+            && u.interval == Duration::from_secs(10)
+            && u.used == 3));
+    }
+
+    #[tokio::test]
+    async fn depth_weight_follows_limit() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/api/v3/depth")
+            .match_query(mockito::Matcher::Any)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/market/get_depth.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_rate_limiter(Arc::new(RateLimiter::spot()));
+        let market: Market = Binance::new_with_config(None, None, &config);
+        market.get_custom_depth("LTCBTC", 1000).await.unwrap();
+
+        assert_eq!(weight_usage(&config.rate_limiter).used, 50);
+    }
+
+    #[tokio::test]
+    async fn exchange_info_sets_limits() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/exchange_info.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_rate_limiter(Arc::new(RateLimiter::spot()));
+        let general: General = Binance::new_with_config(None, None, &config);
+        general.exchange_info().await.unwrap();
+
+        assert_eq!(weight_usage(&config.rate_limiter).limit, Some(1200));
+    }
+
+    #[tokio::test]
+    async fn too_many_requests_blocks_shared_clients() {
+        let mut server = Server::new_async().await;
+        let mock_ping = server
+            .mock("GET", "/api/v3/ping")
+            .with_status(429)
+            .with_header("retry-after", "30")
+            .with_body(r#"{"code":-1003,"msg":"Too many requests."}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let limiter = Arc::new(RateLimiter::spot());
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_rate_limiter(limiter.clone());
+        let general: General = Binance::new_with_config(None, None, &config);
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        match general.ping().await {
+            Err(Error::Http(http)) => {
+                assert_eq!(http.status.as_u16(), 429);
+                assert_eq!(http.retry_after, Some(Duration::from_secs(30)));
+            }
+            other => panic!("expected Http, got {:?}", other),
+        }
+        // Rejected locally: the mock expects exactly one hit.
+        match market.get_price("LTCBTC").await {
+            Err(Error::RateLimited {
+                retry_after,
+                banned,
+                ..
+            }) => {
+                assert!(banned);
+                assert!(retry_after > Duration::from_secs(25));
+            }
+            other => panic!("expected RateLimited, got {:?}", other),
+        }
+        mock_ping.assert();
+        assert!(limiter.blocked_for().is_some());
+    }
+}
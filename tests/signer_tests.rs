@@ -0,0 +1,93 @@
+use binance_rs_plus::account::*;
+use binance_rs_plus::api::*;
+use binance_rs_plus::config::*;
+use binance_rs_plus::signer::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    // Example request from the Binance API documentation.
+    const PAYLOAD: &str = "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1&recvWindow=5000&timestamp=1499827319559";
+
+    // Signatures below were produced with `openssl pkeyutl`/`openssl dgst` from the keys in tests/mocks/signer.
+    const ED25519_SIGNATURE: &str =
+        "r6bQ9LK9nmHOmQE/LT5kn7TGT4arp7SdsfOxGvEwBZSGiXbtvJA71IIA34ErLljIJbRe7EltK+j4eDcoagDiBQ==";
+    const RSA_SIGNATURE: &str = "ikCLYsqId0/zmPdyA1QVSyAZd85V7j6UAyK5+B29dUdHAGdI6zH2QnEK4wHLim43RVpLeHIYlSpQR5Ab4IPwc1lArBZ6T5tfoGUE9249unuDarf9NaaT1pwFq2lsY4X2r4CiVsWBrnV5F9kcHtTurgmmGqKBtdXAZAaQqpYYC987IU56pLseV0nqgZa6MHV8S9nLs8l6zjfj1H8Kc0HqknkkaxGt/FVoC+90WKhMG4lq3CRseJUrzfDEW0Ka14cxJ0bJNUUIv+YnKlVsa/jYxM1XH0lvsxKxLs8+TTKngfekTZtO7fWwG1KrubbIgwwXL1OtYw+zxeKwh54zr6dgzg==";
+
+    fn ed25519() -> Ed25519Signer {
+        Ed25519Signer::from_pkcs8_pem(include_str!("mocks/signer/ed25519.pem")).unwrap()
+    }
+
+    fn encoded(signature: &str) -> String {
+        signature
+            .replace('+', "%2B")
+            .replace('/', "%2F")
+            .replace('=', "%3D")
+    }
+
+    #[test]
+    fn hmac_matches_documentation() {
+        let signer =
+            HmacSigner::new("NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j");
+        assert_eq!(
+            sign_query(&signer, PAYLOAD).unwrap(),
+            format!(
+                "{}&signature=c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71",
+                PAYLOAD
+            )
+        );
+    }
+
+    #[test]
+    fn ed25519_vector() {
+        let signer = ed25519();
+        assert_eq!(signer.sign(PAYLOAD.as_bytes()).unwrap(), ED25519_SIGNATURE);
+        assert_eq!(
+            sign_query(&signer, PAYLOAD).unwrap(),
+            format!("{}&signature={}", PAYLOAD, encoded(ED25519_SIGNATURE))
+        );
+    }
+
+    #[test]
+    fn rsa_vector() {
+        let signer = RsaSigner::from_pkcs8_pem(include_str!("mocks/signer/rsa.pem")).unwrap();
+        assert_eq!(signer.sign(PAYLOAD.as_bytes()).unwrap(), RSA_SIGNATURE);
+        assert_eq!(
+            sign_query(&signer, PAYLOAD).unwrap(),
+            format!("{}&signature={}", PAYLOAD, encoded(RSA_SIGNATURE))
+        );
+    }
+
+    #[test]
+    fn invalid_pem_is_an_error() {
+        assert!(Ed25519Signer::from_pkcs8_pem("not a key").is_err());
+        assert!(RsaSigner::from_pkcs8_pem(include_str!("mocks/signer/ed25519.pem")).is_err());
+    }
+
+    #[tokio::test]
+    async fn config_signer_signs_requests() {
+        let mut server = Server::new_async().await;
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_header("x-mbx-apikey", "ed25519-api-key")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&timestamp=\\d+&signature=[A-Za-z0-9%]+$".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234)
+            .set_signer(ed25519());
+        let account: Account =
+            Binance::new_with_config(Some("ed25519-api-key".into()), None, &config);
+        account.get_account().await.unwrap();
+
+        mock_get_account.assert();
+    }
+}
@@ -0,0 +1,108 @@
+use binance_rs_plus::streams::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spot_stream_names() {
+        assert_eq!(Stream::agg_trade("BTCUSDT").to_string(), "btcusdt@aggTrade");
+        assert_eq!(Stream::trade("ethBTC").to_string(), "ethbtc@trade");
+        assert_eq!(
+            Stream::kline("BTCUSDT", KlineInterval::Minutes1).to_string(),
+            "btcusdt@kline_1m"
+        );
+        assert_eq!(
+            Stream::kline("BTCUSDT", KlineInterval::Months1).to_string(),
+            "btcusdt@kline_1M"
+        );
+        assert_eq!(
+            Stream::book_ticker("BNBBTC").to_string(),
+            "bnbbtc@bookTicker"
+        );
+        assert_eq!(Stream::AllBookTickers.to_string(), "!bookTicker");
+        assert_eq!(
+            Stream::mini_ticker("BNBBTC").to_string(),
+            "bnbbtc@miniTicker"
+        );
+        assert_eq!(Stream::AllMiniTickers.to_string(), "!miniTicker@arr");
+        assert_eq!(Stream::ticker("BNBBTC").to_string(), "bnbbtc@ticker");
+        assert_eq!(Stream::AllTickers.to_string(), "!ticker@arr");
+        assert_eq!(
+            Stream::window_ticker("BNBBTC", TickerWindow::Hours4).to_string(),
+            "bnbbtc@ticker_4h"
+        );
+        assert_eq!(
+            Stream::AllWindowTickers {
+                window: TickerWindow::Days1
+            }
+            .to_string(),
+            "!ticker_1d@arr"
+        );
+    }
+
+    #[test]
+    fn depth_stream_names() {
+        let diff = Stream::Depth {
+            symbol: "BNBBTC".into(),
+            levels: None,
+            speed: None,
+        };
+        assert_eq!(diff.to_string(), "bnbbtc@depth");
+        assert_eq!(
+            Stream::depth("BNBBTC", UpdateSpeed::Ms100).to_string(),
+            "bnbbtc@depth@100ms"
+        );
+        assert_eq!(
+            Stream::partial_depth("BTCUSDT", DepthLevels::Twenty, UpdateSpeed::Ms100).to_string(),
+            "btcusdt@depth20@100ms"
+        );
+        let partial = Stream::Depth {
+            symbol: "BTCUSDT".into(),
+            levels: Some(DepthLevels::Five),
+            speed: None,
+        };
+        assert_eq!(partial.to_string(), "btcusdt@depth5");
+        assert_eq!(
+            Stream::depth("BNBBTC", UpdateSpeed::Ms1000).to_string(),
+            "bnbbtc@depth"
+        );
+        assert_eq!(
+            Stream::partial_depth("BTCUSDT", DepthLevels::Ten, UpdateSpeed::Ms1000).to_string(),
+            "btcusdt@depth10"
+        );
+    }
+
+    #[test]
+    fn futures_stream_names() {
+        assert_eq!(
+            Stream::mark_price("BTCUSDT", PriceSpeed::Secs3).to_string(),
+            "btcusdt@markPrice"
+        );
+        assert_eq!(
+            Stream::mark_price("BTCUSDT", PriceSpeed::Secs1).to_string(),
+            "btcusdt@markPrice@1s"
+        );
+        assert_eq!(
+            Stream::AllMarkPrices {
+                speed: PriceSpeed::Secs1
+            }
+            .to_string(),
+            "!markPrice@arr@1s"
+        );
+        assert_eq!(
+            Stream::continuous_kline("BTCUSDT", ContractType::Perpetual, KlineInterval::Minutes5)
+                .to_string(),
+            "btcusdt_perpetual@continuousKline_5m"
+        );
+        assert_eq!(
+            Stream::index_price("BTCUSD", PriceSpeed::Secs1).to_string(),
+            "btcusd@indexPrice@1s"
+        );
+        assert_eq!(
+            Stream::force_order("BTCUSDT").to_string(),
+            "btcusdt@forceOrder"
+        );
+        assert_eq!(Stream::AllForceOrders.to_string(), "!forceOrder@arr");
+    }
+}
@@ -0,0 +1,252 @@
+use binance_rs_plus::account::*;
+use binance_rs_plus::api::*;
+use binance_rs_plus::config::*;
+use binance_rs_plus::errors::Error;
+use binance_rs_plus::general::*;
+use binance_rs_plus::symbol_rules::*;
+
+mod common;
+use common::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use binance_rs_plus::futures::account::{CustomOrderRequest, FuturesAccount};
+    use mockito::Server;
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    async fn bnbbtc_rules() -> SymbolRules {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/exchange_info.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let general: General = Binance::new_with_config(None, None, &config);
+        let symbol = general.get_symbol_info("BNBBTC").await.unwrap();
+        SymbolRules::try_from(&symbol).unwrap()
+    }
+
+    #[tokio::test]
+    async fn parses_spot_filters() {
+        let rules = bnbbtc_rules().await;
+
+        assert_eq!(rules.symbol, "BNBBTC");
+        let price = rules.price.as_ref().unwrap();
+        assert_eq!(price.tick_size, dec("0.0000001"));
+        assert_eq!(rules.lot_size.as_ref().unwrap().step_size, dec("0.01"));
+        assert!(rules.market_lot_size.as_ref().unwrap().step_size.is_zero());
+        let notional = rules.notional.as_ref().unwrap();
+        assert_eq!(notional.min_notional, Some(dec("0.0001")));
+        assert!(notional.apply_to_market);
+        assert_eq!(
+            rules.percent_price.as_ref().unwrap().multiplier_up,
+            dec("5")
+        );
+    }
+
+    #[tokio::test]
+    async fn rounds_onto_the_exchange_grid() {
+        let rules = bnbbtc_rules().await;
+
+        assert_eq!(rules.round_price(num("0.00123456")), num("0.0012346"));
+        assert_eq!(rules.round_price(num("0.00123449")), num("0.0012345"));
+        assert_eq!(rules.round_qty(num("1.23956")), num("1.23"));
+        // 0.29 / 0.01 is 28.999999999999996 in binary floating point.
+        assert_eq!(rules.round_qty(num("0.29")), num("0.29"));
+        // MARKET_LOT_SIZE has no step, so nothing to round.
+        assert_eq!(rules.round_market_qty(num("1.23956")), num("1.23956"));
+
+        assert!(
+            rules
+                .check_price(rules.round_price(num("0.00123456")))
+                .is_ok()
+        );
+        assert!(rules.check_qty(rules.round_qty(num("0.29")), false).is_ok());
+    }
+
+    #[tokio::test]
+    async fn reports_typed_violations() {
+        let rules = bnbbtc_rules().await;
+
+        assert_eq!(
+            rules.check_price(num("0.00123456")),
+            Err(FilterError::PriceNotOnTick {
+                price: dec("0.00123456"),
+                tick_size: dec("0.0000001"),
+            })
+        );
+        assert!(matches!(
+            rules.check_price(num("0.00000001")),
+            Err(FilterError::PriceTooLow { .. })
+        ));
+        assert!(matches!(
+            rules.check_qty(num("0.001"), false),
+            Err(FilterError::QuantityTooLow { .. })
+        ));
+        assert!(matches!(
+            rules.check_qty(num("0.015"), false),
+            Err(FilterError::QuantityNotOnStep { .. })
+        ));
+        assert!(matches!(
+            rules.check_qty(num("9000"), true),
+            Err(FilterError::QuantityTooHigh { .. })
+        ));
+        assert_eq!(
+            rules.validate(Some(num("0.0000005")), Some(num("0.01")), false),
+            Err(FilterError::NotionalTooLow {
+                notional: dec("0.000000005"),
+                min: dec("0.0001"),
+            })
+        );
+        assert!(
+            rules
+                .validate(Some(num("0.0012345")), Some(num("1")), false)
+                .is_ok()
+        );
+
+        assert!(
+            rules
+                .check_percent_price(num("0.001"), num("0.0012"))
+                .is_ok()
+        );
+        assert!(matches!(
+            rules.check_percent_price(num("0.0001"), num("0.0012")),
+            Err(FilterError::PriceOutsideBand { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn account_checks_registered_rules() {
+        let rules = bnbbtc_rules().await;
+        let mut server = Server::new_async().await;
+        let mock_order = server
+            .mock("POST", "/api/v3/order")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let mut account: Account = Binance::new_with_config(None, None, &config);
+        account.set_symbol_rules(rules);
+
+        match account
+            .limit_buy("BNBBTC", num("1"), num("0.00123456"))
+            .await
+        {
+            Err(Error::Filter(FilterError::PriceNotOnTick { .. })) => {}
+            other => panic!("Expected a tick size violation, got {:?}", other),
+        }
+        match account
+            .market_buy_using_quote_quantity("BNBBTC", num("0.00001"))
+            .await
+        {
+            Err(Error::Filter(FilterError::NotionalTooLow { .. })) => {}
+            other => panic!("Expected a notional violation, got {:?}", other),
+        }
+
+        mock_order.assert();
+    }
+
+    #[tokio::test]
+    async fn account_checks_rules_of_oco_orders() {
+        let rules = bnbbtc_rules().await;
+        let mut server = Server::new_async().await;
+        let mock_oco = server
+            .mock("POST", "/api/v3/order/oco")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let mut account: Account = Binance::new_with_config(None, None, &config);
+        account.set_symbol_rules(rules);
+
+        let num = |value: &str| value.parse().unwrap();
+        let off_tick_stop = OcoOrderRequest::new(
+            "BNBBTC",
+            OrderSide::Sell,
+            num("1"),
+            num("0.0013"),
+            num("0.00123456"),
+        );
+        match account.place_oco(off_tick_stop).await {
+            Err(Error::Filter(FilterError::PriceNotOnTick { .. })) => {}
+            other => panic!("Expected a tick size violation, got {:?}", other),
+        }
+        let off_step_qty = OcoOrderRequest::new(
+            "BNBBTC",
+            OrderSide::Sell,
+            num("1.005"),
+            num("0.0013"),
+            num("0.0011"),
+        );
+        match account.place_oco(off_step_qty).await {
+            Err(Error::Filter(FilterError::QuantityNotOnStep { .. })) => {}
+            other => panic!("Expected a step size violation, got {:?}", other),
+        }
+
+        mock_oco.assert();
+    }
+
+    #[tokio::test]
+    async fn futures_account_checks_registered_rules() {
+        let symbol: binance_rs_plus::futures::model::Symbol = serde_json::from_str(
+            r#"{
+                "symbol": "BTCUSDT", "status": "TRADING", "maintMarginPercent": "2.5000",
+                "requiredMarginPercent": "5.0000", "baseAsset": "BTC", "quoteAsset": "USDT",
+                "onboardDate": 1569398400000, "pricePrecision": 2, "quantityPrecision": 3,
+                "baseAssetPrecision": 8, "quotePrecision": 8,
+                "orderTypes": ["LIMIT", "MARKET"], "timeInForce": ["GTC"],
+                "filters": [
+                    {"filterType": "PRICE_FILTER", "minPrice": "556.80", "maxPrice": "4529764", "tickSize": "0.10"},
+                    {"filterType": "LOT_SIZE", "minQty": "0.001", "maxQty": "1000", "stepSize": "0.001"},
+                    {"filterType": "MARKET_LOT_SIZE", "minQty": "0.001", "maxQty": "120", "stepSize": "0.001"},
+                    {"filterType": "MIN_NOTIONAL", "notional": "100"},
+                    {"filterType": "PERCENT_PRICE", "multiplierUp": "1.0500", "multiplierDown": "0.9500", "multiplierDecimal": "4"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let rules = SymbolRules::try_from(&symbol).unwrap();
+        assert_eq!(
+            rules.notional.as_ref().unwrap().min_notional,
+            Some(dec("100"))
+        );
+
+        let mut account: FuturesAccount = Binance::new(None, None);
+        account.set_symbol_rules(rules);
+        let order = CustomOrderRequest {
+            symbol: "BTCUSDT".into(),
+            side: OrderSide::Buy,
+            position_side: None,
+            order_type: binance_rs_plus::futures::account::OrderType::Limit,
+            time_in_force: Some(binance_rs_plus::futures::account::TimeInForce::GTC),
+            quantity: Some(num("0.001")),
+            reduce_only: None,
+            price: Some(num("30000")),
+            new_client_order_id: None,
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+        };
+
+        match account.custom_order(order).await {
+            Err(Error::Filter(FilterError::NotionalTooLow { notional, .. })) => {
+                assert_eq!(notional, dec("30"));
+            }
+            other => panic!("Expected a notional violation, got {:?}", other),
+        }
+    }
+}
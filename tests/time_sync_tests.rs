@@ -0,0 +1,120 @@
+use binance_rs_plus::account::*;
+use binance_rs_plus::api::*;
+use binance_rs_plus::config::*;
+use binance_rs_plus::futures::account::FuturesAccount;
+use binance_rs_plus::time_sync::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+    use std::time::Duration;
+
+    #[test]
+    fn measure_uses_round_trip_midpoint() {
+        let offset = TimeOffset::measure(1_000, 1_550, 1_100);
+        assert_eq!(offset.offset_ms, 500);
+        assert_eq!(offset.round_trip, Duration::from_millis(100));
+
+        let behind = TimeOffset::measure(10_000, 9_000, 10_000);
+        assert_eq!(behind.offset_ms, -1_000);
+        assert_eq!(behind.apply(10_000), 9_000);
+        assert_eq!(behind.apply(500), 0);
+    }
+
+    #[test]
+    fn time_sync_staleness() {
+        let manual = TimeSync::new(None);
+        assert!(!manual.is_stale());
+        assert_eq!(manual.offset(), None);
+
+        let periodic = TimeSync::new(Some(Duration::from_secs(60)));
+        assert!(periodic.is_stale());
+        periodic.update(TimeOffset::measure(0, 3_600_000, 0));
+        assert!(!periodic.is_stale());
+
+        let local = local_timestamp();
+        let stamped = periodic.timestamp();
+        assert!(stamped >= local + 3_600_000 && stamped < local + 3_660_000);
+    }
+
+    #[tokio::test]
+    async fn periodic_sync_stamps_server_time() {
+        let mut server = Server::new_async().await;
+        let mock_time = server
+            .mock("GET", "/api/v3/time")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/server_time.json")
+            .expect(1)
+            .create_async()
+            .await;
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=14998\\d{8}&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .expect(2)
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234)
+            .set_time_sync_interval(Duration::from_secs(60));
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        account.get_account().await.unwrap();
+        account.get_account().await.unwrap();
+
+        mock_time.assert();
+        mock_get_account.assert();
+
+        let offset = account.client.time_offset().unwrap();
+        assert!(offset.offset_ms < 0);
+    }
+
+    #[tokio::test]
+    async fn timestamp_error_resyncs_and_retries() {
+        let mut server = Server::new_async().await;
+        let mock_time = server
+            .mock("GET", "/fapi/v1/time")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/server_time.json")
+            .expect(1)
+            .create_async()
+            .await;
+        let mock_rejected = server
+            .mock("GET", "/fapi/v2/balance")
+            .match_query(Matcher::Regex("timestamp=(1[5-9]|[2-9])\\d{11}&".into()))
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(
+                r#"{"code":-1021,"msg":"Timestamp for this request is outside of the recvWindow."}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let mock_balance = server
+            .mock("GET", "/fapi/v2/balance")
+            .match_query(Matcher::Regex("timestamp=14998\\d{8}&".into()))
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("[]")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        assert_eq!(account.client.time_offset(), None);
+
+        let balances = account.account_balance().await.unwrap();
+        assert!(balances.is_empty());
+
+        mock_time.assert();
+        mock_rejected.assert();
+        mock_balance.assert();
+        assert!(account.client.time_offset().is_some());
+    }
+}
@@ -0,0 +1,244 @@
+use binance_rs_plus::api::*;
+use binance_rs_plus::async_websocket_client::*;
+use binance_rs_plus::config::*;
+use binance_rs_plus::user_data_stream::*;
+use binance_rs_plus::userstream::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::{SinkExt, StreamExt};
+    use mockito::{Matcher, Server};
+    use std::time::Duration;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::accept_async;
+    use tokio_tungstenite::tungstenite::Message;
+
+    const EXECUTION_REPORT: &str = r#"{"e":"executionReport","E":1499405658658,"s":"ETHBTC","c":"mUvoqJxFIILMdfAW5iGSOW","S":"BUY","o":"LIMIT","f":"GTC","q":"1.00000000","p":"0.10264410","P":"0.00000000","F":"0.00000000","g":-1,"C":"","x":"NEW","X":"NEW","r":"NONE","i":4293153,"l":"0.00000000","z":"0.00000000","L":"0.00000000","n":"0","N":null,"T":1499405658657,"t":-1,"I":8641984,"w":true,"m":false,"M":false}"#;
+    const BALANCE_UPDATE: &str =
+        r#"{"e":"balanceUpdate","E":1573200697110,"a":"BTC","d":"100.00000000","T":1573200697068}"#;
+    const AGG_TRADE: &str = r#"{"e":"aggTrade","E":1672515782136,"s":"BNBBTC","a":12345,"p":"0.001","q":"100","f":100,"l":105,"T":1672515782136,"m":true,"M":true}"#;
+    const EXPIRED: &str = r#"{"e":"listenKeyExpired","E":1576653824250}"#;
+    // Not sent: makes `serve` close the session.
+    const DROP: &str = "<drop>";
+    const ACCOUNT_POSITION: &str = r#"{"e":"outboundAccountPosition","E":1564034571105,"u":1564034571073,"B":[{"a":"ETH","f":"10000.000000","l":"0.000000"}]}"#;
+
+    // Serves one websocket session per entry of `sessions`, sending its
+    // messages and then keeping it open, or closing it at `DROP`.
+    async fn serve(sessions: Vec<Vec<&'static str>>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            for messages in sessions {
+                let (tcp, _) = listener.accept().await.unwrap();
+                let mut ws = accept_async(tcp).await.unwrap();
+                tokio::spawn(async move {
+                    for message in messages {
+                        if message == DROP {
+                            let _ = ws.close(None).await;
+                            return;
+                        }
+                        ws.send(Message::Text(message.into())).await.unwrap();
+                    }
+                    while ws.next().await.is_some() {}
+                });
+            }
+        });
+        format!("ws://{}/ws", addr)
+    }
+
+    fn options() -> UserDataStreamConfig {
+        UserDataStreamConfig::default().set_reconnect_policy(
+            ReconnectPolicy::default()
+                .set_initial_backoff(Duration::from_millis(10))
+                .set_jitter(0.0),
+        )
+    }
+
+    #[tokio::test]
+    async fn renews_expired_listen_key() {
+        let mut server = Server::new_async().await;
+        let mock_start = server
+            .mock("POST", "/api/v3/userDataStream")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(r#"{"listenKey":"pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1"}"#)
+            .expect(2)
+            .create_async()
+            .await;
+        let mock_close = server
+            .mock("DELETE", "/api/v3/userDataStream")
+            .match_query(Matcher::Any)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{}")
+            .expect(2)
+            .create_async()
+            .await;
+
+        let ws_endpoint = serve(vec![
+            vec![EXECUTION_REPORT, AGG_TRADE, BALANCE_UPDATE, EXPIRED],
+            vec![ACCOUNT_POSITION],
+        ])
+        .await;
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_ws_endpoint(ws_endpoint);
+        let user_stream: UserStream =
+            Binance::new_with_config(Some("api_key".into()), None, &config);
+
+        let (manager, events) = UserDataStreamManager::start(user_stream, &config, options())
+            .await
+            .unwrap();
+        let events: Vec<UserDataEvent> = events.take(3).map(|event| event.unwrap()).collect().await;
+
+        assert!(matches!(&events[0], UserDataEvent::OrderTrade(e) if e.order_id == 4293153));
+        assert!(matches!(&events[1], UserDataEvent::BalanceUpdate(e) if e.asset == "BTC"));
+        assert!(
+            matches!(&events[2], UserDataEvent::AccountPosition(e) if e.balances[0].asset == "ETH")
+        );
+        manager.shutdown().await.unwrap();
+        mock_start.assert();
+        mock_close.assert();
+    }
+
+    #[tokio::test]
+    async fn renews_after_failed_keep_alive() {
+        let mut server = Server::new_async().await;
+        let mock_start = server
+            .mock("POST", "/api/v3/userDataStream")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(r#"{"listenKey":"key"}"#)
+            .expect_at_least(2)
+            .create_async()
+            .await;
+        let mock_keep_alive = server
+            .mock("PUT", "/api/v3/userDataStream")
+            .match_query(Matcher::Any)
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(r#"{"code":-1125,"msg":"This listenKey does not exist."}"#)
+            .expect_at_least(1)
+            .create_async()
+            .await;
+        let _mock_close = server
+            .mock("DELETE", "/api/v3/userDataStream")
+            .match_query(Matcher::Any)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let ws_endpoint = serve(vec![vec![], vec![BALANCE_UPDATE]]).await;
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_ws_endpoint(ws_endpoint);
+        let user_stream: UserStream =
+            Binance::new_with_config(Some("api_key".into()), None, &config);
+
+        let (manager, mut events) = UserDataStreamManager::start(
+            user_stream,
+            &config,
+            options().set_keep_alive_interval(Duration::from_millis(50)),
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            events.next().await,
+            Some(Ok(UserDataEvent::BalanceUpdate(_)))
+        ));
+        assert_eq!(manager.listen_key(), "key");
+        manager.shutdown().await.unwrap();
+        mock_start.assert();
+        mock_keep_alive.assert();
+    }
+
+    #[tokio::test]
+    async fn reconnects_dropped_connection_without_closing_key() {
+        let mut server = Server::new_async().await;
+        let mock_start = server
+            .mock("POST", "/api/v3/userDataStream")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(r#"{"listenKey":"shared"}"#)
+            .expect(2)
+            .create_async()
+            .await;
+        // Only the shutdown closes the key other consumers may share.
+        let mock_close = server
+            .mock("DELETE", "/api/v3/userDataStream")
+            .match_query(Matcher::Any)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{}")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let ws_endpoint = serve(vec![vec![EXECUTION_REPORT, DROP], vec![BALANCE_UPDATE]]).await;
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_ws_endpoint(ws_endpoint);
+        let user_stream: UserStream =
+            Binance::new_with_config(Some("api_key".into()), None, &config);
+
+        let (manager, events) = UserDataStreamManager::start(user_stream, &config, options())
+            .await
+            .unwrap();
+        let events: Vec<UserDataEvent> = events.take(2).map(|event| event.unwrap()).collect().await;
+
+        assert!(matches!(&events[0], UserDataEvent::OrderTrade(_)));
+        assert!(matches!(&events[1], UserDataEvent::BalanceUpdate(_)));
+        assert_eq!(manager.listen_key(), "shared");
+        manager.shutdown().await.unwrap();
+        mock_start.assert();
+        mock_close.assert();
+    }
+
+    #[tokio::test]
+    async fn shutdown_during_backoff_closes_key() {
+        let mut server = Server::new_async().await;
+        let mock_start = server
+            .mock("POST", "/api/v3/userDataStream")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(r#"{"listenKey":"dropped"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let mock_close = server
+            .mock("DELETE", "/api/v3/userDataStream")
+            .match_body(Matcher::UrlEncoded("listenKey".into(), "dropped".into()))
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{}")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let ws_endpoint = serve(vec![vec![EXECUTION_REPORT, DROP]]).await;
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_ws_endpoint(ws_endpoint);
+        let user_stream: UserStream =
+            Binance::new_with_config(Some("api_key".into()), None, &config);
+
+        let (manager, mut events) = UserDataStreamManager::start(
+            user_stream,
+            &config,
+            options().set_reconnect_policy(
+                ReconnectPolicy::default().set_initial_backoff(Duration::from_secs(60)),
+            ),
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            events.next().await,
+            Some(Ok(UserDataEvent::OrderTrade(_)))
+        ));
+        // Let the session see the drop and start waiting to reconnect.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        tokio::time::timeout(Duration::from_secs(5), manager.shutdown())
+            .await
+            .expect("shutdown must not wait for the backoff")
+            .unwrap();
+        mock_start.assert();
+        mock_close.assert();
+    }
+}
@@ -0,0 +1,563 @@
+use binance_rs_plus::async_websocket_client::*;
+use binance_rs_plus::config::*;
+use binance_rs_plus::event_channel::*;
+use binance_rs_plus::futures::websockets::*;
+use binance_rs_plus::options::websockets::*;
+use binance_rs_plus::websockets::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+    use futures_util::{SinkExt, StreamExt};
+    use tokio::net::TcpListener;
+    use tokio::sync::Mutex;
+    use tokio_tungstenite::accept_async;
+    use tokio_tungstenite::tungstenite::Message;
+
+    const AGG_TRADE: &str = r#"{"e":"aggTrade","E":1672515782136,"s":"BNBBTC","a":12345,"p":"0.001","q":"100","f":100,"l":105,"T":1672515782136,"m":true,"M":true}"#;
+
+    // Serves `connections` websocket sessions in turn, sending one aggTrade to
+    // each before the server closes it. With `keep_last_open` the final session
+    // stays up instead.
+    async fn serve(connections: usize, keep_last_open: bool) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            for n in 0..connections {
+                let (tcp, _) = listener.accept().await.unwrap();
+                let mut ws = accept_async(tcp).await.unwrap();
+                ws.send(Message::Text(AGG_TRADE.into())).await.unwrap();
+                if keep_last_open && n + 1 == connections {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                } else {
+                    ws.close(None).await.unwrap();
+                }
+            }
+        });
+        format!("ws://{}/ws", addr)
+    }
+
+    #[test]
+    fn reconnect_policy_backoff() {
+        let policy = ReconnectPolicy::default()
+            .set_initial_backoff(Duration::from_millis(100))
+            .set_max_backoff(Duration::from_millis(1000))
+            .set_multiplier(2.0)
+            .set_jitter(0.0);
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(4), Duration::from_millis(800));
+        assert_eq!(policy.backoff(5), Duration::from_millis(1000));
+        assert_eq!(policy.delay(3), Duration::from_millis(400));
+
+        let jittered = policy.set_jitter(0.5);
+        for attempt in 1..6 {
+            let delay = jittered.delay(attempt).as_millis();
+            let base = jittered.backoff(attempt).as_millis();
+            assert!(delay >= base / 2 && delay <= base * 3 / 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn reconnects_after_server_close() {
+        let endpoint = serve(2, true).await;
+        let events: Arc<Mutex<Vec<WebsocketEvent>>> = Arc::new(Mutex::new(Vec::new()));
+        let keep_running = Arc::new(AtomicBool::new(true));
+
+        let events_handler = Arc::clone(&events);
+        let keep_running_handler = Arc::clone(&keep_running);
+        let mut web_socket = WebSockets::new(move |event: WebsocketEvent| {
+            let events = Arc::clone(&events_handler);
+            let keep_running = Arc::clone(&keep_running_handler);
+            Box::pin(async move {
+                let mut events = events.lock().await;
+                events.push(event);
+                let trades = events
+                    .iter()
+                    .filter(|e| matches!(e, WebsocketEvent::AggrTrades(_)))
+                    .count();
+                if trades == 2 {
+                    keep_running.store(false, Ordering::Relaxed);
+                }
+                Ok(())
+            })
+        });
+        web_socket.set_reconnect_policy(
+            ReconnectPolicy::default()
+                .set_initial_backoff(Duration::from_millis(10))
+                .set_jitter(0.0),
+        );
+
+        let config = Config::default().set_ws_endpoint(endpoint);
+        web_socket
+            .connect_with_config("bnbbtc@aggTrade", &config)
+            .await
+            .unwrap();
+        web_socket.event_loop(keep_running).await.unwrap();
+
+        let events = events.lock().await;
+        assert!(matches!(events[0], WebsocketEvent::AggrTrades(_)));
+        assert!(matches!(
+            events[1],
+            WebsocketEvent::Connection(ConnectionEvent::Disconnected { .. })
+        ));
+        assert!(matches!(
+            events[2],
+            WebsocketEvent::Connection(ConnectionEvent::Reconnecting { attempt: 1, .. })
+        ));
+        assert!(matches!(
+            events[3],
+            WebsocketEvent::Connection(ConnectionEvent::Reconnected { attempts: 1 })
+        ));
+        assert!(matches!(events[4], WebsocketEvent::AggrTrades(_)));
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let endpoint = serve(1, false).await;
+        let reconnecting = Arc::new(Mutex::new(0u32));
+
+        let reconnecting_handler = Arc::clone(&reconnecting);
+        let mut web_socket = WebSockets::new(move |event: WebsocketEvent| {
+            let reconnecting = Arc::clone(&reconnecting_handler);
+            Box::pin(async move {
+                if let WebsocketEvent::Connection(ConnectionEvent::Reconnecting { .. }) = event {
+                    *reconnecting.lock().await += 1;
+                }
+                Ok(())
+            })
+        });
+        web_socket.set_reconnect_policy(
+            ReconnectPolicy::default()
+                .set_initial_backoff(Duration::from_millis(1))
+                .set_max_attempts(3),
+        );
+
+        let config = Config::default().set_ws_endpoint(endpoint);
+        web_socket
+            .connect_with_config("bnbbtc@aggTrade", &config)
+            .await
+            .unwrap();
+
+        let keep_running = Arc::new(AtomicBool::new(true));
+        assert!(web_socket.event_loop(keep_running).await.is_err());
+        assert_eq!(*reconnecting.lock().await, 3);
+    }
+
+    #[tokio::test]
+    async fn without_policy_disconnect_is_an_error() {
+        let endpoint = serve(1, false).await;
+        let mut web_socket =
+            WebSockets::new(|_event: WebsocketEvent| Box::pin(async move { Ok(()) }));
+
+        let config = Config::default().set_ws_endpoint(endpoint);
+        web_socket
+            .connect_with_config("bnbbtc@aggTrade", &config)
+            .await
+            .unwrap();
+
+        let keep_running = Arc::new(AtomicBool::new(true));
+        assert!(web_socket.event_loop(keep_running).await.is_err());
+    }
+
+    // Answers SUBSCRIBE/UNSUBSCRIBE/LIST_SUBSCRIPTIONS like Binance does,
+    // rejecting streams named "bad", and pushes an aggTrade after each reply.
+    async fn serve_control() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = accept_async(tcp).await.unwrap();
+            let mut streams = vec!["bnbbtc@aggTrade".to_string()];
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                let request: serde_json::Value = serde_json::from_str(&text).unwrap();
+                let id = request["id"].clone();
+                let params: Vec<String> =
+                    serde_json::from_value(request["params"].clone()).unwrap_or_default();
+                let reply = if params.iter().any(|p| p == "bad") {
+                    serde_json::json!({"error": {"code": 2, "msg": "Invalid request"}, "id": id})
+                } else {
+                    match request["method"].as_str().unwrap() {
+                        "SUBSCRIBE" => {
+                            streams.extend(params);
+                            serde_json::json!({"result": null, "id": id})
+                        }
+                        "UNSUBSCRIBE" => {
+                            streams.retain(|s| !params.contains(s));
+                            serde_json::json!({"result": null, "id": id})
+                        }
+                        _ => serde_json::json!({"result": streams, "id": id}),
+                    }
+                };
+                ws.send(Message::Text(reply.to_string())).await.unwrap();
+                ws.send(Message::Text(AGG_TRADE.into())).await.unwrap();
+            }
+        });
+        format!("ws://{}/ws", addr)
+    }
+
+    #[tokio::test]
+    async fn live_subscription_changes() {
+        let endpoint = serve_control().await;
+        let trades = Arc::new(Mutex::new(0u32));
+        let trades_handler = Arc::clone(&trades);
+        let mut web_socket = WebSockets::new(move |event: WebsocketEvent| {
+            let trades = Arc::clone(&trades_handler);
+            Box::pin(async move {
+                if let WebsocketEvent::AggrTrades(_) = event {
+                    *trades.lock().await += 1;
+                }
+                Ok(())
+            })
+        });
+
+        let config = Config::default().set_ws_endpoint(endpoint);
+        web_socket
+            .connect_with_config("bnbbtc@aggTrade", &config)
+            .await
+            .unwrap();
+
+        let keep_running = Arc::new(AtomicBool::new(true));
+        let (event_loop, ()) = tokio::join!(web_socket.event_loop(keep_running.clone()), async {
+            web_socket.subscribe(&["ethbtc@trade"]).await.unwrap();
+            assert_eq!(
+                web_socket.list_subscriptions().await.unwrap(),
+                vec!["bnbbtc@aggTrade", "ethbtc@trade"]
+            );
+            web_socket.unsubscribe(&["bnbbtc@aggTrade"]).await.unwrap();
+            assert_eq!(
+                web_socket.list_subscriptions().await.unwrap(),
+                vec!["ethbtc@trade"]
+            );
+            keep_running.store(false, Ordering::Relaxed);
+            assert!(web_socket.subscribe(&["bad"]).await.is_err());
+        });
+
+        event_loop.unwrap();
+        assert!(*trades.lock().await >= 3);
+    }
+
+    // Sends `messages` on a single session and keeps it open.
+    async fn serve_messages(messages: Vec<&'static str>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = accept_async(tcp).await.unwrap();
+            for message in messages {
+                ws.send(Message::Text(message.into())).await.unwrap();
+            }
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        });
+        format!("ws://{}/ws", addr)
+    }
+
+    #[tokio::test]
+    async fn dispatches_on_event_type() {
+        let endpoint = serve_messages(vec![
+            r#"{"u":400900217,"s":"BNBUSDT","b":"25.35190000","B":"31.21000000","a":"25.36520000","A":"40.66000000"}"#,
+            r#"{"stream":"bnbbtc@depth5","data":{"lastUpdateId":160,"bids":[["0.0024","10"]],"asks":[["0.0026","100"]]}}"#,
+            r#"{"e":"someNewEvent","E":1672515782136,"x":1}"#,
+            AGG_TRADE,
+        ])
+        .await;
+        let events: Arc<Mutex<Vec<WebsocketEvent>>> = Arc::new(Mutex::new(Vec::new()));
+        let keep_running = Arc::new(AtomicBool::new(true));
+
+        let events_handler = Arc::clone(&events);
+        let keep_running_handler = Arc::clone(&keep_running);
+        let mut web_socket = WebSockets::new(move |event: WebsocketEvent| {
+            let events = Arc::clone(&events_handler);
+            let keep_running = Arc::clone(&keep_running_handler);
+            Box::pin(async move {
+                let mut events = events.lock().await;
+                events.push(event);
+                if events.len() == 4 {
+                    keep_running.store(false, Ordering::Relaxed);
+                }
+                Ok(())
+            })
+        });
+
+        let config = Config::default().set_ws_endpoint(endpoint);
+        web_socket
+            .connect_with_config("bnbusdt@bookTicker", &config)
+            .await
+            .unwrap();
+        web_socket.event_loop(keep_running).await.unwrap();
+
+        let events = events.lock().await;
+        assert!(matches!(&events[0], WebsocketEvent::BookTicker(e) if e.update_id == 400900217));
+        assert!(matches!(&events[1], WebsocketEvent::OrderBook(e) if e.last_update_id == 160));
+        assert!(matches!(&events[2], WebsocketEvent::Unknown(e) if e["x"] == 1));
+        assert!(matches!(&events[3], WebsocketEvent::AggrTrades(_)));
+    }
+
+    #[tokio::test]
+    async fn futures_dispatches_on_event_type() {
+        let endpoint = serve_messages(vec![
+            r#"[{"e":"markPriceUpdate","E":1562305380000,"s":"BTCUSDT","p":"11794.15000000","i":"11784.62659091","P":"11784.25641265","r":"0.00038167","T":1562306400000}]"#,
+            r#"{"e":"listenKeyExpired","E":1576653824250}"#,
+            r#"{"e":"bookTicker","u":400900217,"E":1568014460893,"T":1568014460891,"s":"BNBUSDT","b":"25.35190000","B":"31.21000000","a":"25.36520000","A":"40.66000000"}"#,
+        ])
+        .await;
+        let config = Config::default().set_ws_endpoint(endpoint);
+        let events: Arc<Mutex<Vec<FuturesWebsocketEvent>>> = Arc::new(Mutex::new(Vec::new()));
+        let keep_running = Arc::new(AtomicBool::new(true));
+
+        let events_handler = Arc::clone(&events);
+        let keep_running_handler = Arc::clone(&keep_running);
+        let mut web_socket = FuturesWebSockets::new(move |event: FuturesWebsocketEvent| {
+            let events = Arc::clone(&events_handler);
+            let keep_running = Arc::clone(&keep_running_handler);
+            Box::pin(async move {
+                let mut events = events.lock().await;
+                events.push(event);
+                if events.len() == 3 {
+                    keep_running.store(false, Ordering::Relaxed);
+                }
+                Ok(())
+            })
+        });
+
+        web_socket
+            .connect_with_config(&FuturesMarket::USDM, "!markPrice@arr", &config)
+            .await
+            .unwrap();
+        web_socket.event_loop(keep_running).await.unwrap();
+
+        let events = events.lock().await;
+        assert!(matches!(&events[0], FuturesWebsocketEvent::MarkPriceAll(v) if v.len() == 1));
+        assert!(matches!(
+            &events[1],
+            FuturesWebsocketEvent::UserDataStreamExpiredEvent(_)
+        ));
+        assert!(matches!(&events[2], FuturesWebsocketEvent::BookTicker(_)));
+    }
+
+    #[tokio::test]
+    async fn combined_stream_events_carry_stream_name() {
+        let endpoint = serve_messages(vec![
+            r#"{"stream":"bnbbtc@aggTrade","data":{"e":"aggTrade","E":1672515782136,"s":"BNBBTC","a":12345,"p":"0.001","q":"100","f":100,"l":105,"T":1672515782136,"m":true,"M":true}}"#,
+            r#"{"stream":"ethbtc@aggTrade","data":{"e":"aggTrade","E":1672515782137,"s":"ETHBTC","a":12346,"p":"0.05","q":"1","f":200,"l":200,"T":1672515782137,"m":false,"M":true}}"#,
+        ])
+        .await;
+        let events: Arc<Mutex<Vec<StreamEvent<WebsocketEvent>>>> = Arc::new(Mutex::new(Vec::new()));
+        let keep_running = Arc::new(AtomicBool::new(true));
+
+        let events_handler = Arc::clone(&events);
+        let keep_running_handler = Arc::clone(&keep_running);
+        let mut web_socket =
+            WebSockets::new_with_stream_events(move |event: StreamEvent<WebsocketEvent>| {
+                let events = Arc::clone(&events_handler);
+                let keep_running = Arc::clone(&keep_running_handler);
+                Box::pin(async move {
+                    let mut events = events.lock().await;
+                    events.push(event);
+                    if events.len() == 2 {
+                        keep_running.store(false, Ordering::Relaxed);
+                    }
+                    Ok(())
+                })
+            });
+
+        let config = Config::default().set_ws_endpoint(endpoint);
+        web_socket
+            .connect_with_config("bnbbtc@aggTrade", &config)
+            .await
+            .unwrap();
+        web_socket.event_loop(keep_running).await.unwrap();
+
+        let events = events.lock().await;
+        assert_eq!(events[0].stream.as_deref(), Some("bnbbtc@aggTrade"));
+        assert_eq!(events[1].stream.as_deref(), Some("ethbtc@aggTrade"));
+        assert!(matches!(&events[1].event, WebsocketEvent::AggrTrades(e) if e.symbol == "ETHBTC"));
+        assert!(events[0].received_at > 1672515782136);
+        assert!(events[0].received_at <= events[1].received_at);
+    }
+
+    #[tokio::test]
+    async fn channel_yields_events_then_the_error() {
+        let endpoint = serve(1, false).await;
+        let (mut web_socket, events) = WebSockets::channel(ChannelConfig::default());
+        let config = Config::default().set_ws_endpoint(endpoint);
+        web_socket
+            .connect_with_config("bnbbtc@aggTrade", &config)
+            .await
+            .unwrap();
+        let handle = web_socket.spawn();
+
+        let events: Vec<_> = events.collect().await;
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            &events[0],
+            Ok(StreamEvent {
+                stream: None,
+                event: WebsocketEvent::AggrTrades(_),
+                ..
+            })
+        ));
+        assert!(events[1].is_err());
+        handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn channel_events_carry_stream_name() {
+        let endpoint = serve_messages(vec![
+            r#"{"stream":"bnbbtc@aggTrade","data":{"e":"aggTrade","E":1672515782136,"s":"BNBBTC","a":12345,"p":"0.001","q":"100","f":100,"l":105,"T":1672515782136,"m":true,"M":true}}"#,
+        ])
+        .await;
+        let (mut web_socket, mut events) = WebSockets::channel(ChannelConfig::default());
+        let config = Config::default().set_ws_endpoint(endpoint);
+        web_socket
+            .connect_with_config("bnbbtc@aggTrade", &config)
+            .await
+            .unwrap();
+        let handle = web_socket.spawn();
+
+        let event = events.next().await.unwrap().unwrap();
+        assert_eq!(event.stream.as_deref(), Some("bnbbtc@aggTrade"));
+        assert!(event.received_at > 1672515782136);
+        assert!(matches!(event.event, WebsocketEvent::AggrTrades(_)));
+
+        handle.abort();
+        assert!(handle.await.is_err());
+        assert!(events.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn spawned_channel_socket_changes_subscriptions() {
+        let endpoint = serve_control().await;
+        let (mut web_socket, mut events) = WebSockets::channel(ChannelConfig::default());
+        let config = Config::default().set_ws_endpoint(endpoint);
+        web_socket
+            .connect_with_config("bnbbtc@aggTrade", &config)
+            .await
+            .unwrap();
+        let handle = web_socket.spawn();
+
+        handle.subscribe(&["ethbtc@trade"]).await.unwrap();
+        assert_eq!(
+            handle.list_subscriptions().await.unwrap(),
+            vec!["bnbbtc@aggTrade", "ethbtc@trade"]
+        );
+        handle.unsubscribe(&["bnbbtc@aggTrade"]).await.unwrap();
+        assert_eq!(
+            handle.list_subscriptions().await.unwrap(),
+            vec!["ethbtc@trade"]
+        );
+        assert!(matches!(
+            events.next().await,
+            Some(Ok(StreamEvent {
+                event: WebsocketEvent::AggrTrades(_),
+                ..
+            }))
+        ));
+        assert!(!handle.is_finished());
+    }
+
+    #[tokio::test]
+    async fn futures_user_data_events() {
+        let endpoint = serve_messages(vec![
+            include_str!("mocks/futures/userstream/margin_call.json"),
+            include_str!("mocks/futures/userstream/account_config_update_leverage.json"),
+            include_str!("mocks/futures/userstream/account_config_update_multi_assets.json"),
+            include_str!("mocks/futures/userstream/trade_lite.json"),
+            include_str!("mocks/futures/userstream/grid_update.json"),
+            include_str!("mocks/futures/userstream/strategy_update.json"),
+            include_str!("mocks/futures/userstream/conditional_order_trigger_reject.json"),
+        ])
+        .await;
+        let (mut web_socket, events) = FuturesWebSockets::channel(ChannelConfig::default());
+        let config = Config::default().set_ws_endpoint(endpoint);
+        web_socket
+            .connect_with_config(&FuturesMarket::USDM, "listen_key", &config)
+            .await
+            .unwrap();
+        web_socket.spawn();
+
+        let events: Vec<FuturesWebsocketEvent> = events
+            .take(7)
+            .map(|event| event.unwrap().event)
+            .collect()
+            .await;
+        assert!(matches!(
+            &events[0],
+            FuturesWebsocketEvent::MarginCall(e)
+                if e.positions[0].symbol == "ETHUSDT" && e.positions[0].margin_type == "CROSSED"
+        ));
+        assert!(matches!(
+            &events[1],
+            FuturesWebsocketEvent::AccountConfigUpdate(e)
+                if e.leverage_update.as_ref().unwrap().leverage == 25 && e.multi_assets_update.is_none()
+        ));
+        assert!(matches!(
+            &events[2],
+            FuturesWebsocketEvent::AccountConfigUpdate(e)
+                if e.multi_assets_update.as_ref().unwrap().multi_assets_margin
+        ));
+        assert!(matches!(
+            &events[3],
+            FuturesWebsocketEvent::TradeLite(e) if e.order_id == 8886774 && e.trade_id == 109100866
+        ));
+        assert!(matches!(
+            &events[4],
+            FuturesWebsocketEvent::GridUpdate(e) if e.grid.strategy_id == 176057039
+        ));
+        assert!(matches!(
+            &events[5],
+            FuturesWebsocketEvent::StrategyUpdate(e)
+                if e.strategy.strategy_status == "NEW" && e.strategy.op_code == Some(8007)
+        ));
+        assert!(matches!(
+            &events[6],
+            FuturesWebsocketEvent::ConditionalOrderTriggerReject(e) if e.order.order_id == 155618472834
+        ));
+    }
+
+    #[tokio::test]
+    async fn options_events() {
+        let endpoint = serve_messages(vec![
+            include_str!("mocks/options/mark_price_stream.json"),
+            include_str!("mocks/options/ticker_stream.json"),
+            include_str!("mocks/options/userstream/account_update.json"),
+            include_str!("mocks/options/userstream/order_trade_update.json"),
+        ])
+        .await;
+        let (mut web_socket, events) = OptionsWebSockets::channel(ChannelConfig::default());
+        let config = Config::default().set_options_ws_endpoint(endpoint);
+        web_socket
+            .connect_with_config("listen_key", &config)
+            .await
+            .unwrap();
+        web_socket.spawn();
+
+        let events: Vec<OptionsWebsocketEvent> = events
+            .take(4)
+            .map(|event| event.unwrap().event)
+            .collect()
+            .await;
+        assert!(matches!(
+            &events[0],
+            OptionsWebsocketEvent::MarkPrice(e) if e.len() == 2 && e[1].symbol == "ETH-250926-4000-P"
+        ));
+        assert!(matches!(
+            &events[1],
+            OptionsWebsocketEvent::Ticker(e) if e.symbol == "BTC-250926-60000-C" && e.trade_count == 8
+        ));
+        assert!(matches!(
+            &events[2],
+            OptionsWebsocketEvent::AccountUpdate(e)
+                if e.greeks[0].underlying == "BTCUSDT" && e.positions[0].symbol == "BTC-250926-60000-C"
+        ));
+        assert!(matches!(
+            &events[3],
+            OptionsWebsocketEvent::OrderTrade(e)
+                if e.orders[0].status == "PARTIALLY_FILLED" && e.orders[0].fills[0].liquidity == "TAKER"
+        ));
+    }
+}